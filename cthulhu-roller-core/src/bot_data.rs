@@ -0,0 +1,285 @@
+#[cfg(feature = "character-sheet")]
+use crate::{
+    calendar::Calendar,
+    character::{
+        BoutKind, Character, CharacterDraft, ExpressionError, has_sheet_variables, resolve_roll, resolve_threshold,
+    },
+    dashboard::Dashboard,
+    locale::{LocaleTag, locale_text_by_tag_lang},
+    session::Session,
+};
+use crate::{
+    locale::LocaleLang,
+    roller::{battle::Battle, roll_log::RollRecord},
+    ruleset::RulesetKind,
+    settings::GuildSettings,
+    types::*,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt::Display};
+use tokio::fs;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct UserData {
+    #[cfg(feature = "character-sheet")]
+    pub characters: HashMap<String, Character>,
+    #[cfg(feature = "character-sheet")]
+    pub active_character: Option<String>,
+    pub lang: LocaleLang,
+    /// Unfinished character of the creation wizard.
+    #[cfg(feature = "character-sheet")]
+    #[serde(default)]
+    pub draft: Option<CharacterDraft>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Data {
+    pub battle: Option<Battle>,
+    pub gm_role_name: String,
+    pub max_characters_per_user: usize,
+    pub users: UsersHashMap,
+    #[cfg(feature = "character-sheet")]
+    #[serde(default)]
+    pub calendar: Calendar,
+    #[serde(default)]
+    pub guilds: HashMap<u64, GuildSettings>,
+    #[serde(default)]
+    pub rolls: Vec<RollRecord>,
+    #[cfg(feature = "character-sheet")]
+    #[serde(default)]
+    pub session: Option<Session>,
+    #[cfg(feature = "character-sheet")]
+    #[serde(default)]
+    pub dashboards: HashMap<u64, Dashboard>,
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        Self {
+            battle: None,
+            gm_role_name: "GM".into(),
+            max_characters_per_user: 3,
+            users: HashMap::new(),
+            #[cfg(feature = "character-sheet")]
+            calendar: Calendar::default(),
+            guilds: HashMap::new(),
+            rolls: vec![],
+            #[cfg(feature = "character-sheet")]
+            session: None,
+            #[cfg(feature = "character-sheet")]
+            dashboards: HashMap::new(),
+        }
+    }
+}
+
+impl Data {
+    const DB_JSON: &str = "db.json";
+    const DB_BACKUP_JSON: &str = "db-backup.json";
+    const MAX_ROLLS: usize = 10_000;
+
+    /// Bouts of madness are played out in real time during battle and summarized otherwise.
+    #[cfg(feature = "character-sheet")]
+    pub fn bout_kind(&self) -> BoutKind {
+        if self.battle.is_some() {
+            BoutKind::RealTime
+        } else {
+            BoutKind::Summary
+        }
+    }
+
+    /// House rules of a guild; direct messages and unconfigured guilds use global defaults.
+    pub fn settings(&self, guild_id: Option<u64>) -> GuildSettings {
+        guild_id
+            .and_then(|guild_id| self.guilds.get(&guild_id).cloned())
+            .unwrap_or_else(|| self.default_settings())
+    }
+
+    #[cfg(any(feature = "character-sheet", feature = "inline-rolls"))]
+    pub fn settings_mut(&mut self, guild_id: u64) -> &mut GuildSettings {
+        let default_settings = self.default_settings();
+        self.guilds.entry(guild_id).or_insert(default_settings)
+    }
+
+    fn default_settings(&self) -> GuildSettings {
+        GuildSettings {
+            gm_role_name: self.gm_role_name.clone(),
+            max_characters_per_user: self.max_characters_per_user,
+            ..Default::default()
+        }
+    }
+
+    pub fn ruleset(&self, guild_id: Option<u64>) -> RulesetKind {
+        guild_id
+            .and_then(|guild_id| self.guilds.get(&guild_id))
+            .map(|settings| settings.ruleset)
+            .unwrap_or_default()
+    }
+
+    /// Ruleset of a user's rolls: their active character's, so a character is never rolled under two rulesets,
+    /// or the guild's one without an active character.
+    #[cfg(feature = "character-sheet")]
+    pub fn roll_ruleset(&self, user_id: u64, guild_id: Option<u64>) -> RulesetKind {
+        self.users
+            .get(&user_id)
+            .and_then(|user_data| {
+                let name = user_data.active_character.as_ref()?;
+                user_data.characters.get(name)
+            })
+            .map_or_else(|| self.ruleset(guild_id), |character| character.ruleset())
+    }
+
+    #[cfg(not(feature = "character-sheet"))]
+    pub fn roll_ruleset(&self, _: u64, guild_id: Option<u64>) -> RulesetKind {
+        self.ruleset(guild_id)
+    }
+
+    /// Owner of the active character called `name`; `user_id`'s own character wins when several players use it.
+    #[cfg(feature = "character-sheet")]
+    pub fn active_character_owner(&self, user_id: u64, name: &str, lang: LocaleLang) -> Result<u64, Error> {
+        let owners: Vec<u64> = self
+            .users
+            .iter()
+            .filter(|(_, user_data)| user_data.active_character.as_deref() == Some(name))
+            .map(|(owner, _)| *owner)
+            .collect();
+        match owners[..] {
+            [owner] => Ok(owner),
+            _ if owners.contains(&user_id) => Ok(user_id),
+            [] => Err(format!(
+                "{}: `{}`",
+                locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+                name
+            )
+            .into()),
+            _ => Err(format!(
+                "{}: `{}`",
+                locale_text_by_tag_lang(lang, LocaleTag::AmbiguousCharacter),
+                name
+            )
+            .into()),
+        }
+    }
+
+    /// Keeps the roll history bounded by dropping the oldest rolls.
+    pub fn record_roll(&mut self, record: RollRecord) {
+        self.rolls.push(record);
+        if self.rolls.len() > Self::MAX_ROLLS {
+            let excess = self.rolls.len() - Self::MAX_ROLLS;
+            self.rolls.drain(..excess);
+        }
+    }
+
+    /// Dice query with sheet variables like `DB` or `skill("Dodge")` taken from the user's active character.
+    #[cfg(feature = "character-sheet")]
+    pub fn resolve_roll(&self, user_id: u64, query: &str) -> Result<String, Error> {
+        self.resolve_expression(user_id, query, resolve_roll)
+    }
+
+    /// Skill check threshold with sheet variables like `(POW+INT)/2` taken from the user's active character.
+    #[cfg(feature = "character-sheet")]
+    pub fn resolve_threshold(&self, user_id: u64, query: &str) -> Result<String, Error> {
+        self.resolve_expression(user_id, query, resolve_threshold)
+    }
+
+    #[cfg(feature = "character-sheet")]
+    fn resolve_expression(
+        &self,
+        user_id: u64,
+        query: &str,
+        resolve: fn(LocaleLang, &Character, &str) -> Result<String, ExpressionError>,
+    ) -> Result<String, Error> {
+        if !has_sheet_variables(query) {
+            return Ok(query.to_string());
+        }
+        let user_data = self.users.get(&user_id);
+        let lang = user_data.map(|u| u.lang).unwrap_or_default();
+        let character = user_data
+            .and_then(|u| u.active_character.as_ref().and_then(|name| u.characters.get(name)))
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoCharacterSelected))?;
+        Ok(resolve(lang, character, query).map_err(|e| e.to_string(lang))?)
+    }
+
+    #[cfg(not(feature = "character-sheet"))]
+    pub fn resolve_roll(&self, _: u64, query: &str) -> Result<String, Error> {
+        Ok(query.to_string())
+    }
+
+    #[cfg(not(feature = "character-sheet"))]
+    pub fn resolve_threshold(&self, _: u64, query: &str) -> Result<String, Error> {
+        Ok(query.to_string())
+    }
+
+    pub fn get_db_path(&self) -> &'static str {
+        Self::DB_JSON
+    }
+
+    pub async fn save(&self) -> Result<(), Error> {
+        self.save_to_file(Self::DB_JSON).await
+    }
+
+    pub async fn load() -> Self {
+        fs::try_exists(Self::DB_JSON).await.expect("No database file!");
+        match Self::load_from_file(Self::DB_JSON).await {
+            Ok(d) => {
+                println!("Loaded database.");
+                d
+            }
+            Err(e) => {
+                eprintln!("Data loading error: {e}");
+                let invalid_db_name = format!(
+                    "db-invalid-{}.json",
+                    chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+                );
+                match fs::copy(Self::DB_JSON, &invalid_db_name).await {
+                    Ok(_) => {
+                        eprintln!("Saved invalid db as {invalid_db_name}")
+                    }
+                    Err(e) => eprintln!("{e}"),
+                }
+                eprintln!("Creating new database.");
+                let d = Data::default();
+                let _ = d.save().await;
+                d
+            }
+        }
+    }
+
+    pub async fn quicksave(&self) -> Result<(), Error> {
+        self.save_to_file(Self::DB_BACKUP_JSON).await
+    }
+
+    pub async fn quickload(&mut self) -> Result<(), Error> {
+        self.save().await?;
+        (*self).clone_from(&Data::from_json(Self::DB_BACKUP_JSON).await?);
+        Ok(())
+    }
+
+    async fn load_from_file(path: &str) -> Result<Self, Error> {
+        let json = fs::read_to_string(path).await?;
+        Data::from_json(&json).await
+    }
+
+    async fn save_to_file(&self, path: &str) -> Result<(), Error> {
+        let string = self.to_json()?;
+        fs::write(path, string).await?;
+        Ok(())
+    }
+
+    fn to_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    async fn from_json(json: &str) -> Result<Data, Error> {
+        Ok(serde_json::from_str::<Data>(json)?)
+    }
+}
+
+impl Display for Data {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            serde_json::to_string_pretty(&self)
+                .map_err(|_| std::fmt::Error)?
+                .as_str(),
+        )
+    }
+}
@@ -0,0 +1,82 @@
+use crate::locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Era {
+    Gaslight,
+    #[default]
+    Classic,
+    Modern,
+}
+
+impl Era {
+    pub const ALL: [Era; 3] = [Era::Gaslight, Era::Classic, Era::Modern];
+
+    pub fn from_name(text: &str) -> Option<Self> {
+        match text.trim().to_lowercase().as_str() {
+            "gaslight" | "1890s" | "wiktoriańska" => Some(Era::Gaslight),
+            "classic" | "1920s" | "klasyczna" => Some(Era::Classic),
+            "modern" | "współczesna" => Some(Era::Modern),
+            _ => None,
+        }
+    }
+
+    pub fn to_locale_tag(self) -> LocaleTag {
+        match self {
+            Era::Gaslight => LocaleTag::EraGaslight,
+            Era::Classic => LocaleTag::EraClassic,
+            Era::Modern => LocaleTag::EraModern,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Calendar {
+    pub now: NaiveDateTime,
+    pub era: Era,
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Self {
+            now: NaiveDate::from_ymd_opt(1925, 1, 1)
+                .unwrap()
+                .and_time(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+            era: Era::default(),
+        }
+    }
+}
+
+impl Calendar {
+    /// Moves the clock forward and returns the number of days (midnights) that have passed.
+    pub fn advance(&mut self, hours: i32) -> i32 {
+        let previous = self.now;
+        self.now += Duration::hours(hours.max(0).into());
+        (self.now.date() - previous.date()).num_days() as i32
+    }
+
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        format!(
+            "📅 **{}** ({})",
+            self.now.format("%Y-%m-%d %H:%M"),
+            locale_text_by_tag_lang(lang, self.era.to_locale_tag())
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, 0)]
+    #[case(16, 1)]
+    #[case(24, 1)]
+    #[case(7 * 24, 7)]
+    fn test_advance_counts_days(#[case] hours: i32, #[case] days: i32) {
+        let mut calendar = Calendar::default();
+        assert_eq!(calendar.advance(hours), days);
+    }
+}
@@ -153,11 +153,6 @@ impl Attributes {
         }
     }
 
-    pub fn calculate_hp(&self, pulp: bool) -> i32 {
-        let divider = if pulp { 5 } else { 10 };
-        (self.constitution.value + self.size.value) / divider
-    }
-
     pub fn calculate_sanity(&self) -> i32 {
         self.power.value
     }
@@ -170,20 +165,32 @@ impl Attributes {
 impl From<Attributes> for AttributeMap {
     fn from(value: Attributes) -> Self {
         let mut map = AttributeMap::new();
-        map.insert(locale_entry_by_tag(LocaleTag::Strength).clone().en, value.strength);
         map.insert(
-            locale_entry_by_tag(LocaleTag::Constitution).clone().en,
+            locale_entry_by_tag(LocaleTag::Strength).en().to_string(),
+            value.strength,
+        );
+        map.insert(
+            locale_entry_by_tag(LocaleTag::Constitution).en().to_string(),
             value.constitution,
         );
-        map.insert(locale_entry_by_tag(LocaleTag::Size).clone().en, value.size);
-        map.insert(locale_entry_by_tag(LocaleTag::Dexterity).clone().en, value.dexterity);
-        map.insert(locale_entry_by_tag(LocaleTag::Appearance).clone().en, value.appearance);
+        map.insert(locale_entry_by_tag(LocaleTag::Size).en().to_string(), value.size);
+        map.insert(
+            locale_entry_by_tag(LocaleTag::Dexterity).en().to_string(),
+            value.dexterity,
+        );
+        map.insert(
+            locale_entry_by_tag(LocaleTag::Appearance).en().to_string(),
+            value.appearance,
+        );
         map.insert(
-            locale_entry_by_tag(LocaleTag::Intelligence).clone().en,
+            locale_entry_by_tag(LocaleTag::Intelligence).en().to_string(),
             value.intelligence,
         );
-        map.insert(locale_entry_by_tag(LocaleTag::Power).clone().en, value.power);
-        map.insert(locale_entry_by_tag(LocaleTag::Education).clone().en, value.education);
+        map.insert(locale_entry_by_tag(LocaleTag::Power).en().to_string(), value.power);
+        map.insert(
+            locale_entry_by_tag(LocaleTag::Education).en().to_string(),
+            value.education,
+        );
         map
     }
 }
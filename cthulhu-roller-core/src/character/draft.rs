@@ -0,0 +1,493 @@
+use crate::{
+    Error,
+    character::{Attributes, CatalogError, Character, Item, SkillError, find_catalog_weapon},
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang, locale_text_fmt},
+    roller::{attribute_roll::AttributeRollResult, dice_rng::DiceRng},
+    ruleset::RulesetKind,
+};
+use serde::{Deserialize, Serialize};
+
+/// Order of characteristics in a draft, the same as in `Attributes::new`.
+pub const CHARACTERISTICS: [LocaleTag; 8] = [
+    LocaleTag::Strength,
+    LocaleTag::Constitution,
+    LocaleTag::Size,
+    LocaleTag::Dexterity,
+    LocaleTag::Appearance,
+    LocaleTag::Intelligence,
+    LocaleTag::Power,
+    LocaleTag::Education,
+];
+pub const CHARACTERISTIC_ABBREVIATIONS: [LocaleTag; 8] = [
+    LocaleTag::Str,
+    LocaleTag::Con,
+    LocaleTag::Siz,
+    LocaleTag::Dex,
+    LocaleTag::App,
+    LocaleTag::Int,
+    LocaleTag::Pow,
+    LocaleTag::Edu,
+];
+const STR: usize = 0;
+const CON: usize = 1;
+const SIZ: usize = 2;
+const DEX: usize = 3;
+const APP: usize = 4;
+const INT: usize = 5;
+const EDU: usize = 7;
+
+pub const MIN_AGE: i32 = 15;
+pub const MAX_AGE: i32 = 89;
+
+/// Age modifiers of the rulebook: highest age of the bracket, EDU improvement checks,
+/// points taken from STR, CON and DEX, points taken from APP and the Move penalty.
+const AGE_BRACKETS: [(i32, i32, i32, i32, i32); 6] = [
+    (39, 1, 0, 0, 0),
+    (49, 2, 5, 5, 1),
+    (59, 3, 10, 10, 2),
+    (69, 4, 20, 15, 3),
+    (79, 4, 40, 20, 4),
+    (89, 4, 80, 25, 5),
+];
+
+pub enum DraftError {
+    Incomplete,
+    InvalidCharacteristics,
+    InvalidAge,
+    InvalidLine(String),
+    NotEnoughSkillPoints(i32, i32),
+    Skill(SkillError),
+    Catalog(CatalogError),
+}
+
+impl DraftError {
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        match self {
+            DraftError::Incomplete => locale_text_by_tag_lang(lang, LocaleTag::DraftIncomplete),
+            DraftError::InvalidCharacteristics => locale_text_by_tag_lang(lang, LocaleTag::InvalidCharacteristics),
+            DraftError::InvalidAge => locale_text_fmt(lang, LocaleTag::InvalidAge, &[&MIN_AGE, &MAX_AGE]),
+            DraftError::InvalidLine(line) => locale_text_fmt(lang, LocaleTag::InvalidLine, &[line]),
+            DraftError::NotEnoughSkillPoints(spent, available) => {
+                locale_text_fmt(lang, LocaleTag::NotEnoughSkillPoints, &[spent, available])
+            }
+            DraftError::Skill(e) => e.to_string(lang),
+            DraftError::Catalog(e) => e.to_string(lang),
+        }
+    }
+}
+
+/// Characteristics and age of a draft, with the rolled age modifiers applied.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DraftAge {
+    pub years: i32,
+    pub characteristics: [i32; 8],
+    pub move_penalty: i32,
+}
+
+/// Character being built by the creation wizard; it is kept between sessions until created or discarded.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct CharacterDraft {
+    pub name: Option<String>,
+    pub occupation: Option<String>,
+    /// STR, CON, SIZ, DEX, APP, INT, POW and EDU before age modifiers.
+    pub characteristics: Option<[i32; 8]>,
+    pub luck: Option<i32>,
+    /// EDU improvement check rolls (1D100 and 1D10) kept for the characteristics, so changing age does not reroll them.
+    #[serde(default)]
+    pub edu_rolls: Vec<(i32, i32)>,
+    pub age: Option<DraftAge>,
+    /// Skill points spent on top of the base value of each skill.
+    pub skills: Vec<(String, i32)>,
+    /// Names of catalog weapons.
+    pub weapons: Vec<String>,
+    pub items: Vec<String>,
+}
+
+impl CharacterDraft {
+    /// Characteristics from `roll_attributes`, with Luck rolled the same way as 3D6×5.
+    pub fn roll(&mut self, rng: &mut dyn DiceRng, roll: &AttributeRollResult) {
+        let characteristics = CHARACTERISTICS.map(|tag| roll.roll_map.get(&tag).map_or(0, |r| r.result() * 5));
+        let luck = (0..3).map(|_| rng.random_range(1..=6)).sum::<i32>() * 5;
+        self.characteristics = Some(characteristics);
+        self.luck = Some(luck);
+        self.edu_rolls.clear();
+        self.age = None;
+    }
+
+    /// Replaces characteristics and Luck; age modifiers have to be applied again afterwards.
+    pub fn set_characteristics(&mut self, characteristics: [i32; 8], luck: i32) -> Result<(), DraftError> {
+        if characteristics
+            .iter()
+            .chain([&luck])
+            .any(|value| !(1..=99).contains(value))
+        {
+            return Err(DraftError::InvalidCharacteristics);
+        }
+        self.characteristics = Some(characteristics);
+        self.luck = Some(luck);
+        self.edu_rolls.clear();
+        self.age = None;
+        Ok(())
+    }
+
+    /// Applies age modifiers: EDU improvement checks, lower physical characteristics and APP, and lower Move.
+    /// Players younger than 20 lose 5 from STR or SIZ and 5 from EDU instead.
+    pub fn set_age(&mut self, rng: &mut dyn DiceRng, years: i32) -> Result<(), DraftError> {
+        let mut characteristics = self.characteristics.ok_or(DraftError::Incomplete)?;
+        if !(MIN_AGE..=MAX_AGE).contains(&years) {
+            return Err(DraftError::InvalidAge);
+        }
+
+        let mut move_penalty = 0;
+        if years < 20 {
+            deduct(&mut characteristics, &[STR, SIZ], 5);
+            characteristics[EDU] = (characteristics[EDU] - 5).max(1);
+        } else if let Some((_, edu_checks, physical, appearance, penalty)) =
+            AGE_BRACKETS.iter().find(|(max_age, ..)| years <= *max_age)
+        {
+            while self.edu_rolls.len() < *edu_checks as usize {
+                self.edu_rolls
+                    .push((rng.random_range(1..=100), rng.random_range(1..=10)));
+            }
+            for (check, improvement) in self.edu_rolls.iter().take(*edu_checks as usize) {
+                if *check > characteristics[EDU] {
+                    characteristics[EDU] = (characteristics[EDU] + improvement).min(99);
+                }
+            }
+            deduct(&mut characteristics, &[STR, CON, DEX], *physical);
+            characteristics[APP] = (characteristics[APP] - appearance).max(1);
+            move_penalty = *penalty;
+        }
+
+        self.age = Some(DraftAge {
+            years,
+            characteristics,
+            move_penalty,
+        });
+        Ok(())
+    }
+
+    /// Occupation points (EDU×4) and personal interest points (INT×2) after age modifiers.
+    pub fn skill_points(&self) -> Option<i32> {
+        self.age
+            .as_ref()
+            .map(|age| age.characteristics[EDU] * 4 + age.characteristics[INT] * 2)
+    }
+
+    pub fn spent_skill_points(&self) -> i32 {
+        self.skills
+            .iter()
+            .fold(0, |spent: i32, (_, points)| spent.saturating_add(*points))
+    }
+
+    /// Replaces spent skill points, checking them against the budget and the skills of the ruleset.
+    pub fn set_skills(&mut self, skills: Vec<(String, i32)>, ruleset: RulesetKind) -> Result<(), DraftError> {
+        let available = self.skill_points().ok_or(DraftError::Incomplete)?;
+        let previous = std::mem::replace(&mut self.skills, skills);
+        let spent = self.skills.iter().try_fold(0, |spent: i32, (_, points)| {
+            (1..=available)
+                .contains(points)
+                .then(|| spent.checked_add(*points))
+                .flatten()
+        });
+        let result = match spent {
+            Some(spent) if spent <= available => self
+                .base_character("-", ruleset)
+                .and_then(|mut character| self.apply_skills(&mut character)),
+            _ => Err(DraftError::NotEnoughSkillPoints(self.spent_skill_points(), available)),
+        };
+        if result.is_err() {
+            self.skills = previous;
+        }
+        result
+    }
+
+    pub fn set_weapons(&mut self, weapons: Vec<String>) -> Result<(), DraftError> {
+        for weapon in &weapons {
+            find_catalog_weapon(weapon).map_err(DraftError::Catalog)?;
+        }
+        self.weapons = weapons;
+        Ok(())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.name.is_some() && self.luck.is_some() && self.age.is_some()
+    }
+
+    fn base_character(&self, name: &str, ruleset: RulesetKind) -> Result<Character, DraftError> {
+        let (Some(age), Some(luck)) = (&self.age, self.luck) else {
+            return Err(DraftError::Incomplete);
+        };
+        let [str, con, siz, dex, app, int, pow, edu] = age.characteristics;
+        let attributes =
+            Attributes::new(str, con, siz, dex, app, int, pow, edu).map_err(|_| DraftError::InvalidCharacteristics)?;
+        let mut character = Character::new(name, &self.occupation, attributes, luck, None, ruleset)
+            .map_err(|_| DraftError::InvalidCharacteristics)?;
+        character.move_rate = (character.move_rate - age.move_penalty).max(1);
+        Ok(character)
+    }
+
+    fn apply_skills(&self, character: &mut Character) -> Result<(), DraftError> {
+        for (name, points) in &self.skills {
+            match character.get_skill(name) {
+                Some(skill) if !skill.improvable || skill.constant => {
+                    return Err(DraftError::Skill(SkillError::Constant(skill.name)));
+                }
+                Some(_) => character.modify_skill(name, *points),
+                None => character.add_skill(name, *points),
+            }
+            .map_err(DraftError::Skill)?;
+        }
+        Ok(())
+    }
+
+    /// Creates the character with `Character::new`, then adds spent skill points, weapons and items.
+    pub fn build(&self, lang: LocaleLang, ruleset: RulesetKind) -> Result<Character, Error> {
+        let name = self.name.as_deref().ok_or(DraftError::Incomplete.to_string(lang))?;
+        let mut character = self.base_character(name, ruleset).map_err(|e| e.to_string(lang))?;
+        self.apply_skills(&mut character).map_err(|e| e.to_string(lang))?;
+        for weapon in &self.weapons {
+            let weapon = find_catalog_weapon(weapon).map_err(|e| e.to_string(lang))?;
+            character.weapons.push(weapon.to_weapon(lang, None)?);
+        }
+        character
+            .items
+            .extend(self.items.iter().map(|item| Item::new(item, 1, None)));
+        Ok(character)
+    }
+
+    pub fn summary(&self, lang: LocaleLang) -> String {
+        let text = |tag| locale_text_by_tag_lang(lang, tag);
+        let or_dash = |value: Option<String>| value.unwrap_or("-".into());
+        let characteristics = self
+            .age
+            .as_ref()
+            .map(|age| age.characteristics)
+            .or(self.characteristics)
+            .map(|values| {
+                CHARACTERISTIC_ABBREVIATIONS
+                    .iter()
+                    .zip(values)
+                    .map(|(tag, value)| format!("{}: **{value}**", text(*tag)))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            });
+        let skills = (!self.skills.is_empty()).then(|| {
+            self.skills
+                .iter()
+                .map(|(name, points)| format!("{name} +{points}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+        [
+            format!("{}: **{}**", text(LocaleTag::Name), or_dash(self.name.clone())),
+            format!(
+                "{}: **{}**",
+                text(LocaleTag::Occupation),
+                or_dash(self.occupation.clone())
+            ),
+            or_dash(characteristics),
+            format!(
+                "{}: **{}**",
+                text(LocaleTag::Luck),
+                or_dash(self.luck.map(|l| l.to_string()))
+            ),
+            format!(
+                "{}: **{}**",
+                text(LocaleTag::Age),
+                or_dash(self.age.as_ref().map(|age| match age.move_penalty {
+                    0 => age.years.to_string(),
+                    penalty => format!("{} ({} -{penalty})", age.years, text(LocaleTag::Move)),
+                }))
+            ),
+            format!(
+                "{}: **{}**/{}",
+                text(LocaleTag::SkillPoints),
+                self.spent_skill_points(),
+                or_dash(self.skill_points().map(|p| p.to_string()))
+            ),
+            or_dash(skills),
+            format!("{}: {}", text(LocaleTag::Weapons), or_dash(join(&self.weapons))),
+            format!("{}: {}", text(LocaleTag::Items), or_dash(join(&self.items))),
+        ]
+        .join("\n")
+    }
+}
+
+fn join(names: &[String]) -> Option<String> {
+    (!names.is_empty()).then(|| names.join(", "))
+}
+
+/// Takes `points` in steps of 5 from whichever of the given characteristics is highest.
+fn deduct(characteristics: &mut [i32; 8], from: &[usize], points: i32) {
+    for _ in 0..points / 5 {
+        if let Some(&i) = from.iter().max_by_key(|&&i| characteristics[i]) {
+            characteristics[i] = (characteristics[i] - 5).max(1);
+        }
+    }
+}
+
+/// Non-empty trimmed lines, e.g. weapons or items entered one per line.
+pub fn parse_lines(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Lines of `Skill: points`.
+pub fn parse_skills(text: &str) -> Result<Vec<(String, i32)>, DraftError> {
+    parse_lines(text)
+        .into_iter()
+        .map(|line| {
+            line.rsplit_once(':')
+                .and_then(|(name, points)| Some((name.trim().to_string(), points.trim().parse::<i32>().ok()?)))
+                .filter(|(name, points)| !name.is_empty() && *points > 0)
+                .ok_or(DraftError::InvalidLine(line))
+        })
+        .collect()
+}
+
+/// Numbers separated by spaces or commas.
+pub fn parse_numbers(text: &str) -> Result<Vec<i32>, DraftError> {
+    text.split([' ', ','])
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse().map_err(|_| DraftError::InvalidCharacteristics))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    struct FixedRng(i32);
+
+    impl DiceRng for FixedRng {
+        fn random_range(&mut self, range: std::ops::RangeInclusive<i32>) -> i32 {
+            self.0.clamp(*range.start(), *range.end())
+        }
+    }
+
+    fn draft(age: i32) -> CharacterDraft {
+        let mut draft = CharacterDraft {
+            name: Some("Harvey".into()),
+            ..Default::default()
+        };
+        assert!(draft.set_characteristics([50, 60, 70, 40, 55, 80, 65, 70], 45).is_ok());
+        assert!(draft.set_age(&mut FixedRng(100), age).is_ok());
+        draft
+    }
+
+    #[rstest]
+    #[case(17, [50, 60, 65, 40, 55, 80, 65, 65], 0)]
+    #[case(25, [50, 60, 70, 40, 55, 80, 65, 80], 0)]
+    #[case(45, [50, 55, 70, 40, 50, 80, 65, 90], 1)]
+    #[case(65, [45, 45, 70, 40, 40, 80, 65, 99], 3)]
+    fn test_age_modifiers(#[case] age: i32, #[case] characteristics: [i32; 8], #[case] move_penalty: i32) {
+        let draft = draft(age);
+        let draft_age = draft.age.unwrap();
+        assert_eq!(draft_age.characteristics, characteristics);
+        assert_eq!(draft_age.move_penalty, move_penalty);
+    }
+
+    #[test]
+    fn test_new_characteristics_reset_age() {
+        let mut draft = draft(45);
+        assert!(draft.set_characteristics([50; 8], 50).is_ok());
+        assert_eq!(draft.age, None);
+        assert!(!draft.is_complete());
+        assert!(draft.set_age(&mut FixedRng(1), 90).is_err());
+        assert!(draft.set_characteristics([0; 8], 50).is_err());
+    }
+
+    #[test]
+    fn test_skill_points_budget() {
+        let mut draft = draft(25);
+        assert_eq!(draft.skill_points(), Some(80 * 4 + 80 * 2));
+        assert!(
+            draft
+                .set_skills(vec![("Spot Hidden".into(), 500)], RulesetKind::Classic)
+                .is_err()
+        );
+        assert!(
+            draft
+                .set_skills(vec![("Cthulhu Mythos".into(), 10)], RulesetKind::Classic)
+                .is_err()
+        );
+        assert!(draft.skills.is_empty());
+        assert!(
+            draft
+                .set_skills(
+                    vec![("Spot Hidden".into(), 40), ("Basket Weaving".into(), 30)],
+                    RulesetKind::Classic
+                )
+                .is_ok()
+        );
+        assert_eq!(draft.spent_skill_points(), 70);
+        assert!(
+            draft
+                .set_skills(
+                    vec![("Spot Hidden".into(), i32::MAX), ("Listen".into(), i32::MAX)],
+                    RulesetKind::Classic
+                )
+                .is_err()
+        );
+        assert!(
+            draft
+                .set_skills(
+                    vec![("Spot Hidden".into(), i32::MAX), ("Listen".into(), i32::MIN + 1)],
+                    RulesetKind::Classic
+                )
+                .is_err()
+        );
+        assert_eq!(draft.spent_skill_points(), 70);
+    }
+
+    #[test]
+    fn test_changing_age_keeps_edu_rolls() {
+        let mut draft = draft(45);
+        let edu = draft.age.as_ref().unwrap().characteristics[EDU];
+        assert!(draft.set_age(&mut FixedRng(1), 45).is_ok());
+        assert_eq!(draft.age.as_ref().unwrap().characteristics[EDU], edu);
+        assert!(draft.set_age(&mut FixedRng(1), 65).is_ok());
+        assert_eq!(draft.edu_rolls, vec![(100, 10), (100, 10), (1, 1), (1, 1)]);
+        assert!(draft.set_characteristics([50; 8], 50).is_ok());
+        assert!(draft.edu_rolls.is_empty());
+    }
+
+    #[test]
+    fn test_build_calls_character_new() {
+        let mut draft = draft(45);
+        assert!(
+            draft
+                .set_skills(vec![("Spot Hidden".into(), 40)], RulesetKind::Classic)
+                .is_ok()
+        );
+        assert!(draft.set_weapons(vec!["Knife, medium".into()]).is_ok());
+        assert!(draft.set_weapons(vec!["Death Ray".into()]).is_err());
+        draft.items = vec!["Flashlight".into()];
+
+        let character = draft.build(LocaleLang::default(), RulesetKind::Classic).unwrap();
+        assert_eq!(character.name, "Harvey");
+        assert_eq!(character.get_skill("Spot Hidden").unwrap().value, 65);
+        assert_eq!(character.weapons.len(), 2);
+        assert_eq!(character.items.len(), 1);
+        assert_eq!(character.move_rate, 6);
+    }
+
+    #[rstest]
+    #[case("Spot Hidden: 40\n\n Art/Craft (Painting) : 10 ", true)]
+    #[case("Spot Hidden 40", false)]
+    #[case("Spot Hidden: -5", false)]
+    fn test_parse_skills(#[case] text: &str, #[case] valid: bool) {
+        assert_eq!(parse_skills(text).is_ok(), valid);
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(parse_numbers("50, 60 70").ok(), Some(vec![50, 60, 70]));
+        assert!(parse_numbers("50 sixty").is_err());
+    }
+}
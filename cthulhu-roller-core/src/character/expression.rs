@@ -0,0 +1,382 @@
+use crate::{
+    character::{Character, SkillError},
+    locale::{LocaleLang, LocaleTag, locale_text_fmt},
+};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// Dice queries have single letters only (`1d6`, `k10x3`), so a longer word is a sheet variable.
+    static ref SHEET_VARIABLE: Regex = Regex::new(r"[A-Za-z]{2,}").unwrap();
+    static ref TRAILING_MODIFIER_DICE: Regex = Regex::new(r"[+-]*$").unwrap();
+}
+
+pub enum ExpressionError {
+    Invalid(String),
+    Skill(SkillError),
+}
+
+impl ExpressionError {
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        match self {
+            ExpressionError::Invalid(expression) => locale_text_fmt(lang, LocaleTag::InvalidExpression, &[expression]),
+            ExpressionError::Skill(e) => e.to_string(lang),
+        }
+    }
+}
+
+/// Whether `query` refers to the sheet, e.g. `1d6+DB` or `(POW+INT)/2`.
+pub fn has_sheet_variables(query: &str) -> bool {
+    SHEET_VARIABLE.is_match(query)
+}
+
+/// Dice query with sheet variables replaced by values of `character`, e.g. `1d6+DB` becomes `1d6+1d4`.
+pub fn resolve_roll(lang: LocaleLang, character: &Character, query: &str) -> Result<String, ExpressionError> {
+    Ok(evaluate(lang, character, query)?.to_query())
+}
+
+/// Skill check threshold with sheet variables replaced; trailing `+`/`-` bonus and penalty dice are kept,
+/// e.g. `(POW+INT)/2+` becomes `57+`.
+pub fn resolve_threshold(lang: LocaleLang, character: &Character, query: &str) -> Result<String, ExpressionError> {
+    let query = query.trim();
+    let modifier_dice = TRAILING_MODIFIER_DICE.find(query).map_or("", |m| m.as_str());
+    let expression = &query[..query.len() - modifier_dice.len()];
+    let sum = evaluate(lang, character, expression)?;
+    if !sum.dice.is_empty() {
+        return Err(ExpressionError::Invalid(expression.to_string()));
+    }
+    Ok(format!("{}{modifier_dice}", sum.constant))
+}
+
+/// Dice rolled `count` times with `sides` sides; the result is multiplied by `multiplier`.
+#[derive(Clone, Debug, PartialEq)]
+struct Dice {
+    count: i32,
+    sides: i32,
+    multiplier: i32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Sum {
+    dice: Vec<Dice>,
+    constant: i32,
+}
+
+impl Sum {
+    fn constant(constant: i32) -> Self {
+        Self { dice: vec![], constant }
+    }
+
+    /// `None` on overflow, like the other checked operations below.
+    fn add(mut self, other: Sum) -> Option<Self> {
+        self.dice.extend(other.dice);
+        self.constant = self.constant.checked_add(other.constant)?;
+        Some(self)
+    }
+
+    fn scale(mut self, factor: i32) -> Option<Self> {
+        for dice in &mut self.dice {
+            dice.multiplier = dice.multiplier.checked_mul(factor)?;
+        }
+        self.constant = self.constant.checked_mul(factor)?;
+        Some(self)
+    }
+
+    fn divide(self, divisor: i32) -> Option<Self> {
+        Some(Sum::constant(self.constant.checked_div_euclid(divisor)?))
+    }
+
+    fn to_query(&self) -> String {
+        let mut query: String = self
+            .dice
+            .iter()
+            .map(|dice| {
+                format!(
+                    "{}{}d{}{}",
+                    if dice.multiplier < 0 { "-" } else { "+" },
+                    dice.count,
+                    dice.sides,
+                    match dice.multiplier.unsigned_abs() {
+                        1 => String::new(),
+                        factor => format!("x{factor}"),
+                    }
+                )
+            })
+            .collect();
+        if self.constant != 0 || query.is_empty() {
+            query.push_str(&format!("{:+}", self.constant));
+        }
+        query.trim_start_matches('+').to_string()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(i32),
+    Dice(i32, i32),
+    Variable(String),
+    Skill(String),
+    Operator(char),
+    Open,
+    Close,
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, ExpressionError> {
+    let invalid = || ExpressionError::Invalid(expression.to_string());
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    let take_while = |i: &mut usize, predicate: fn(&char) -> bool| {
+        let start = *i;
+        while *i < chars.len() && predicate(&chars[*i]) {
+            *i += 1;
+        }
+        chars[start..*i].iter().collect::<String>()
+    };
+    let is_die = |c: char| matches!(c, 'd' | 'D' | 'k' | 'K' | 'w' | 'W');
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || (is_die(c) && chars.get(i + 1).is_some_and(char::is_ascii_digit)) {
+            let count = take_while(&mut i, char::is_ascii_digit);
+            if chars.get(i).is_some_and(|c| is_die(*c)) && chars.get(i + 1).is_some_and(char::is_ascii_digit) {
+                i += 1;
+                let sides = take_while(&mut i, char::is_ascii_digit);
+                tokens.push(Token::Dice(
+                    if count.is_empty() {
+                        1
+                    } else {
+                        count.parse().map_err(|_| invalid())?
+                    },
+                    sides.parse().map_err(|_| invalid())?,
+                ));
+            } else {
+                tokens.push(Token::Number(count.parse().map_err(|_| invalid())?));
+            }
+        } else if c.is_alphabetic() {
+            let name = take_while(&mut i, |c| c.is_alphabetic() || *c == '_');
+            if name.eq_ignore_ascii_case("skill") {
+                let rest: String = chars[i..].iter().collect();
+                let (skill, length) = quoted_argument(&rest).ok_or_else(invalid)?;
+                tokens.push(Token::Skill(skill));
+                i += length;
+            } else if name.eq_ignore_ascii_case("x") {
+                tokens.push(Token::Operator('*'));
+            } else {
+                tokens.push(Token::Variable(name.to_uppercase()));
+            }
+        } else {
+            tokens.push(match c {
+                '+' | '-' | '*' | '/' => Token::Operator(c),
+                '(' => Token::Open,
+                ')' => Token::Close,
+                _ => return Err(invalid()),
+            });
+            i += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+/// `("Firearms (Handgun)")` at the start of `text`; returns the name and the length in chars.
+fn quoted_argument(text: &str) -> Option<(String, usize)> {
+    lazy_static! {
+        static ref ARGUMENT: Regex = Regex::new(r#"^\s*\(\s*["“”']([^"“”']+)["“”']\s*\)"#).unwrap();
+    }
+    let captures = ARGUMENT.captures(text)?;
+    Some((captures[1].trim().to_string(), captures[0].chars().count()))
+}
+
+struct Parser<'a> {
+    lang: LocaleLang,
+    character: &'a Character,
+    expression: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+fn evaluate(lang: LocaleLang, character: &Character, expression: &str) -> Result<Sum, ExpressionError> {
+    let mut parser = Parser {
+        lang,
+        character,
+        expression,
+        tokens: tokenize(expression)?,
+        position: 0,
+    };
+    let sum = parser.sum()?;
+    if parser.position != parser.tokens.len() {
+        return Err(parser.invalid());
+    }
+    Ok(sum)
+}
+
+impl Parser<'_> {
+    fn invalid(&self) -> ExpressionError {
+        ExpressionError::Invalid(self.expression.to_string())
+    }
+
+    fn next_if(&mut self, predicate: impl Fn(&Token) -> bool) -> Option<Token> {
+        let token = self.tokens.get(self.position).filter(|token| predicate(token))?.clone();
+        self.position += 1;
+        Some(token)
+    }
+
+    fn sum(&mut self) -> Result<Sum, ExpressionError> {
+        let mut sum = self.product()?;
+        while let Some(Token::Operator(operator)) = self.next_if(|t| matches!(t, Token::Operator('+' | '-'))) {
+            let term = self.product()?;
+            let term = if operator == '-' { term.scale(-1) } else { Some(term) };
+            sum = term.and_then(|term| sum.add(term)).ok_or_else(|| self.invalid())?;
+        }
+        Ok(sum)
+    }
+
+    /// Dice can be multiplied by a number, but only numbers can be divided; halves round down like on the sheet.
+    fn product(&mut self) -> Result<Sum, ExpressionError> {
+        let mut product = self.factor()?;
+        while let Some(Token::Operator(operator)) = self.next_if(|t| matches!(t, Token::Operator('*' | '/'))) {
+            let factor = self.factor()?;
+            product = match (operator, product.dice.is_empty(), factor.dice.is_empty()) {
+                ('*', _, true) => product.scale(factor.constant),
+                ('*', true, false) => factor.scale(product.constant),
+                ('/', true, true) => product.divide(factor.constant),
+                _ => None,
+            }
+            .ok_or_else(|| self.invalid())?;
+        }
+        Ok(product)
+    }
+
+    fn factor(&mut self) -> Result<Sum, ExpressionError> {
+        let token = self.next_if(|_| true).ok_or_else(|| self.invalid())?;
+        Ok(match token {
+            Token::Number(number) => Sum::constant(number),
+            Token::Dice(count, sides) => Sum {
+                dice: vec![Dice {
+                    count,
+                    sides,
+                    multiplier: 1,
+                }],
+                constant: 0,
+            },
+            Token::Operator('-') => self.factor()?.scale(-1).ok_or_else(|| self.invalid())?,
+            Token::Operator('+') => self.factor()?,
+            Token::Open => {
+                let sum = self.sum()?;
+                self.next_if(|t| *t == Token::Close).ok_or_else(|| self.invalid())?;
+                sum
+            }
+            Token::Skill(name) => Sum::constant(
+                self.character
+                    .get_skill(&name)
+                    .ok_or(ExpressionError::Skill(SkillError::NoSuchSkill(name)))?
+                    .value,
+            ),
+            Token::Variable(name) => self.variable(&name)?,
+            _ => return Err(self.invalid()),
+        })
+    }
+
+    fn variable(&self, name: &str) -> Result<Sum, ExpressionError> {
+        let character = self.character;
+        let attributes = &character.attributes;
+        Ok(Sum::constant(match name {
+            "STR" => attributes.strength(),
+            "CON" => attributes.constitution(),
+            "SIZ" => attributes.size(),
+            "DEX" => attributes.dexterity(),
+            "APP" => attributes.appearance(),
+            "INT" => attributes.intelligence(),
+            "POW" => attributes.power(),
+            "EDU" => attributes.education(),
+            "LUCK" => character.luck.current,
+            "SAN" => character.sanity.current,
+            "HP" => character.hp.current,
+            "MP" => character.magic.current,
+            "MOV" => character.move_rate,
+            "BUILD" => character.build,
+            "DB" => {
+                let damage_modifier = character.damage_modifier(self.lang);
+                return evaluate(self.lang, character, &damage_modifier);
+            }
+            _ => return Err(ExpressionError::Invalid(name.to_string())),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{character::Attributes, ruleset::RulesetKind};
+    use rstest::rstest;
+
+    fn character() -> Character {
+        Character::new(
+            "Harvey",
+            &None,
+            Attributes::new(80, 55, 75, 40, 50, 70, 45, 60).unwrap(),
+            50,
+            None,
+            RulesetKind::Classic,
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case("1d6+DB", "1d6+1d4")]
+    #[case("1d3 + db", "1d3+1d4")]
+    #[case("CON/2", "27")]
+    #[case("STR/5+1d10", "1d10+16")]
+    #[case("2*(POW+INT)/2", "115")]
+    #[case("1d6x2-MOV", "1d6x2-8")]
+    #[case("-DB", "-1d4")]
+    #[case("BUILD+skill(\"Spot Hidden\")", "26")]
+    #[case("skill(“Firearms (Handgun)”)", "20")]
+    fn test_resolve_roll(#[case] query: &str, #[case] expected: &str) {
+        assert_eq!(
+            resolve_roll(LocaleLang::ENGLISH, &character(), query).ok().as_deref(),
+            Some(expected)
+        );
+    }
+
+    #[rstest]
+    #[case("1d6+FOO")]
+    #[case("1d6/2")]
+    #[case("STR/0")]
+    #[case("(STR+1")]
+    #[case("skill(\"Basket Weaving\")")]
+    #[case("STR$")]
+    #[case("2147483647+STR")]
+    #[case("2147483647*STR")]
+    #[case("1d6*2147483647*2")]
+    #[case("-(-2147483647-1)")]
+    #[case("(-2147483647-1)/(-1)")]
+    fn test_invalid_roll(#[case] query: &str) {
+        assert!(resolve_roll(LocaleLang::ENGLISH, &character(), query).is_err());
+    }
+
+    #[rstest]
+    #[case("(POW+INT)/2+", "57+")]
+    #[case("STR--", "80--")]
+    #[case("CON/5", "11")]
+    fn test_resolve_threshold(#[case] query: &str, #[case] expected: &str) {
+        assert_eq!(
+            resolve_threshold(LocaleLang::ENGLISH, &character(), query)
+                .ok()
+                .as_deref(),
+            Some(expected)
+        );
+        assert!(resolve_threshold(LocaleLang::ENGLISH, &character(), "DB").is_err());
+    }
+
+    #[rstest]
+    #[case("1d6+DB", true)]
+    #[case("skill(\"Dodge\")", true)]
+    #[case("k10x3+2", false)]
+    #[case("3d6-1", false)]
+    fn test_has_sheet_variables(#[case] query: &str, #[case] expected: bool) {
+        assert_eq!(has_sheet_variables(query), expected);
+    }
+}
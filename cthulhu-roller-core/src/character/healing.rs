@@ -0,0 +1,32 @@
+use crate::locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang, locale_text_fmt};
+use serde::{Deserialize, Serialize};
+
+pub const DAYS_BETWEEN_RECOVERY_ROLLS: i32 = 7;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct HealingState {
+    pub dying: bool,
+    pub first_aid_today: bool,
+    pub medicine_today: bool,
+    pub days_since_recovery_roll: i32,
+}
+
+pub enum HealingError {
+    Dead,
+    FirstAidAlreadyApplied,
+    MedicineAlreadyApplied,
+    NoMajorWound,
+    RecoveryTooSoon(i32),
+}
+
+impl HealingError {
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        match self {
+            HealingError::Dead => locale_text_by_tag_lang(lang, LocaleTag::HealingTooLate),
+            HealingError::FirstAidAlreadyApplied => locale_text_by_tag_lang(lang, LocaleTag::FirstAidAlreadyApplied),
+            HealingError::MedicineAlreadyApplied => locale_text_by_tag_lang(lang, LocaleTag::MedicineAlreadyApplied),
+            HealingError::NoMajorWound => locale_text_by_tag_lang(lang, LocaleTag::NoMajorWound),
+            HealingError::RecoveryTooSoon(days) => locale_text_fmt(lang, LocaleTag::RecoveryTooSoon, &[days]),
+        }
+    }
+}
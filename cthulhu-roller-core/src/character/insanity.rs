@@ -0,0 +1,207 @@
+use crate::{
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
+    roller::dice_rng::DiceRng,
+};
+use serde::{Deserialize, Serialize};
+
+const BOUTS_REAL_TIME: [LocaleTag; 10] = [
+    LocaleTag::BoutRealTimeAmnesia,
+    LocaleTag::BoutRealTimePsychosomatic,
+    LocaleTag::BoutRealTimeViolence,
+    LocaleTag::BoutRealTimeParanoia,
+    LocaleTag::BoutRealTimeSignificantPerson,
+    LocaleTag::BoutRealTimeFaint,
+    LocaleTag::BoutRealTimeFlee,
+    LocaleTag::BoutRealTimeHysterics,
+    LocaleTag::BoutPhobia,
+    LocaleTag::BoutMania,
+];
+
+const BOUTS_SUMMARY: [LocaleTag; 10] = [
+    LocaleTag::BoutSummaryAmnesia,
+    LocaleTag::BoutSummaryRobbed,
+    LocaleTag::BoutSummaryBattered,
+    LocaleTag::BoutSummaryViolence,
+    LocaleTag::BoutSummaryIdeology,
+    LocaleTag::BoutSummarySignificantPeople,
+    LocaleTag::BoutSummaryInstitutionalized,
+    LocaleTag::BoutSummaryFlee,
+    LocaleTag::BoutPhobia,
+    LocaleTag::BoutMania,
+];
+
+const HOURS_PER_MONTH: i32 = 30 * 24;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InsanityKind {
+    Temporary,
+    Indefinite,
+    Permanent,
+}
+
+impl InsanityKind {
+    pub fn to_locale_tag(self) -> LocaleTag {
+        match self {
+            InsanityKind::Temporary => LocaleTag::TempInsanity,
+            InsanityKind::Indefinite => LocaleTag::IndefInsanity,
+            InsanityKind::Permanent => LocaleTag::MindShattered,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoutKind {
+    RealTime,
+    Summary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Bout {
+    pub kind: BoutKind,
+    pub roll: i32,
+    pub duration: i32,
+}
+
+impl Bout {
+    pub fn roll(rng: &mut dyn DiceRng, kind: BoutKind) -> Self {
+        Self {
+            kind,
+            roll: rng.random_range(1..=10),
+            duration: rng.random_range(1..=10),
+        }
+    }
+
+    pub fn to_locale_tag(&self) -> LocaleTag {
+        let index = (self.roll - 1).clamp(0, 9) as usize;
+        match self.kind {
+            BoutKind::RealTime => BOUTS_REAL_TIME[index],
+            BoutKind::Summary => BOUTS_SUMMARY[index],
+        }
+    }
+
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        let unit = match self.kind {
+            BoutKind::RealTime => LocaleTag::Rounds,
+            BoutKind::Summary => LocaleTag::Hours,
+        };
+        format!(
+            "🌀 **{}** ({} {})",
+            locale_text_by_tag_lang(lang, self.to_locale_tag()),
+            self.duration,
+            locale_text_by_tag_lang(lang, unit).to_lowercase()
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InsanityEpisode {
+    pub kind: InsanityKind,
+    pub cause: Option<String>,
+    pub bouts: Vec<Bout>,
+    pub duration_hours: Option<i32>,
+    pub remaining_hours: Option<i32>,
+    pub active: bool,
+}
+
+impl InsanityEpisode {
+    /// Rolls episode duration: 1d10 hours for temporary, 1d10 months for indefinite insanity.
+    pub fn new(rng: &mut dyn DiceRng, kind: InsanityKind, cause: Option<String>) -> Self {
+        let duration_hours = match kind {
+            InsanityKind::Temporary => Some(rng.random_range(1..=10)),
+            InsanityKind::Indefinite => Some(rng.random_range(1..=10) * HOURS_PER_MONTH),
+            InsanityKind::Permanent => None,
+        };
+        Self {
+            kind,
+            cause,
+            bouts: vec![],
+            duration_hours,
+            remaining_hours: duration_hours,
+            active: true,
+        }
+    }
+
+    /// Returns `true` when the episode has just ended.
+    pub fn pass_time(&mut self, hours: i32) -> bool {
+        if !self.active {
+            return false;
+        }
+        if let Some(remaining) = self.remaining_hours.as_mut() {
+            *remaining = (*remaining - hours).max(0);
+            if *remaining == 0 {
+                self.active = false;
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn duration_to_string(&self, lang: LocaleLang) -> String {
+        match self.duration_hours {
+            Some(hours) if self.kind == InsanityKind::Indefinite => format!(
+                "{} {}",
+                hours / HOURS_PER_MONTH,
+                locale_text_by_tag_lang(lang, LocaleTag::Months).to_lowercase()
+            ),
+            Some(hours) => format!(
+                "{} {}",
+                hours,
+                locale_text_by_tag_lang(lang, LocaleTag::Hours).to_lowercase()
+            ),
+            None => "∞".into(),
+        }
+    }
+
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        let mut out = format!(
+            "{} **{}** ({})",
+            if self.active { "🌀" } else { "✔️" },
+            locale_text_by_tag_lang(lang, self.kind.to_locale_tag()),
+            self.duration_to_string(lang)
+        );
+        if let Some(cause) = &self.cause {
+            out.push_str(format!(" — {cause}").as_str());
+        }
+        for bout in &self.bouts {
+            out.push_str(format!("\n  {}", bout.to_string(lang)).as_str());
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedRng(i32);
+
+    impl DiceRng for FixedRng {
+        fn random_range(&mut self, _: std::ops::RangeInclusive<i32>) -> i32 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_indefinite_insanity_lasts_months() {
+        let episode = InsanityEpisode::new(&mut FixedRng(3), InsanityKind::Indefinite, None);
+        assert_eq!(episode.duration_hours, Some(3 * HOURS_PER_MONTH));
+    }
+
+    #[test]
+    fn test_temporary_insanity_ends() {
+        let mut episode = InsanityEpisode::new(&mut FixedRng(5), InsanityKind::Temporary, None);
+        assert!(!episode.pass_time(4));
+        assert!(episode.active);
+        assert!(episode.pass_time(1));
+        assert!(!episode.active);
+        assert!(!episode.pass_time(1));
+    }
+
+    #[test]
+    fn test_bout_table() {
+        let bout = Bout::roll(&mut FixedRng(10), BoutKind::Summary);
+        assert_eq!(bout.to_locale_tag(), LocaleTag::BoutMania);
+        let bout = Bout::roll(&mut FixedRng(1), BoutKind::RealTime);
+        assert_eq!(bout.to_locale_tag(), LocaleTag::BoutRealTimeAmnesia);
+    }
+}
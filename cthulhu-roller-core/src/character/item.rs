@@ -0,0 +1,256 @@
+use crate::{
+    locale::{LocaleLang, LocaleTag, locale_dice, locale_entry_by_tag, locale_text_by_tag_lang, locale_text_fmt},
+    roller::roll::roll_parse,
+};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ItemEffect {
+    /// Restores Hit Points rolled with given dice query.
+    Heal(String),
+    /// Adds a bonus die to the user's next skill roll.
+    BonusDie,
+    /// Light source, can be lit and put out.
+    Light,
+}
+
+impl FromStr for ItemEffect {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        let (name, arg) = s.split_once(':').unwrap_or((s.as_str(), ""));
+        match name {
+            "heal" | "lecz" => {
+                let query = if arg.is_empty() { "1d3" } else { arg };
+                match roll_parse(query) {
+                    Ok(dice) if !dice.is_empty() => Ok(ItemEffect::Heal(query.into())),
+                    _ => Err(()),
+                }
+            }
+            "bonus" | "premia" => Ok(ItemEffect::BonusDie),
+            "light" | "światło" => Ok(ItemEffect::Light),
+            _ => Err(()),
+        }
+    }
+}
+
+impl ItemEffect {
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        match self {
+            ItemEffect::Heal(query) => locale_text_fmt(lang, LocaleTag::ItemEffectHeal, &[&locale_dice(lang, query)]),
+            ItemEffect::BonusDie => locale_text_by_tag_lang(lang, LocaleTag::ItemEffectBonusDie),
+            ItemEffect::Light => locale_text_by_tag_lang(lang, LocaleTag::ItemEffectLight),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Item {
+    pub name: String,
+    pub quantity: i32,
+    pub unit: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Weight of a single unit in kilograms.
+    #[serde(default)]
+    pub weight: f32,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Name of the item this one is stored in.
+    #[serde(default)]
+    pub container: Option<String>,
+    /// Remaining charges of the current unit; unit is consumed when they run out.
+    #[serde(default)]
+    pub charges: Option<i32>,
+    #[serde(default)]
+    pub max_charges: Option<i32>,
+    #[serde(default)]
+    pub effect: Option<ItemEffect>,
+    #[serde(default)]
+    pub lit: bool,
+}
+
+pub enum ItemError {
+    CantRemove(i32),
+    NoEffect(String),
+    Empty(String),
+    InvalidCharges(i32),
+}
+
+impl ItemError {
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        match self {
+            ItemError::CantRemove(q) => locale_text_fmt(lang, LocaleTag::CantRemoveUnits, &[q]),
+            ItemError::NoEffect(name) => locale_text_fmt(lang, LocaleTag::ItemCantBeUsed, &[name]),
+            ItemError::Empty(name) => locale_text_fmt(lang, LocaleTag::ItemUsedUp, &[name]),
+            ItemError::InvalidCharges(charges) => locale_text_fmt(lang, LocaleTag::InvalidCharges, &[charges]),
+        }
+    }
+}
+
+impl Item {
+    pub fn new(name: &str, quantity: i32, unit: Option<&str>) -> Self {
+        Self {
+            name: name.into(),
+            quantity,
+            unit: unit.map(|u| u.into()),
+            description: None,
+            weight: 0.0,
+            tags: vec![],
+            container: None,
+            charges: None,
+            max_charges: None,
+            effect: None,
+            lit: false,
+        }
+    }
+
+    /// Makes every unit last `charges` uses; the current unit starts full.
+    pub fn with_charges(mut self, charges: i32) -> Result<Self, ItemError> {
+        if charges <= 0 {
+            return Err(ItemError::InvalidCharges(charges));
+        }
+        self.charges = Some(charges);
+        self.max_charges = Some(charges);
+        Ok(self)
+    }
+
+    pub fn is_consumable(&self) -> bool {
+        self.max_charges.is_some()
+    }
+
+    pub fn total_weight(&self) -> f32 {
+        self.weight * self.quantity as f32
+    }
+
+    pub fn add(&mut self, quantity: i32) {
+        self.quantity += quantity;
+    }
+
+    pub fn remove(&mut self, mut quantity: i32) -> Result<Item, ItemError> {
+        if self.quantity < quantity {
+            quantity = self.quantity
+        }
+        self.quantity -= quantity;
+
+        let mut removed_item = self.clone();
+        removed_item.quantity = quantity;
+
+        Ok(removed_item)
+    }
+
+    /// Uses up one charge of a consumable; returns the effect to apply.
+    /// Lights are only lit or put out, so they don't spend charges.
+    pub fn use_charge(&mut self) -> Result<ItemEffect, ItemError> {
+        let effect = self.effect.clone().ok_or(ItemError::NoEffect(self.name.clone()))?;
+        if self.quantity <= 0 {
+            return Err(ItemError::Empty(self.name.clone()));
+        }
+        if effect == ItemEffect::Light {
+            self.lit = !self.lit;
+            return Ok(effect);
+        }
+        if let Some(charges) = self.charges.as_mut() {
+            *charges -= 1;
+            if *charges <= 0 {
+                self.quantity -= 1;
+                self.charges = self.max_charges;
+            }
+        }
+        Ok(effect)
+    }
+
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        let mut out = self.name.clone();
+        if self.lit {
+            out = format!("🔦 {out}");
+        }
+        if self.quantity != 1 {
+            out.push_str(
+                format!(
+                    " [{} {}]",
+                    self.quantity,
+                    self.unit
+                        .clone()
+                        .unwrap_or(locale_entry_by_tag(LocaleTag::Pcs).get(lang))
+                )
+                .as_str(),
+            );
+        }
+        if let (Some(charges), Some(max_charges)) = (self.charges, self.max_charges) {
+            out.push_str(format!(" ({charges}/{max_charges})").as_str());
+        }
+        out
+    }
+
+    pub fn to_details(&self, lang: LocaleLang) -> String {
+        let mut out = format!("**{}**", self.to_string(lang));
+        if let Some(description) = &self.description {
+            out.push_str(format!("\n*{description}*").as_str());
+        }
+        if self.weight > 0.0 {
+            out.push_str(format!("\n⚖️ {:.1} kg", self.total_weight()).as_str());
+        }
+        if let Some(container) = &self.container {
+            out.push_str(format!("\n📦 {container}").as_str());
+        }
+        if !self.tags.is_empty() {
+            out.push_str(format!("\n🏷️ {}", self.tags.join(", ")).as_str());
+        }
+        if let Some(effect) = &self.effect {
+            out.push_str(format!("\n{}", effect.to_string(lang)).as_str());
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("heal:2d3", Some(ItemEffect::Heal("2d3".into())))]
+    #[case("heal", Some(ItemEffect::Heal("1d3".into())))]
+    #[case("Bonus", Some(ItemEffect::BonusDie))]
+    #[case("light", Some(ItemEffect::Light))]
+    #[case("heal:abc", None)]
+    #[case("heal:1d99999999999", None)]
+    #[case("fly", None)]
+    fn test_item_effect_from_str(#[case] text: &str, #[case] expected: Option<ItemEffect>) {
+        assert_eq!(text.parse().ok(), expected);
+    }
+
+    #[test]
+    fn test_consumable_charges() {
+        let Ok(mut kit) = Item::new("First aid kit", 2, None).with_charges(2) else {
+            panic!("positive charges");
+        };
+        kit.effect = Some(ItemEffect::Heal("1d3".into()));
+        assert!(kit.use_charge().is_ok());
+        assert_eq!((kit.quantity, kit.charges), (2, Some(1)));
+        assert!(kit.use_charge().is_ok());
+        assert_eq!((kit.quantity, kit.charges), (1, Some(2)));
+        assert!(kit.use_charge().is_ok());
+        assert!(kit.use_charge().is_ok());
+        assert_eq!(kit.quantity, 0);
+        assert!(kit.use_charge().is_err());
+        assert!(Item::new("First aid kit", 1, None).with_charges(0).is_err());
+        assert!(Item::new("First aid kit", 1, None).with_charges(-1).is_err());
+    }
+
+    #[test]
+    fn test_light_keeps_charges() {
+        let Ok(mut lantern) = Item::new("Lantern", 1, None).with_charges(1) else {
+            panic!("positive charges");
+        };
+        lantern.effect = Some(ItemEffect::Light);
+        assert!(lantern.use_charge().is_ok());
+        assert!(lantern.lit);
+        assert!(lantern.use_charge().is_ok());
+        assert!(!lantern.lit);
+        assert_eq!((lantern.quantity, lantern.charges), (1, Some(1)));
+    }
+}
@@ -0,0 +1,808 @@
+use crate::{
+    Error,
+    locale::{LocaleEntry, LocaleLang, LocaleTag, locale_dice, locale_entry_by_tag, locale_tag_by_str},
+    roller::dice_rng::DiceRng,
+    ruleset::RulesetKind,
+    types::SkillMap,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod attributes;
+pub use attributes::*;
+pub mod skill;
+pub use skill::*;
+pub mod weapon;
+pub use weapon::*;
+pub mod item;
+pub use item::*;
+pub mod tome;
+pub use tome::*;
+pub mod insanity;
+pub use insanity::*;
+pub mod healing;
+pub use healing::*;
+pub mod spell;
+pub use spell::*;
+pub mod pulp;
+pub use pulp::*;
+pub mod weapon_catalog;
+pub use weapon_catalog::*;
+pub mod draft;
+pub use draft::*;
+pub mod expression;
+pub use expression::*;
+pub mod stats;
+pub use stats::*;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CharacterVariable {
+    pub current: i32,
+    pub initial: i32,
+    pub start: i32,
+    pub max: i32,
+}
+
+impl CharacterVariable {
+    pub fn new(start: i32, max: i32) -> Self {
+        Self {
+            current: start,
+            initial: start,
+            start,
+            max,
+        }
+    }
+
+    pub fn new_clamped(start: i32) -> Self {
+        Self {
+            current: start,
+            initial: start,
+            start,
+            max: start,
+        }
+    }
+
+    pub fn modify(&mut self, delta: i32) {
+        self.current = (self.current + delta).clamp(0, self.max);
+    }
+
+    pub fn update_initial(&mut self) {
+        self.initial = self.current;
+    }
+
+    pub fn reset(&mut self) {
+        self.current = self.start;
+        self.initial = self.start;
+    }
+}
+
+pub fn default_skills(attributes: &Attributes) -> Result<SkillMap, Error> {
+    let mut skill_map = SkillMap::new();
+    for (locale_tag, value) in DEFAULT_SKILLS.iter() {
+        let (k, v) = match locale_tag {
+            LocaleTag::LanguageOwn => skill_map_wrapper(*locale_tag, attributes.education())?,
+            LocaleTag::Dodge => skill_map_wrapper(*locale_tag, attributes.dexterity() / 2)?,
+            _ => skill_map_wrapper(*locale_tag, *value)?,
+        };
+        skill_map.insert(k, v);
+    }
+    for (locale_tag, value) in DEFAULT_UNIMPROVABLE_SKILLS.iter() {
+        let (k, v) = skill_unimprovable_map_wrapper(*locale_tag, *value)?;
+        skill_map.insert(k, v);
+    }
+    for (locale_tag, value) in DEFAULT_CONSTANT_SKILLS.iter() {
+        let (k, v) = skill_constant_map_wrapper(*locale_tag, *value)?;
+        skill_map.insert(k, v);
+    }
+    Ok(skill_map)
+}
+
+pub fn skill_map_wrapper(tag: LocaleTag, val: i32) -> Result<(String, Skill), Error> {
+    Ok((
+        locale_entry_by_tag(tag).en().to_string(),
+        Skill::new_default(locale_entry_by_tag(tag).clone(), val),
+    ))
+}
+
+pub fn skill_unimprovable_map_wrapper(tag: LocaleTag, val: i32) -> Result<(String, Skill), Error> {
+    Ok((
+        locale_entry_by_tag(tag).en().to_string(),
+        Skill::new_default_unimprovable(locale_entry_by_tag(tag).clone(), val),
+    ))
+}
+
+fn skill_constant_map_wrapper(tag: LocaleTag, val: i32) -> Result<(String, Skill), Error> {
+    Ok((
+        locale_entry_by_tag(tag).en().to_string(),
+        Skill::new_default_constant(locale_entry_by_tag(tag).clone(), val),
+    ))
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Character {
+    pub name: String,
+    pub occupation: Option<String>,
+    pub attributes: Attributes,
+    pub build: i32,
+    pub move_rate: i32,
+    pub hp: CharacterVariable,
+    pub major_wound: bool,
+    pub dead: bool,
+    pub sanity: CharacterVariable,
+    pub fragile_mind: bool,
+    pub insane: bool,
+    pub luck: CharacterVariable,
+    pub magic: CharacterVariable,
+    pub skills: SkillMap,
+    pub weapons: Vec<Weapon>,
+    pub items: Vec<Item>,
+    pub pulp_archetype: Option<LocaleEntry>,
+    pub pulp_talents: Vec<LocaleEntry>,
+    #[serde(default)]
+    pub tomes: Vec<Tome>,
+    #[serde(default)]
+    pub spells: Vec<String>,
+    #[serde(default)]
+    pub insanity_episodes: Vec<InsanityEpisode>,
+    #[serde(default)]
+    pub healing: HealingState,
+    #[serde(default)]
+    pub active_spells: Vec<ActiveSpell>,
+    #[serde(default)]
+    pub bonus_dice: i32,
+    /// Hit Points lost to the latest damage, which the Tough Guy talent can shrug off once.
+    #[serde(default)]
+    pub last_damage: i32,
+    /// Hit Points lost since creation; session recaps report the difference.
+    #[serde(default)]
+    pub damage_taken: i32,
+    /// Sanity points lost since creation; session recaps report the difference.
+    #[serde(default)]
+    pub sanity_lost: i32,
+    #[serde(default)]
+    pub ruleset: Option<RulesetKind>,
+    #[serde(default)]
+    pub pulp_core_characteristic: Option<LocaleEntry>,
+    #[serde(default)]
+    pub archetype_points: i32,
+    #[serde(default)]
+    pub backstory: Option<String>,
+}
+
+impl PartialOrd for Character {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Character {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.name.cmp(&other.name)
+    }
+}
+
+impl PartialEq for Character {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.eq(&other.name)
+    }
+}
+
+impl Eq for Character {}
+
+impl Character {
+    pub fn new(
+        name: &str,
+        occupation: &Option<String>,
+        attributes: Attributes,
+        luck: i32,
+        pulp_archetype: Option<LocaleEntry>,
+        ruleset: RulesetKind,
+    ) -> Result<Self, Error> {
+        let rules = ruleset.ruleset();
+        Ok(Character {
+            name: name.into(),
+            occupation: occupation.clone(),
+            magic: CharacterVariable::new_clamped(attributes.power() / 5),
+            skills: rules.default_skills(&attributes)?,
+            move_rate: attributes.calculate_move_rate(),
+            hp: CharacterVariable::new_clamped(rules.hit_points(&attributes)),
+            luck: CharacterVariable::new(rules.starting_luck(luck), 99),
+            sanity: CharacterVariable::new(attributes.calculate_sanity(), 99),
+            build: attributes.calculate_build(),
+            attributes,
+            dead: false,
+            major_wound: false,
+            fragile_mind: false,
+            insane: false,
+            items: vec![],
+            weapons: vec![Weapon {
+                name: "🙌".into(),
+                range_dmgs: RangeDamage::from(vec!["-".into()], vec!["1d3".into()]).unwrap(),
+                attacks_query: "1".into(),
+                attacks: attacks_from("1")?,
+                apply_damage_modifier: true,
+                half_damage_modifier: false,
+                impaling: false,
+                skill: locale_entry_by_tag(LocaleTag::FightingBrawl).en().to_string(),
+                malfunction: None,
+                ammo: None,
+                default: true,
+                jammed: None,
+            }],
+            archetype_points: if pulp_archetype.is_some() {
+                ARCHETYPE_SKILL_POINTS
+            } else {
+                0
+            },
+            pulp_archetype,
+            pulp_talents: vec![],
+            tomes: vec![],
+            spells: vec![],
+            insanity_episodes: vec![],
+            healing: HealingState::default(),
+            active_spells: vec![],
+            bonus_dice: 0,
+            last_damage: 0,
+            damage_taken: 0,
+            sanity_lost: 0,
+            ruleset: Some(ruleset),
+            pulp_core_characteristic: None,
+            backstory: None,
+        })
+    }
+
+    /// Characters saved before rulesets were introduced are Pulp heroes if they have an archetype.
+    pub fn ruleset(&self) -> RulesetKind {
+        self.ruleset.unwrap_or(if self.pulp_archetype.is_some() {
+            RulesetKind::Pulp
+        } else {
+            RulesetKind::Classic
+        })
+    }
+
+    pub fn set_attribute(&mut self, name: &str, value: i32) {
+        if let Some(entry) = self.attributes.get_mut(name) {
+            entry.value = value;
+        }
+
+        if self.attributes.get("strength").unwrap().name.equals_ignore_case(name) {
+            self.build = self.attributes.calculate_build();
+            self.move_rate = self.attributes.calculate_move_rate();
+        }
+        if self
+            .attributes
+            .get("constitution")
+            .unwrap()
+            .name
+            .equals_ignore_case(name)
+        {
+            self.hp.max = self.ruleset().ruleset().hit_points(&self.attributes);
+        }
+        if self.attributes.get("size").unwrap().name.equals_ignore_case(name) {
+            self.build = self.attributes.calculate_build();
+            self.move_rate = self.attributes.calculate_move_rate();
+            self.hp.max = self.ruleset().ruleset().hit_points(&self.attributes);
+        }
+        if self.attributes.get("dexterity").unwrap().name.equals_ignore_case(name) {
+            self.move_rate = self.attributes.calculate_move_rate();
+        }
+        if self.attributes.get("power").unwrap().name.equals_ignore_case(name) {
+            self.magic.max = self.attributes.calculate_magic();
+        }
+    }
+
+    pub fn get_skill_partial(&self, partial_skill_name: &str) -> Option<Skill> {
+        if let Some((_, v)) = self
+            .skills
+            .iter()
+            .find(|(_, v)| v.name.partial_match_ignore_case(partial_skill_name))
+        {
+            return Some(v.clone());
+        }
+        None
+    }
+
+    pub fn get_skill(&self, skill_name: &str) -> Option<Skill> {
+        if let Some((_, v)) = self.skills.iter().find(|(_, v)| v.name.equals_ignore_case(skill_name)) {
+            return Some(v.clone());
+        }
+        None
+    }
+
+    pub fn get_mut_skill(&mut self, skill_name: &str) -> Option<&mut Skill> {
+        if let Some((_, v)) = self
+            .skills
+            .iter_mut()
+            .find(|(_, v)| v.name.equals_ignore_case(skill_name))
+        {
+            return Some(v);
+        }
+        None
+    }
+
+    pub fn add_skill(&mut self, name: &str, value: i32) -> Result<(), SkillError> {
+        let locale_text = if let Some(tag) = locale_tag_by_str(name) {
+            locale_entry_by_tag(tag).clone()
+        } else {
+            LocaleEntry::new_single_lang(name)
+        };
+        let new_skill = Skill::new_custom(locale_text.clone(), value);
+        match self.skills.get(locale_text.en()) {
+            Some(_) => Err(SkillError::AlreadyExists(name.to_string())),
+            None => {
+                self.skills.insert(locale_text.en().to_string(), new_skill);
+                Ok(())
+            }
+        }
+    }
+
+    pub fn set_skill(&mut self, name: &str, value: i32) -> Result<(), SkillError> {
+        let skill = self
+            .skills
+            .iter_mut()
+            .find(|(_, v)| v.name.equals_ignore_case(name))
+            .ok_or(SkillError::NoSuchSkill(name.to_string()))?
+            .1;
+        skill.set(value)?;
+        if skill.name.equals_ignore_case("Cthulhu Mythos") {
+            self.sanity.max = 99 - skill.value;
+        }
+        Ok(())
+    }
+
+    pub fn modify_skill(&mut self, name: &str, value: i32) -> Result<(), SkillError> {
+        let skill = self
+            .skills
+            .iter_mut()
+            .find(|(_, v)| v.name.equals_ignore_case(name))
+            .ok_or(SkillError::NoSuchSkill(name.to_string()))?
+            .1;
+        skill.modify(value)?;
+        if skill.name.equals_ignore_case("Cthulhu Mythos") {
+            self.sanity.max = 99 - skill.value;
+        }
+        Ok(())
+    }
+
+    pub fn remove_skill(&mut self, skill_name: &str) -> Result<(), SkillError> {
+        let key = self
+            .skills
+            .iter()
+            .find(|(_, skill)| skill.name.equals_ignore_case(skill_name) && !skill.default)
+            .map(|(key, _)| key.clone())
+            .ok_or(SkillError::CantRemove(skill_name.to_string()))?;
+        self.skills.remove(&key);
+
+        Ok(())
+    }
+
+    pub fn get_tome(&self, tome_name: &str) -> Option<&Tome> {
+        self.tomes.iter().find(|t| t.name.eq_ignore_ascii_case(tome_name))
+    }
+
+    pub fn get_mut_tome(&mut self, tome_name: &str) -> Option<&mut Tome> {
+        self.tomes.iter_mut().find(|t| t.name.eq_ignore_ascii_case(tome_name))
+    }
+
+    /// Applies Cthulhu Mythos gain of finished reading phase and returns newly learned spells.
+    pub fn learn_from_tome(&mut self, tome: &Tome, phase: ReadingPhase) -> Result<Vec<String>, SkillError> {
+        let mythos_name = locale_entry_by_tag(LocaleTag::CthulhuMythos).en().to_string();
+        let mythos = self.get_skill(&mythos_name).map_or(0, |s| s.value);
+        self.set_skill(&mythos_name, mythos + tome.mythos_gain(phase))?;
+
+        let mut learned = vec![];
+        if phase == ReadingPhase::Full {
+            for spell in &tome.spells {
+                if !self.spells.iter().any(|s| s.eq_ignore_ascii_case(spell)) {
+                    self.spells.push(spell.clone());
+                    learned.push(spell.clone());
+                }
+            }
+        }
+        Ok(learned)
+    }
+
+    pub fn cast_spell(
+        &mut self,
+        spell_name: &str,
+        cost: i32,
+        duration_hours: Option<i32>,
+    ) -> Result<String, SpellError> {
+        let name = self
+            .spells
+            .iter()
+            .find(|s| s.eq_ignore_ascii_case(spell_name))
+            .cloned()
+            .ok_or(SpellError::UnknownSpell(spell_name.to_string()))?;
+        if cost > self.magic.current {
+            return Err(SpellError::NotEnoughMagicPoints(cost));
+        }
+        self.magic.modify(-cost);
+        if let Some(remaining_hours) = duration_hours {
+            self.active_spells.retain(|s| s.name != name);
+            self.active_spells.push(ActiveSpell {
+                name: name.clone(),
+                remaining_hours,
+            });
+        }
+        Ok(name)
+    }
+
+    pub fn carried_weight(&self) -> f32 {
+        self.items.iter().map(|i| i.total_weight()).sum()
+    }
+
+    /// Maximum weight in kilograms the character can carry without being encumbered.
+    pub fn carry_limit(&self) -> f32 {
+        (self.attributes.strength() + self.attributes.size()) as f32 / 4.0
+    }
+
+    pub fn is_encumbered(&self) -> bool {
+        self.carried_weight() > self.carry_limit()
+    }
+
+    /// Counts down active spell durations and returns spells that ended.
+    pub fn pass_spell_time(&mut self, hours: i32) -> Vec<ActiveSpell> {
+        for spell in self.active_spells.iter_mut() {
+            spell.remaining_hours -= hours;
+        }
+        let (ended, active) = self.active_spells.drain(..).partition(|s| s.remaining_hours <= 0);
+        self.active_spells = active;
+        ended
+    }
+
+    pub fn active_insanity(&self) -> Option<&InsanityEpisode> {
+        self.insanity_episodes.iter().rev().find(|e| e.active)
+    }
+
+    pub fn start_insanity(
+        &mut self,
+        rng: &mut dyn DiceRng,
+        kind: InsanityKind,
+        cause: Option<String>,
+        bout_kind: Option<BoutKind>,
+    ) -> InsanityEpisode {
+        let mut episode = InsanityEpisode::new(rng, kind, cause);
+        if let Some(bout_kind) = bout_kind {
+            episode.bouts.push(Bout::roll(rng, bout_kind));
+        }
+        match kind {
+            InsanityKind::Permanent => self.insane = true,
+            _ => self.fragile_mind = true,
+        }
+        self.insanity_episodes.push(episode.clone());
+        episode
+    }
+
+    /// Rolls another bout of madness for already insane character.
+    pub fn add_bout(&mut self, rng: &mut dyn DiceRng, bout_kind: BoutKind) -> Option<Bout> {
+        let episode = self.insanity_episodes.iter_mut().rev().find(|e| e.active)?;
+        let bout = Bout::roll(rng, bout_kind);
+        episode.bouts.push(bout.clone());
+        Some(bout)
+    }
+
+    pub fn end_insanity(&mut self) {
+        for episode in self
+            .insanity_episodes
+            .iter_mut()
+            .filter(|e| e.kind != InsanityKind::Permanent)
+        {
+            episode.active = false;
+        }
+        self.fragile_mind = false;
+    }
+
+    /// Counts down insanity durations and returns episodes that ended.
+    pub fn pass_insanity_time(&mut self, hours: i32) -> Vec<InsanityEpisode> {
+        let ended: Vec<_> = self
+            .insanity_episodes
+            .iter_mut()
+            .filter_map(|e| e.pass_time(hours).then(|| e.clone()))
+            .collect();
+        if !ended.is_empty() && !self.insanity_episodes.iter().any(|e| e.active) {
+            self.fragile_mind = false;
+        }
+        ended
+    }
+
+    /// First Aid heals 1 HP and stabilizes dying character.
+    pub fn can_receive_first_aid(&self) -> Result<(), HealingError> {
+        if self.dead {
+            return Err(HealingError::Dead);
+        }
+        if self.healing.first_aid_today {
+            return Err(HealingError::FirstAidAlreadyApplied);
+        }
+        Ok(())
+    }
+
+    pub fn can_receive_medicine(&self) -> Result<(), HealingError> {
+        if self.dead {
+            return Err(HealingError::Dead);
+        }
+        if self.healing.medicine_today {
+            return Err(HealingError::MedicineAlreadyApplied);
+        }
+        Ok(())
+    }
+
+    /// Uses up today's First Aid attempt; only a successful one heals.
+    pub fn first_aid(&mut self, success: bool) -> Result<i32, HealingError> {
+        self.can_receive_first_aid()?;
+        self.healing.first_aid_today = true;
+        if !success {
+            return Ok(0);
+        }
+        self.healing.dying = false;
+        self.hp.modify(1);
+        Ok(1)
+    }
+
+    /// Uses up today's Medicine attempt; only a successful one heals.
+    pub fn medicine(&mut self, success: bool, amount: i32) -> Result<i32, HealingError> {
+        self.can_receive_medicine()?;
+        self.healing.medicine_today = true;
+        if !success {
+            return Ok(0);
+        }
+        self.healing.dying = false;
+        self.hp.modify(amount);
+        Ok(amount)
+    }
+
+    pub fn can_roll_recovery(&self) -> Result<(), HealingError> {
+        if self.dead {
+            return Err(HealingError::Dead);
+        }
+        if !self.major_wound {
+            return Err(HealingError::NoMajorWound);
+        }
+        if self.healing.days_since_recovery_roll < DAYS_BETWEEN_RECOVERY_ROLLS {
+            return Err(HealingError::RecoveryTooSoon(
+                DAYS_BETWEEN_RECOVERY_ROLLS - self.healing.days_since_recovery_roll,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Weekly recovery roll for major wound; successful roll heals and clears the wound.
+    pub fn natural_recovery(&mut self, success: bool, amount: i32) -> Result<i32, HealingError> {
+        self.can_roll_recovery()?;
+        self.healing.days_since_recovery_roll = 0;
+        if !success {
+            return Ok(0);
+        }
+        self.major_wound = false;
+        self.hp.modify(amount);
+        Ok(amount)
+    }
+
+    /// Starts a new in-game day: clears daily healing limits and heals naturally without major wound.
+    pub fn new_day(&mut self) -> i32 {
+        self.healing.first_aid_today = false;
+        self.healing.medicine_today = false;
+        self.last_damage = 0;
+        self.healing.days_since_recovery_roll += 1;
+        if self.dead || self.major_wound || self.healing.dying || self.hp.current == self.hp.max {
+            return 0;
+        }
+        let healed = self.natural_healing().min(self.hp.max - self.hp.current);
+        self.hp.modify(healed);
+        healed
+    }
+
+    pub fn reset(&mut self) {
+        self.healing = HealingState::default();
+        for episode in self.insanity_episodes.iter_mut() {
+            episode.active = false;
+        }
+        self.dead = false;
+        self.major_wound = false;
+        self.insane = false;
+        self.fragile_mind = false;
+        self.hp.reset();
+        self.sanity.reset();
+        self.luck.reset();
+        self.magic.reset();
+    }
+
+    pub fn status_hp(&self) -> String {
+        let mut comment: Option<&str> = None;
+        if self.dead {
+            comment = Some(" 💀");
+        } else if self.healing.dying {
+            comment = Some(" 🩸");
+        } else if self.major_wound {
+            comment = Some(" 🤕");
+        }
+
+        format!(
+            "❤️ **{}**/{}{}",
+            self.hp.current,
+            self.hp.max,
+            comment.unwrap_or_default(),
+        )
+    }
+
+    pub fn status_hp_raw(&self) -> String {
+        let mut comment: Option<&str> = None;
+        if self.dead {
+            comment = Some(" 💀");
+        } else if self.healing.dying {
+            comment = Some(" 🩸");
+        } else if self.major_wound {
+            comment = Some(" 🤕");
+        }
+
+        format!("{}/{}{}", self.hp.current, self.hp.max, comment.unwrap_or_default(),)
+    }
+
+    pub fn status_sanity(&self) -> String {
+        let mut comment: Option<&str> = None;
+        if self.insane {
+            comment = Some(" 🤯")
+        } else if self.fragile_mind {
+            comment = Some(" 😨")
+        }
+
+        format!(
+            "🧠 **{}**/{}{}",
+            self.sanity.current,
+            self.sanity.max,
+            comment.unwrap_or_default(),
+        )
+    }
+
+    pub fn status_sanity_raw(&self) -> String {
+        let mut comment: Option<&str> = None;
+        if self.insane {
+            comment = Some(" 🤯")
+        } else if self.fragile_mind {
+            comment = Some(" 😨")
+        }
+
+        format!("{}{}", self.sanity.current, comment.unwrap_or_default(),)
+    }
+
+    pub fn status_luck(&self) -> String {
+        format!("🍀 **{}**", self.luck.current)
+    }
+
+    pub fn status_luck_raw(&self) -> String {
+        format!("{}", self.luck.current)
+    }
+
+    pub fn status_magic(&self) -> String {
+        format!("🪄 **{}**/{}", self.magic.current, self.magic.max)
+    }
+
+    pub fn status_magic_raw(&self) -> String {
+        format!("{}/{}", self.magic.current, self.magic.max)
+    }
+
+    pub fn status(&self) -> String {
+        format!(
+            "{}\n{}\n{}\n{}",
+            self.status_hp(),
+            self.status_sanity(),
+            self.status_luck(),
+            self.status_magic()
+        )
+    }
+
+    pub fn status_one_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}",
+            self.status_hp(),
+            self.status_sanity(),
+            self.status_luck(),
+            self.status_magic()
+        )
+    }
+
+    pub fn status_named(&self) -> String {
+        format!("`{}`\n{}", self.name, self.status())
+    }
+
+    pub fn damage_modifier(&self, lang: LocaleLang) -> String {
+        locale_dice(lang, &Self::build_to_damage_modifier(self.build))
+    }
+
+    fn build_to_damage_modifier(build: i32) -> String {
+        let out: String = match build {
+            -2 => "-2".to_string(),
+            -1 => "-1".to_string(),
+            0 => "0".to_string(),
+            1 => "+1d4".to_string(),
+            v => format!("+{}d6", v - 1),
+        };
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(-2,"-2")]
+    #[case(-1,"-1")]
+    #[case(0, "0")]
+    #[case(1, "+1d4")]
+    #[case(2, "+1d6")]
+    #[case(3, "+2d6")]
+    #[case(4, "+3d6")]
+    #[case(5, "+4d6")]
+    #[case(6, "+5d6")]
+    #[case(7, "+6d6")]
+    #[case(8, "+7d6")]
+    fn test_damage_modifier(#[case] build: i32, #[case] dice: &str) {
+        assert_eq!(Character::build_to_damage_modifier(build), dice);
+    }
+
+    fn wounded_character() -> Character {
+        let mut character = Character {
+            hp: CharacterVariable::new_clamped(12),
+            ..Default::default()
+        };
+        character.hp.modify(-8);
+        character.major_wound = true;
+        character
+    }
+
+    #[test]
+    fn test_daily_healing_limits() {
+        let mut character = wounded_character();
+        assert_eq!(character.first_aid(true).ok(), Some(1));
+        assert!(character.first_aid(true).is_err());
+        assert_eq!(character.medicine(true, 3).ok(), Some(3));
+        assert!(character.medicine(true, 3).is_err());
+        assert_eq!(character.hp.current, 8);
+
+        assert_eq!(character.new_day(), 0);
+        assert_eq!(character.first_aid(true).ok(), Some(1));
+    }
+
+    #[test]
+    fn test_failed_treatment_uses_daily_attempt() {
+        let mut character = wounded_character();
+        assert_eq!(character.first_aid(false).ok(), Some(0));
+        assert!(character.first_aid(true).is_err());
+        assert_eq!(character.medicine(false, 3).ok(), Some(0));
+        assert!(character.medicine(true, 3).is_err());
+        assert_eq!(character.hp.current, 4);
+    }
+
+    #[test]
+    fn test_weekly_recovery_clears_major_wound() {
+        let mut character = wounded_character();
+        assert!(character.natural_recovery(true, 2).is_err());
+        for _ in 0..DAYS_BETWEEN_RECOVERY_ROLLS {
+            character.new_day();
+        }
+        assert_eq!(character.natural_recovery(false, 2).ok(), Some(0));
+        assert!(character.major_wound);
+        for _ in 0..DAYS_BETWEEN_RECOVERY_ROLLS {
+            character.new_day();
+        }
+        assert_eq!(character.natural_recovery(true, 2).ok(), Some(2));
+        assert!(!character.major_wound);
+        assert_eq!(character.new_day(), 1);
+        assert_eq!(character.hp.current, 7);
+    }
+
+    #[test]
+    fn test_remove_custom_skill() {
+        let mut character = Character::default();
+        let (key, dodge) = skill_map_wrapper(LocaleTag::Dodge, 20).unwrap();
+        character.skills.insert(key, dodge);
+        assert!(character.add_skill("Stage Magic", 15).is_ok());
+
+        assert!(character.remove_skill("stage magic").is_ok());
+        assert!(character.get_skill("Stage Magic").is_none());
+        assert!(character.remove_skill("Stage Magic").is_err());
+        assert!(character.remove_skill("Dodge").is_err());
+        assert!(character.get_skill("Dodge").is_some());
+    }
+}
@@ -0,0 +1,701 @@
+use crate::{
+    character::{Character, SkillError},
+    locale::{LocaleEntry, LocaleLang, LocaleTag, locale_entry_by_tag, locale_text_by_tag_lang, locale_text_fmt},
+};
+
+/// Pulp heroes spend these on archetype skills on top of occupation and personal interest points.
+pub const ARCHETYPE_SKILL_POINTS: i32 = 100;
+pub const TALENT_LUCK_COST: i32 = 10;
+
+pub struct PulpArchetype {
+    pub tag: LocaleTag,
+    pub core_characteristics: &'static [LocaleTag],
+    pub skills: &'static [LocaleTag],
+}
+
+impl PulpArchetype {
+    /// Chosen core characteristic; archetypes with a single option don't need a choice.
+    pub fn core_characteristic(&self, choice: Option<&str>) -> Result<LocaleTag, PulpError> {
+        match choice {
+            None => Ok(self.core_characteristics[0]),
+            Some(choice) => self
+                .core_characteristics
+                .iter()
+                .copied()
+                .find(|tag| locale_entry_by_tag(*tag).equals_ignore_case(choice))
+                .ok_or(PulpError::NotCoreCharacteristic(choice.to_string())),
+        }
+    }
+
+    /// Specializations count as archetype skills, e.g. `Firearms (Handgun)` for `Firearms`.
+    pub fn has_skill(&self, skill_name: &LocaleEntry) -> bool {
+        let base_name = |name: &str| name.split(" (").next().unwrap_or_default().to_lowercase();
+        self.skills
+            .iter()
+            .any(|tag| base_name(locale_entry_by_tag(*tag).en()) == base_name(skill_name.en()))
+    }
+}
+
+pub fn pulp_archetype(tag: LocaleTag) -> Option<&'static PulpArchetype> {
+    PULP_ARCHETYPES.iter().find(|archetype| archetype.tag == tag)
+}
+
+const PULP_ARCHETYPES: [PulpArchetype; 22] = [
+    PulpArchetype {
+        tag: LocaleTag::Adventurer,
+        core_characteristics: &[LocaleTag::Dexterity, LocaleTag::Appearance],
+        skills: &[
+            LocaleTag::Climb,
+            LocaleTag::Diving,
+            LocaleTag::DriveAuto,
+            LocaleTag::FirstAid,
+            LocaleTag::Fighting,
+            LocaleTag::Firearms,
+            LocaleTag::Jump,
+            LocaleTag::LanguageOther,
+            LocaleTag::MechanicalRepair,
+            LocaleTag::Pilot,
+            LocaleTag::Ride,
+            LocaleTag::Stealth,
+            LocaleTag::Survival,
+            LocaleTag::Swim,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Beefcake,
+        core_characteristics: &[LocaleTag::Strength],
+        skills: &[
+            LocaleTag::Climb,
+            LocaleTag::FightingBrawl,
+            LocaleTag::Intimidate,
+            LocaleTag::Listen,
+            LocaleTag::MechanicalRepair,
+            LocaleTag::Psychology,
+            LocaleTag::Swim,
+            LocaleTag::Throw,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::BonVivant,
+        core_characteristics: &[LocaleTag::Appearance],
+        skills: &[
+            LocaleTag::Appraise,
+            LocaleTag::ArtCraft,
+            LocaleTag::Charm,
+            LocaleTag::FastTalk,
+            LocaleTag::LanguageOther,
+            LocaleTag::Listen,
+            LocaleTag::SpotHidden,
+            LocaleTag::Psychology,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::ColdBlooded,
+        core_characteristics: &[LocaleTag::Intelligence],
+        skills: &[
+            LocaleTag::ArtCraft,
+            LocaleTag::Disguise,
+            LocaleTag::Fighting,
+            LocaleTag::Firearms,
+            LocaleTag::FirstAid,
+            LocaleTag::History,
+            LocaleTag::Intimidate,
+            LocaleTag::Law,
+            LocaleTag::Listen,
+            LocaleTag::MechanicalRepair,
+            LocaleTag::Psychology,
+            LocaleTag::Stealth,
+            LocaleTag::Survival,
+            LocaleTag::Track,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Dreamer,
+        core_characteristics: &[LocaleTag::Power],
+        skills: &[
+            LocaleTag::ArtCraft,
+            LocaleTag::Charm,
+            LocaleTag::History,
+            LocaleTag::LanguageOther,
+            LocaleTag::LibraryUse,
+            LocaleTag::Listen,
+            LocaleTag::NaturalWorld,
+            LocaleTag::Occult,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Egghead,
+        core_characteristics: &[LocaleTag::Intelligence, LocaleTag::Education],
+        skills: &[
+            LocaleTag::Anthropology,
+            LocaleTag::Appraise,
+            LocaleTag::ComputerUse,
+            LocaleTag::ElectricalRepair,
+            LocaleTag::LanguageOther,
+            LocaleTag::LibraryUse,
+            LocaleTag::MechanicalRepair,
+            LocaleTag::OperateHeavyMachinery,
+            LocaleTag::Science,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Explorer,
+        core_characteristics: &[LocaleTag::Dexterity, LocaleTag::Power],
+        skills: &[
+            LocaleTag::AnimalHandling,
+            LocaleTag::Anthropology,
+            LocaleTag::Archeology,
+            LocaleTag::Climb,
+            LocaleTag::FightingBrawl,
+            LocaleTag::FirstAid,
+            LocaleTag::Jump,
+            LocaleTag::LanguageOther,
+            LocaleTag::NaturalWorld,
+            LocaleTag::Navigate,
+            LocaleTag::Pilot,
+            LocaleTag::Ride,
+            LocaleTag::Stealth,
+            LocaleTag::Survival,
+            LocaleTag::Track,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::FemmeFatale,
+        core_characteristics: &[LocaleTag::Appearance, LocaleTag::Intelligence],
+        skills: &[
+            LocaleTag::ArtCraft,
+            LocaleTag::Charm,
+            LocaleTag::Disguise,
+            LocaleTag::FastTalk,
+            LocaleTag::Firearms,
+            LocaleTag::Listen,
+            LocaleTag::Persuade,
+            LocaleTag::Psychology,
+            LocaleTag::SpotHidden,
+            LocaleTag::Stealth,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::GreaseMonkey,
+        core_characteristics: &[LocaleTag::Intelligence],
+        skills: &[
+            LocaleTag::Appraise,
+            LocaleTag::ArtCraft,
+            LocaleTag::FightingBrawl,
+            LocaleTag::DriveAuto,
+            LocaleTag::ElectricalRepair,
+            LocaleTag::Locksmith,
+            LocaleTag::MechanicalRepair,
+            LocaleTag::OperateHeavyMachinery,
+            LocaleTag::SpotHidden,
+            LocaleTag::Throw,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::HardBoiled,
+        core_characteristics: &[LocaleTag::Constitution],
+        skills: &[
+            LocaleTag::ArtCraft,
+            LocaleTag::Fighting,
+            LocaleTag::Firearms,
+            LocaleTag::DriveAuto,
+            LocaleTag::FastTalk,
+            LocaleTag::Intimidate,
+            LocaleTag::Law,
+            LocaleTag::Listen,
+            LocaleTag::Locksmith,
+            LocaleTag::SleightOfHand,
+            LocaleTag::SpotHidden,
+            LocaleTag::Stealth,
+            LocaleTag::Throw,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Harlequin,
+        core_characteristics: &[LocaleTag::Appearance],
+        skills: &[
+            LocaleTag::ArtCraft,
+            LocaleTag::Charm,
+            LocaleTag::Disguise,
+            LocaleTag::FastTalk,
+            LocaleTag::Jump,
+            LocaleTag::LanguageOther,
+            LocaleTag::Listen,
+            LocaleTag::Persuade,
+            LocaleTag::Psychology,
+            LocaleTag::SleightOfHand,
+            LocaleTag::Stealth,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Hunter,
+        core_characteristics: &[LocaleTag::Intelligence, LocaleTag::Constitution],
+        skills: &[
+            LocaleTag::AnimalHandling,
+            LocaleTag::Firearms,
+            LocaleTag::FirstAid,
+            LocaleTag::Listen,
+            LocaleTag::NaturalWorld,
+            LocaleTag::Navigate,
+            LocaleTag::SpotHidden,
+            LocaleTag::Stealth,
+            LocaleTag::Survival,
+            LocaleTag::Track,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Mystic,
+        core_characteristics: &[LocaleTag::Power],
+        skills: &[
+            LocaleTag::ArtCraft,
+            LocaleTag::Hypnosis,
+            LocaleTag::LanguageOther,
+            LocaleTag::NaturalWorld,
+            LocaleTag::Occult,
+            LocaleTag::Science,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Outsider,
+        core_characteristics: &[LocaleTag::Intelligence, LocaleTag::Constitution],
+        skills: &[
+            LocaleTag::ArtCraft,
+            LocaleTag::AnimalHandling,
+            LocaleTag::Fighting,
+            LocaleTag::FirstAid,
+            LocaleTag::Intimidate,
+            LocaleTag::LanguageOther,
+            LocaleTag::Listen,
+            LocaleTag::Medicine,
+            LocaleTag::Navigate,
+            LocaleTag::Stealth,
+            LocaleTag::Survival,
+            LocaleTag::Track,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Rogue,
+        core_characteristics: &[LocaleTag::Dexterity, LocaleTag::Appearance],
+        skills: &[
+            LocaleTag::Appraise,
+            LocaleTag::ArtCraft,
+            LocaleTag::Charm,
+            LocaleTag::Disguise,
+            LocaleTag::FastTalk,
+            LocaleTag::Law,
+            LocaleTag::Locksmith,
+            LocaleTag::Psychology,
+            LocaleTag::ReadLips,
+            LocaleTag::SleightOfHand,
+            LocaleTag::SpotHidden,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Scholar,
+        core_characteristics: &[LocaleTag::Education],
+        skills: &[
+            LocaleTag::Accounting,
+            LocaleTag::Anthropology,
+            LocaleTag::Archeology,
+            LocaleTag::History,
+            LocaleTag::LanguageOther,
+            LocaleTag::LibraryUse,
+            LocaleTag::Medicine,
+            LocaleTag::NaturalWorld,
+            LocaleTag::Occult,
+            LocaleTag::Science,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Seeker,
+        core_characteristics: &[LocaleTag::Intelligence],
+        skills: &[
+            LocaleTag::Accounting,
+            LocaleTag::Appraise,
+            LocaleTag::Disguise,
+            LocaleTag::History,
+            LocaleTag::Law,
+            LocaleTag::LibraryUse,
+            LocaleTag::Listen,
+            LocaleTag::Occult,
+            LocaleTag::Psychology,
+            LocaleTag::Science,
+            LocaleTag::SpotHidden,
+            LocaleTag::Stealth,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Sidekick,
+        core_characteristics: &[LocaleTag::Dexterity, LocaleTag::Constitution],
+        skills: &[
+            LocaleTag::AnimalHandling,
+            LocaleTag::ArtCraft,
+            LocaleTag::Climb,
+            LocaleTag::ElectricalRepair,
+            LocaleTag::FastTalk,
+            LocaleTag::FirstAid,
+            LocaleTag::Jump,
+            LocaleTag::LibraryUse,
+            LocaleTag::Listen,
+            LocaleTag::Navigate,
+            LocaleTag::Science,
+            LocaleTag::SpotHidden,
+            LocaleTag::Stealth,
+            LocaleTag::Track,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Steadfast,
+        core_characteristics: &[LocaleTag::Constitution, LocaleTag::Power],
+        skills: &[
+            LocaleTag::FirstAid,
+            LocaleTag::Intimidate,
+            LocaleTag::Listen,
+            LocaleTag::Navigate,
+            LocaleTag::Persuade,
+            LocaleTag::Psychology,
+            LocaleTag::Ride,
+            LocaleTag::SpotHidden,
+            LocaleTag::Stealth,
+            LocaleTag::Survival,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::Swashbuckler,
+        core_characteristics: &[LocaleTag::Dexterity, LocaleTag::Appearance],
+        skills: &[
+            LocaleTag::Charm,
+            LocaleTag::Climb,
+            LocaleTag::Fighting,
+            LocaleTag::Firearms,
+            LocaleTag::Jump,
+            LocaleTag::LanguageOther,
+            LocaleTag::Pilot,
+            LocaleTag::Ride,
+            LocaleTag::Swim,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::ThrillSeeker,
+        core_characteristics: &[LocaleTag::Dexterity, LocaleTag::Power],
+        skills: &[
+            LocaleTag::ArtCraft,
+            LocaleTag::Climb,
+            LocaleTag::Diving,
+            LocaleTag::DriveAuto,
+            LocaleTag::Jump,
+            LocaleTag::MechanicalRepair,
+            LocaleTag::Navigate,
+            LocaleTag::Pilot,
+            LocaleTag::Ride,
+            LocaleTag::Science,
+            LocaleTag::Survival,
+            LocaleTag::Swim,
+        ],
+    },
+    PulpArchetype {
+        tag: LocaleTag::TwoFisted,
+        core_characteristics: &[LocaleTag::Strength, LocaleTag::Constitution],
+        skills: &[
+            LocaleTag::DriveAuto,
+            LocaleTag::Fighting,
+            LocaleTag::Firearms,
+            LocaleTag::FirstAid,
+            LocaleTag::Intimidate,
+            LocaleTag::Listen,
+            LocaleTag::MechanicalRepair,
+            LocaleTag::SpotHidden,
+            LocaleTag::Swim,
+            LocaleTag::Throw,
+        ],
+    },
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TalentEffect {
+    /// Bonus die on rolls of listed skills or characteristics.
+    BonusDie(&'static [LocaleTag]),
+    /// Luck spent to shrug off up to given damage of the latest hit.
+    DamageReduction(i32),
+    /// Dice added to Luck recovery.
+    ExtraLuckRecovery(&'static str),
+    /// Luck spent to re-roll a failed roll of listed skills.
+    ReRoll(&'static [LocaleTag]),
+    /// Hit Points healed naturally per day.
+    NaturalHealing(i32),
+}
+
+/// Talents without an entry are resolved narratively by the Keeper.
+pub fn talent_effect(tag: LocaleTag) -> Option<TalentEffect> {
+    match tag {
+        LocaleTag::KeenVision => Some(TalentEffect::BonusDie(&[LocaleTag::SpotHidden])),
+        LocaleTag::KeenHearing => Some(TalentEffect::BonusDie(&[LocaleTag::Listen])),
+        LocaleTag::SmoothTalker => Some(TalentEffect::BonusDie(&[LocaleTag::Charm])),
+        LocaleTag::Scary => Some(TalentEffect::BonusDie(&[LocaleTag::Intimidate])),
+        LocaleTag::Shadow => Some(TalentEffect::BonusDie(&[LocaleTag::Stealth])),
+        LocaleTag::MasterOfDisguise => Some(TalentEffect::BonusDie(&[LocaleTag::Disguise])),
+        LocaleTag::Handy => Some(TalentEffect::BonusDie(&[
+            LocaleTag::ElectricalRepair,
+            LocaleTag::MechanicalRepair,
+            LocaleTag::OperateHeavyMachinery,
+        ])),
+        LocaleTag::Linguist => Some(TalentEffect::BonusDie(&[LocaleTag::LanguageOther])),
+        LocaleTag::Endurance => Some(TalentEffect::BonusDie(&[LocaleTag::Constitution])),
+        LocaleTag::PowerLifter => Some(TalentEffect::BonusDie(&[LocaleTag::Strength])),
+        LocaleTag::StrongWilled => Some(TalentEffect::BonusDie(&[LocaleTag::Power])),
+        LocaleTag::SharpWitted => Some(TalentEffect::BonusDie(&[LocaleTag::Intelligence])),
+        LocaleTag::PhotographicMemory => Some(TalentEffect::BonusDie(&[LocaleTag::Education])),
+        LocaleTag::ToughGuy => Some(TalentEffect::DamageReduction(5)),
+        LocaleTag::Lucky => Some(TalentEffect::ExtraLuckRecovery("1d10")),
+        LocaleTag::BeadyEye => Some(TalentEffect::ReRoll(&[LocaleTag::SpotHidden, LocaleTag::LibraryUse])),
+        LocaleTag::QuickHealer => Some(TalentEffect::NaturalHealing(3)),
+        _ => None,
+    }
+}
+
+pub enum PulpError {
+    NoArchetype,
+    NotArchetypeSkill(String),
+    NotEnoughArchetypePoints(i32),
+    InvalidArchetypePoints(i32),
+    NotCoreCharacteristic(String),
+    NoTalent(LocaleTag),
+    NotEnoughLuck(i32),
+    NotDying,
+    NoRecentDamage,
+    CantAvoidDeath,
+    Skill(SkillError),
+}
+
+impl PulpError {
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        match self {
+            PulpError::NoArchetype => locale_text_by_tag_lang(lang, LocaleTag::NoPulpArchetype),
+            PulpError::NotArchetypeSkill(n) => locale_text_fmt(lang, LocaleTag::NotArchetypeSkill, &[n]),
+            PulpError::NotEnoughArchetypePoints(n) => locale_text_fmt(lang, LocaleTag::NotEnoughArchetypePoints, &[n]),
+            PulpError::InvalidArchetypePoints(n) => locale_text_fmt(lang, LocaleTag::InvalidArchetypePoints, &[n]),
+            PulpError::NotCoreCharacteristic(n) => locale_text_fmt(lang, LocaleTag::NotCoreCharacteristic, &[n]),
+            PulpError::NoTalent(t) => {
+                locale_text_fmt(lang, LocaleTag::NoSuchTalent, &[&locale_text_by_tag_lang(lang, *t)])
+            }
+            PulpError::NotEnoughLuck(n) => locale_text_fmt(lang, LocaleTag::NotEnoughLuck, &[n]),
+            PulpError::NotDying => locale_text_by_tag_lang(lang, LocaleTag::NotDying),
+            PulpError::NoRecentDamage => locale_text_by_tag_lang(lang, LocaleTag::NoRecentDamage),
+            PulpError::CantAvoidDeath => locale_text_by_tag_lang(lang, LocaleTag::CantAvoidDeath),
+            PulpError::Skill(e) => e.to_string(lang),
+        }
+    }
+}
+
+impl Character {
+    pub fn talent_effects(&self) -> impl Iterator<Item = TalentEffect> + '_ {
+        self.pulp_talents
+            .iter()
+            .filter_map(|talent| talent.to_enum().and_then(talent_effect))
+    }
+
+    /// Bonus dice from talents for a roll of given skill or characteristic.
+    pub fn talent_bonus_dice(&self, name: &LocaleEntry) -> i32 {
+        self.talent_effects()
+            .filter(|effect| match effect {
+                TalentEffect::BonusDie(tags) => tags.iter().any(|tag| locale_entry_by_tag(*tag).en() == name.en()),
+                _ => false,
+            })
+            .count() as i32
+    }
+
+    pub fn can_talent_reroll(&self, name: &LocaleEntry) -> bool {
+        self.talent_effects().any(|effect| match effect {
+            TalentEffect::ReRoll(tags) => tags.iter().any(|tag| locale_entry_by_tag(*tag).en() == name.en()),
+            _ => false,
+        })
+    }
+
+    pub fn talent_luck_recovery(&self) -> Option<&'static str> {
+        self.talent_effects().find_map(|effect| match effect {
+            TalentEffect::ExtraLuckRecovery(dice) => Some(dice),
+            _ => None,
+        })
+    }
+
+    pub fn natural_healing(&self) -> i32 {
+        self.talent_effects()
+            .filter_map(|effect| match effect {
+                TalentEffect::NaturalHealing(amount) => Some(amount),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(1)
+    }
+
+    pub fn spend_talent_luck(&mut self) -> Result<(), PulpError> {
+        if self.luck.current < TALENT_LUCK_COST {
+            return Err(PulpError::NotEnoughLuck(TALENT_LUCK_COST));
+        }
+        self.luck.modify(-TALENT_LUCK_COST);
+        Ok(())
+    }
+
+    /// Tough Guy: spends Luck to heal back the latest damage taken; each hit can be shrugged off once.
+    pub fn shrug_off(&mut self) -> Result<i32, PulpError> {
+        let max = self
+            .talent_effects()
+            .find_map(|effect| match effect {
+                TalentEffect::DamageReduction(max) => Some(max),
+                _ => None,
+            })
+            .ok_or(PulpError::NoTalent(LocaleTag::ToughGuy))?;
+        if self.last_damage <= 0 {
+            return Err(PulpError::NoRecentDamage);
+        }
+        self.spend_talent_luck()?;
+        let healed = self.last_damage.min(max);
+        self.last_damage = 0;
+        self.hp.modify(healed);
+        self.healing.dying = self.hp.current == 0 && self.major_wound;
+        Ok(healed)
+    }
+
+    /// Spends all remaining Luck to survive with 1 Hit Point.
+    pub fn avoid_death(&mut self) -> Result<i32, PulpError> {
+        if !self.ruleset().ruleset().luck_avoids_death() {
+            return Err(PulpError::CantAvoidDeath);
+        }
+        if !self.dead && !self.healing.dying {
+            return Err(PulpError::NotDying);
+        }
+        if self.luck.current <= 0 {
+            return Err(PulpError::NotEnoughLuck(1));
+        }
+        let spent = self.luck.current;
+        self.luck.modify(-spent);
+        self.dead = false;
+        self.healing.dying = false;
+        self.hp.modify(1 - self.hp.current);
+        Ok(spent)
+    }
+
+    pub fn spend_archetype_points(&mut self, skill_name: &str, points: i32) -> Result<i32, PulpError> {
+        let archetype = self
+            .pulp_archetype
+            .as_ref()
+            .and_then(|archetype| archetype.to_enum())
+            .and_then(pulp_archetype)
+            .ok_or(PulpError::NoArchetype)?;
+        if points <= 0 {
+            return Err(PulpError::InvalidArchetypePoints(points));
+        }
+        if points > self.archetype_points {
+            return Err(PulpError::NotEnoughArchetypePoints(self.archetype_points));
+        }
+        let skill = self
+            .get_mut_skill(skill_name)
+            .ok_or(PulpError::Skill(SkillError::NoSuchSkill(skill_name.to_string())))?;
+        if !archetype.has_skill(&skill.name) {
+            return Err(PulpError::NotArchetypeSkill(skill_name.to_string()));
+        }
+        skill.modify(points).map_err(PulpError::Skill)?;
+        let value = skill.value;
+        self.archetype_points -= points;
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        character::{Attributes, hp_impl},
+        ruleset::RulesetKind,
+    };
+    use rstest::rstest;
+
+    fn pulp_hero(archetype: LocaleTag, talents: &[LocaleTag]) -> Character {
+        let attributes = Attributes::new(60, 50, 60, 50, 50, 70, 50, 60).unwrap();
+        let mut character = Character::new(
+            "Hero",
+            &None,
+            attributes,
+            50,
+            Some(locale_entry_by_tag(archetype).clone()),
+            RulesetKind::Pulp,
+        )
+        .unwrap();
+        character.pulp_talents = talents.iter().map(|tag| locale_entry_by_tag(*tag).clone()).collect();
+        character
+    }
+
+    #[test]
+    fn every_archetype_has_data() {
+        for (tag, _) in crate::locale::LOCALE_PULP_ARCHETYPES.iter() {
+            assert!(pulp_archetype(*tag).is_some(), "{tag:?}");
+        }
+    }
+
+    #[rstest]
+    #[case(LocaleTag::Egghead, None, Ok(LocaleTag::Intelligence))]
+    #[case(LocaleTag::Egghead, Some("education"), Ok(LocaleTag::Education))]
+    #[case(LocaleTag::Egghead, Some("strength"), Err(()))]
+    fn archetype_core_characteristic(
+        #[case] archetype: LocaleTag,
+        #[case] choice: Option<&str>,
+        #[case] expected: Result<LocaleTag, ()>,
+    ) {
+        let result = pulp_archetype(archetype)
+            .unwrap()
+            .core_characteristic(choice)
+            .map_err(|_| ());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn talents_give_bonus_dice() {
+        let character = pulp_hero(LocaleTag::Seeker, &[LocaleTag::KeenVision, LocaleTag::SharpWitted]);
+        assert_eq!(
+            character.talent_bonus_dice(locale_entry_by_tag(LocaleTag::SpotHidden)),
+            1
+        );
+        assert_eq!(
+            character.talent_bonus_dice(locale_entry_by_tag(LocaleTag::Intelligence)),
+            1
+        );
+        assert_eq!(character.talent_bonus_dice(locale_entry_by_tag(LocaleTag::Listen)), 0);
+    }
+
+    #[test]
+    fn tough_guy_shrugs_off_damage() {
+        let mut character = pulp_hero(LocaleTag::TwoFisted, &[LocaleTag::ToughGuy]);
+        assert!(character.shrug_off().is_err());
+        assert!(hp_impl(&mut character, "-8", LocaleLang::ENGLISH).is_ok());
+        assert_eq!(character.shrug_off().ok(), Some(5));
+        assert_eq!(character.luck.current, 40);
+        assert!(character.shrug_off().is_err());
+        assert_eq!(character.luck.current, 40);
+        assert!(pulp_hero(LocaleTag::TwoFisted, &[]).shrug_off().is_err());
+    }
+
+    #[test]
+    fn luck_avoids_death() {
+        let mut character = pulp_hero(LocaleTag::Steadfast, &[]);
+        assert!(character.avoid_death().is_err());
+        character.hp.modify(-character.hp.current);
+        character.dead = true;
+        assert_eq!(character.avoid_death().ok(), Some(50));
+        assert!(!character.dead);
+        assert_eq!(character.hp.current, 1);
+        assert_eq!(character.luck.current, 0);
+    }
+
+    #[test]
+    fn archetype_points_only_for_archetype_skills() {
+        let mut character = pulp_hero(LocaleTag::Scholar, &[]);
+        let history = character.get_skill("History").unwrap().value;
+        assert_eq!(character.spend_archetype_points("History", 40).ok(), Some(history + 40));
+        assert_eq!(character.archetype_points, 60);
+        assert!(character.spend_archetype_points("Climb", 10).is_err());
+        assert!(character.spend_archetype_points("Occult", 70).is_err());
+        assert!(character.spend_archetype_points("History", -40).is_err());
+        assert!(character.spend_archetype_points("History", 0).is_err());
+        assert_eq!(character.archetype_points, 60);
+    }
+}
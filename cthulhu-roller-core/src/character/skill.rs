@@ -1,5 +1,6 @@
 use crate::{
-    locale::{LocaleEntry, LocaleLang, LocaleTag},
+    locale::{LocaleEntry, LocaleLang, LocaleTag, locale_text_by_tag_lang, locale_text_fmt},
+    roller::{croll::CrollResult, modifier_dice::ModifierDiceType},
     types::SkillValMap,
 };
 use lazy_static::lazy_static;
@@ -75,19 +76,11 @@ pub enum SkillError {
 
 impl SkillError {
     pub fn to_string(&self, lang: LocaleLang) -> String {
-        match (self, lang) {
-            (SkillError::AlreadyExists(n), LocaleLang::Polski) => format!("Umiejętność `{n}` już istnieje."),
-            (SkillError::AlreadyExists(n), LocaleLang::English) => format!("Skill `{n}` already exists."),
-            (SkillError::CantRemove(n), LocaleLang::Polski) => {
-                format!("Nie można usunąć podstawowej umiejętności `{n}`.")
-            }
-            (SkillError::CantRemove(n), LocaleLang::English) => format!("Can't remove basic skill `{n}`."),
-            (SkillError::Constant(n), LocaleLang::Polski) => {
-                format!("Umiejętności `{}` nie można modyfikować.", n.pl)
-            }
-            (SkillError::Constant(n), LocaleLang::English) => format!("Skill `{}` can't be modified.", n.en),
-            (SkillError::NoSuchSkill(n), LocaleLang::Polski) => format!("Nie ma takiej umiejętności `{n}`."),
-            (SkillError::NoSuchSkill(n), LocaleLang::English) => format!("No such skill `{n}`"),
+        match self {
+            SkillError::AlreadyExists(n) => locale_text_fmt(lang, LocaleTag::SkillAlreadyExists, &[n]),
+            SkillError::CantRemove(n) => locale_text_fmt(lang, LocaleTag::CantRemoveBasicSkill, &[n]),
+            SkillError::Constant(n) => locale_text_fmt(lang, LocaleTag::SkillCantBeModified, &[&n.get(lang)]),
+            SkillError::NoSuchSkill(n) => format!("{}: `{n}`", locale_text_by_tag_lang(lang, LocaleTag::NoSuchSkill)),
         }
     }
 }
@@ -175,3 +168,12 @@ lazy_static! {
     .into_iter()
     .collect();
 }
+
+/// Rolls made with bonus dice never mark a skill for improvement.
+pub fn marks_skill(croll_result: &CrollResult) -> bool {
+    croll_result.ruleset.ruleset().marks_skill(croll_result.success_level)
+        && croll_result
+            .modifier_dice
+            .as_ref()
+            .is_none_or(|modifier_dice| modifier_dice.dice_type == ModifierDiceType::Penalty)
+}
@@ -0,0 +1,22 @@
+use crate::locale::{LocaleLang, LocaleTag, locale_text_fmt};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ActiveSpell {
+    pub name: String,
+    pub remaining_hours: i32,
+}
+
+pub enum SpellError {
+    UnknownSpell(String),
+    NotEnoughMagicPoints(i32),
+}
+
+impl SpellError {
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        match self {
+            SpellError::UnknownSpell(name) => locale_text_fmt(lang, LocaleTag::UnknownSpell, &[name]),
+            SpellError::NotEnoughMagicPoints(mp) => locale_text_fmt(lang, LocaleTag::NotEnoughMagicPoints, &[mp]),
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{
+    Error,
+    character::{BoutKind, Character, InsanityKind},
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
+    message::MessageContent,
+    roller::{
+        croll::{CrollResult, croll_impl},
+        dice_rng::RealRng,
+        roll::roll_query,
+        success_level::SuccessLevel,
+    },
+};
+
+pub fn hp_impl(character: &mut Character, delta: &str, lang: LocaleLang) -> Result<MessageContent, Error> {
+    let roll_result;
+    {
+        let mut rng = RealRng::new();
+        roll_result = roll_query(&mut rng, delta)?;
+    }
+    let delta_res = roll_result.result_real();
+
+    let hp_before = character.hp.current;
+    character.hp.modify(delta_res);
+    if delta_res < 0 {
+        character.last_damage = hp_before - character.hp.current;
+        character.damage_taken = character.damage_taken.saturating_add(character.last_damage);
+    }
+
+    let mut additional_desc = String::new();
+    if delta_res < -(character.hp.max) {
+        character.dead = true;
+        character.major_wound = false;
+        additional_desc = format!("\n\n**{}**", locale_text_by_tag_lang(lang, LocaleTag::DeathInevitable));
+    } else {
+        if delta_res <= -(character.hp.max / 2) {
+            if !character.major_wound {
+                character.healing.days_since_recovery_roll = 0;
+            }
+            character.major_wound = true;
+            additional_desc = format!("\n\n**{}**", locale_text_by_tag_lang(lang, LocaleTag::MajorWound));
+            if character.hp.current > 0 {
+                additional_desc = format!(
+                    "{}\n{}\n{}",
+                    additional_desc,
+                    locale_text_by_tag_lang(lang, LocaleTag::YouFell),
+                    locale_text_by_tag_lang(lang, LocaleTag::RollConCheckBlackOut)
+                )
+                .to_string();
+            }
+        }
+
+        character.healing.dying = character.hp.current == 0 && character.major_wound;
+
+        if character.hp.current == 0 {
+            if character.major_wound {
+                additional_desc = format!(
+                    "{}\n**{}**\n{}\n{}\n{}",
+                    additional_desc,
+                    locale_text_by_tag_lang(lang, LocaleTag::Agony),
+                    locale_text_by_tag_lang(lang, LocaleTag::YouFell),
+                    locale_text_by_tag_lang(lang, LocaleTag::YouBlackOut),
+                    locale_text_by_tag_lang(lang, LocaleTag::RollConCheckDie)
+                );
+            } else {
+                additional_desc = format!(
+                    "{}\n**{}**\n{}\n{}",
+                    additional_desc,
+                    locale_text_by_tag_lang(lang, LocaleTag::KnockOut),
+                    locale_text_by_tag_lang(lang, LocaleTag::YouFell),
+                    locale_text_by_tag_lang(lang, LocaleTag::YouBlackOut),
+                );
+            }
+        }
+    }
+
+    let mc = MessageContent {
+        title: format!("`{}`", character.name),
+        description: format!(
+            "❤️ **{:+}** ({:+})\n{}{}",
+            delta_res,
+            delta,
+            character.status_hp(),
+            additional_desc
+        ),
+        ..Default::default()
+    };
+
+    Ok(mc)
+}
+
+/// Applies Sanity change; returned flag tells whether INT check for temporary insanity should be offered.
+pub fn san_impl(
+    character: &mut Character,
+    delta: &str,
+    lang: LocaleLang,
+    bout_kind: BoutKind,
+) -> Result<(MessageContent, bool), Error> {
+    let mut rng = RealRng::new();
+    let roll_result = roll_query(&mut rng, delta)?;
+    let delta_res = roll_result.result_real();
+
+    let sanity_before = character.sanity.current;
+    character.sanity.modify(delta_res);
+    if delta_res < 0 {
+        character.sanity_lost = character
+            .sanity_lost
+            .saturating_add(sanity_before - character.sanity.current);
+    }
+
+    let mut int_check = false;
+    let mut additional_desc = String::new();
+    if character.sanity.current == 0 {
+        if !character.insane {
+            character.start_insanity(&mut rng, InsanityKind::Permanent, None, None);
+        }
+        additional_desc = format!("\n\n**{}**", locale_text_by_tag_lang(lang, LocaleTag::MindShattered));
+    } else if character.fragile_mind && delta_res < 0 {
+        additional_desc = format!("\n\n**{}**", locale_text_by_tag_lang(lang, LocaleTag::TempInsanity));
+        if let Some(bout) = character.add_bout(&mut rng, bout_kind) {
+            additional_desc = format!("{}\n{}", additional_desc, bout.to_string(lang));
+        }
+    } else if delta_res <= -5 {
+        int_check = true;
+        additional_desc = format!(
+            "\n\n**{}**\n{}",
+            locale_text_by_tag_lang(lang, LocaleTag::TempInsanityThreat),
+            locale_text_by_tag_lang(lang, LocaleTag::RollIntCheck)
+        );
+    }
+
+    if (character.sanity.current as f32 / character.sanity.initial as f32) < 0.8 {
+        int_check = false;
+        additional_desc = format!("\n\n**{}**", locale_text_by_tag_lang(lang, LocaleTag::IndefInsanity));
+        if !character.insane
+            && !character
+                .active_insanity()
+                .is_some_and(|e| e.kind == InsanityKind::Indefinite)
+        {
+            let episode = character.start_insanity(&mut rng, InsanityKind::Indefinite, None, Some(bout_kind));
+            additional_desc = format!("{}\n{}", additional_desc, episode.to_string(lang));
+        }
+        character.fragile_mind = true;
+    }
+
+    let mc = MessageContent {
+        title: format!("`{}`", character.name),
+        description: format!(
+            "🧠 **{:+}** ({:+})\n{}{}",
+            delta_res,
+            delta,
+            character.status_sanity(),
+            additional_desc
+        ),
+        ..Default::default()
+    };
+
+    Ok((mc, int_check))
+}
+
+pub fn luck_impl(character: &mut Character, delta: &str, lang: LocaleLang) -> Result<MessageContent, Error> {
+    let roll_result;
+    {
+        let mut rng = RealRng::new();
+        roll_result = roll_query(&mut rng, delta)?;
+    }
+    let delta_res = roll_result.result_real();
+
+    if delta_res < -character.luck.current {
+        return Err(locale_text_by_tag_lang(lang, LocaleTag::CantSpendLuck).into());
+    }
+
+    character.luck.modify(delta_res);
+
+    Ok(MessageContent {
+        title: format!("`{}`", character.name),
+        description: format!("🍀 **{:+}** ({:+})\n{}", delta_res, delta, character.status_luck()),
+        ..Default::default()
+    })
+}
+
+/// Weekly CON roll for a major wound; heals 1D3, or 2D3 on an extreme success.
+/// Returns the roll, the healing dice and the Hit Points healed.
+pub fn recovery_roll_impl(
+    character: &mut Character,
+    lang: LocaleLang,
+) -> Result<(CrollResult, &'static str, i32), Error> {
+    character
+        .can_roll_recovery()
+        .map_err(|e| Error::from(e.to_string(lang)))?;
+
+    let croll_result = croll_impl(&character.attributes.constitution().to_string(), character.ruleset())?;
+    let success = croll_result.success_level >= SuccessLevel::Success;
+    let query = if croll_result.success_level >= SuccessLevel::ExtremeSuccess {
+        "2d3"
+    } else {
+        "1d3"
+    };
+    let amount;
+    {
+        let mut rng = RealRng::new();
+        amount = roll_query(&mut rng, query)?.result();
+    }
+    let healed = character
+        .natural_recovery(success, amount)
+        .map_err(|e| Error::from(e.to_string(lang)))?;
+    Ok((croll_result, query, healed))
+}
@@ -0,0 +1,222 @@
+use crate::{
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang, locale_text_fmt},
+    roller::roll::roll_parse,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReadingPhase {
+    #[default]
+    Unread,
+    Initial,
+    Full,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Tome {
+    pub name: String,
+    pub language: String,
+    pub initial_reading_hours: i32,
+    pub full_reading_hours: i32,
+    pub mythos_initial: i32,
+    pub mythos_full: i32,
+    pub sanity_loss: String,
+    pub mythos_rating: i32,
+    pub spells: Vec<String>,
+    pub phase: ReadingPhase,
+    pub hours_read: i32,
+    pub reading: bool,
+}
+
+pub enum TomeError {
+    AlreadyExists(String),
+    NoSuchTome(String),
+    AlreadyRead(String),
+    InvalidSanityLoss(String),
+    InvalidMythosGain(i32),
+    InvalidReadingTime(i32),
+}
+
+impl TomeError {
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        match self {
+            TomeError::AlreadyExists(name) => locale_text_fmt(lang, LocaleTag::TomeAlreadyExists, &[name]),
+            TomeError::NoSuchTome(name) => locale_text_fmt(lang, LocaleTag::NoSuchTome, &[name]),
+            TomeError::AlreadyRead(name) => locale_text_fmt(lang, LocaleTag::TomeAlreadyStudied, &[name]),
+            TomeError::InvalidSanityLoss(loss) => locale_text_fmt(lang, LocaleTag::InvalidSanityLoss, &[loss]),
+            TomeError::InvalidMythosGain(gain) => locale_text_fmt(lang, LocaleTag::InvalidMythosGain, &[gain]),
+            TomeError::InvalidReadingTime(hours) => {
+                format!(
+                    "{}: `{}h`",
+                    locale_text_by_tag_lang(lang, LocaleTag::InvalidTime),
+                    hours
+                )
+            }
+        }
+    }
+}
+
+impl Tome {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: &str,
+        language: &str,
+        initial_reading_hours: i32,
+        full_reading_hours: i32,
+        mythos_initial: i32,
+        mythos_full: i32,
+        sanity_loss: &str,
+        mythos_rating: i32,
+        spells: Vec<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            language: language.into(),
+            initial_reading_hours,
+            full_reading_hours,
+            mythos_initial,
+            mythos_full,
+            sanity_loss: sanity_loss.into(),
+            mythos_rating,
+            spells,
+            ..Default::default()
+        }
+    }
+
+    /// Checks values entered by players; Sanity loss is rolled only when a phase is finished, so it is parsed here.
+    pub fn validate(&self) -> Result<(), TomeError> {
+        if let Some(gain) = [self.mythos_initial, self.mythos_full]
+            .into_iter()
+            .find(|gain| *gain < 0)
+        {
+            return Err(TomeError::InvalidMythosGain(gain));
+        }
+        let no_loss = self.sanity_loss.is_empty() || self.sanity_loss == "0";
+        if !no_loss && !roll_parse(&self.sanity_loss).is_ok_and(|rolls| !rolls.is_empty()) {
+            return Err(TomeError::InvalidSanityLoss(self.sanity_loss.clone()));
+        }
+        Ok(())
+    }
+
+    pub fn hours_to_next_phase(&self) -> Option<i32> {
+        match self.phase {
+            ReadingPhase::Unread => Some(self.initial_reading_hours),
+            ReadingPhase::Initial => Some(self.full_reading_hours),
+            ReadingPhase::Full => None,
+        }
+    }
+
+    /// Adds reading time and returns the phases completed along the way.
+    pub fn read(&mut self, hours: i32) -> Result<Vec<ReadingPhase>, TomeError> {
+        if self.phase == ReadingPhase::Full {
+            return Err(TomeError::AlreadyRead(self.name.clone()));
+        }
+
+        self.hours_read = self
+            .hours_read
+            .checked_add(hours)
+            .ok_or(TomeError::InvalidReadingTime(hours))?;
+        let mut finished = vec![];
+        while let Some(needed) = self.hours_to_next_phase() {
+            if self.hours_read < needed {
+                break;
+            }
+            self.hours_read -= needed;
+            self.phase = match self.phase {
+                ReadingPhase::Unread => ReadingPhase::Initial,
+                _ => ReadingPhase::Full,
+            };
+            finished.push(self.phase);
+        }
+
+        if self.phase == ReadingPhase::Full {
+            self.hours_read = 0;
+            self.reading = false;
+        }
+
+        Ok(finished)
+    }
+
+    pub fn mythos_gain(&self, phase: ReadingPhase) -> i32 {
+        match phase {
+            ReadingPhase::Unread => 0,
+            ReadingPhase::Initial => self.mythos_initial,
+            ReadingPhase::Full => self.mythos_full,
+        }
+    }
+
+    pub fn progress(&self) -> String {
+        match self.hours_to_next_phase() {
+            Some(needed) => format!("{}/{}h", self.hours_read, needed),
+            None => "✔️".into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reading_phases() {
+        let mut tome = Tome::new(
+            "Necronomicon",
+            "Arabic",
+            10,
+            100,
+            2,
+            8,
+            "1d6",
+            15,
+            vec!["Dominate".into()],
+        );
+        assert_eq!(tome.read(5).ok(), Some(vec![]));
+        assert_eq!(tome.read(5).ok(), Some(vec![ReadingPhase::Initial]));
+        assert_eq!(tome.hours_read, 0);
+        assert_eq!(tome.read(120).ok(), Some(vec![ReadingPhase::Full]));
+        assert_eq!(tome.hours_read, 0);
+        assert!(tome.read(1).is_err());
+    }
+
+    #[test]
+    fn test_reading_both_phases_at_once() {
+        let mut tome = Tome::new("Necronomicon", "Arabic", 10, 100, 2, 8, "1d6", 15, vec![]);
+        assert_eq!(
+            tome.read(110).ok(),
+            Some(vec![ReadingPhase::Initial, ReadingPhase::Full])
+        );
+        assert_eq!(tome.mythos_gain(ReadingPhase::Full), 8);
+    }
+
+    #[test]
+    fn test_reading_time_overflow() {
+        let mut tome = Tome::new("Necronomicon", "Arabic", 10, 100, 2, 8, "1d6", 15, vec![]);
+        assert!(tome.read(5).is_ok());
+        assert!(tome.read(i32::MAX).is_err());
+        assert_eq!(tome.hours_read, 5);
+    }
+
+    #[test]
+    fn test_validate() {
+        let tome = |mythos_initial, sanity_loss| {
+            Tome::new(
+                "Necronomicon",
+                "Arabic",
+                10,
+                100,
+                mythos_initial,
+                8,
+                sanity_loss,
+                15,
+                vec![],
+            )
+        };
+        assert!(tome(2, "1d6").validate().is_ok());
+        assert!(tome(2, "2d10+1").validate().is_ok());
+        assert!(tome(2, "0").validate().is_ok());
+        assert!(tome(-2, "1d6").validate().is_err());
+        assert!(tome(2, "abc").validate().is_err());
+        assert!(tome(2, "").validate().is_ok());
+        assert!(tome(2, "1d99999999999").validate().is_err());
+    }
+}
@@ -0,0 +1,610 @@
+use std::iter::zip;
+
+use crate::{
+    character::Item,
+    locale::{LocaleLang, LocaleTag, locale_dice, locale_text_by_tag_lang, locale_text_fmt},
+    types::Error,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Weapon {
+    pub name: String,
+    pub range_dmgs: Vec<RangeDamage>,
+    pub attacks: Vec<Attack>,
+    pub attacks_query: String,
+    pub apply_damage_modifier: bool,
+    pub half_damage_modifier: bool,
+    pub impaling: bool,
+    pub skill: String,
+    pub malfunction: Option<i32>,
+    pub ammo: Option<Ammo>,
+    pub default: bool,
+    /// Rounds of work needed to clear a jam; `None` when the weapon is operational.
+    #[serde(default)]
+    pub jammed: Option<i32>,
+}
+
+pub enum WeaponOk {
+    AddedRounds(i32),
+    CriticalHit,
+    Hit,
+    Loaded(i32),
+    Miss,
+    RemovedRounds(i32),
+    Shot(i32),
+    Unjammed,
+    Unjamming(i32),
+}
+
+impl WeaponOk {
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        match self {
+            WeaponOk::AddedRounds(r) => locale_text_fmt(lang, LocaleTag::AddedRounds, &[r]),
+            WeaponOk::CriticalHit => locale_text_by_tag_lang(lang, LocaleTag::CriticalHit),
+            WeaponOk::Hit => locale_text_by_tag_lang(lang, LocaleTag::Hit),
+            WeaponOk::Loaded(r) => locale_text_fmt(lang, LocaleTag::LoadedRounds, &[r]),
+            WeaponOk::Miss => locale_text_by_tag_lang(lang, LocaleTag::Miss),
+            WeaponOk::RemovedRounds(r) => locale_text_fmt(lang, LocaleTag::RemovedRounds, &[r]),
+            WeaponOk::Shot(r) => locale_text_fmt(lang, LocaleTag::ShotRounds, &[r]),
+            WeaponOk::Unjammed => locale_text_by_tag_lang(lang, LocaleTag::WeaponUnjammed),
+            WeaponOk::Unjamming(r) => locale_text_fmt(lang, LocaleTag::RoundsLeftToUnjam, &[r]),
+        }
+    }
+}
+
+pub enum WeaponError {
+    DoesntUseAmmo,
+    CantRemoveRounds(i32),
+    CantShootRounds(i32),
+    ClipEmpty,
+    NoAmmo,
+    NoNeedToReload,
+    Jammed(i32),
+    NotJammed,
+    NoSuchAmmoType(String),
+    AmmoTypeExists(String),
+}
+
+impl WeaponError {
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        match self {
+            WeaponError::DoesntUseAmmo => locale_text_by_tag_lang(lang, LocaleTag::DoesntUseAmmo),
+            WeaponError::CantRemoveRounds(rounds) => locale_text_fmt(lang, LocaleTag::CantRemoveRounds, &[rounds]),
+            WeaponError::ClipEmpty => locale_text_by_tag_lang(lang, LocaleTag::ClipEmpty),
+            WeaponError::NoAmmo => locale_text_by_tag_lang(lang, LocaleTag::NoAmmoLeft),
+            WeaponError::NoNeedToReload => locale_text_by_tag_lang(lang, LocaleTag::NoNeedToReload),
+            WeaponError::CantShootRounds(rounds) => locale_text_fmt(lang, LocaleTag::CantShootRounds, &[rounds]),
+            WeaponError::Jammed(rounds) => locale_text_fmt(lang, LocaleTag::WeaponIsJammed, &[rounds]),
+            WeaponError::NotJammed => locale_text_by_tag_lang(lang, LocaleTag::WeaponNotJammed),
+            WeaponError::NoSuchAmmoType(name) => locale_text_fmt(lang, LocaleTag::NoSuchAmmoType, &[name]),
+            WeaponError::AmmoTypeExists(name) => locale_text_fmt(lang, LocaleTag::AmmoTypeExists, &[name]),
+        }
+    }
+}
+
+impl Weapon {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: &str,
+        range_dmgs: Vec<RangeDamage>,
+        attacks_query: &str,
+        apply_damage_modifier: bool,
+        impaling: bool,
+        skill: &str,
+        malfunction: Option<i32>,
+        ammo: Option<Ammo>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            range_dmgs,
+            attacks_query: attacks_query.into(),
+            attacks: attacks_from(attacks_query).unwrap(),
+            apply_damage_modifier,
+            half_damage_modifier: false,
+            impaling,
+            skill: skill.to_string(),
+            malfunction,
+            ammo,
+            default: false,
+            jammed: None,
+        }
+    }
+
+    pub fn can_fire(&self) -> Result<(), WeaponError> {
+        match self.jammed {
+            Some(rounds) => Err(WeaponError::Jammed(rounds)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn jam(&mut self, rounds: i32) {
+        self.jammed = Some(rounds.max(1));
+    }
+
+    /// Spends a round working on the jam.
+    pub fn work_on_jam(&mut self) -> Result<WeaponOk, WeaponError> {
+        let rounds = self.jammed.as_mut().ok_or(WeaponError::NotJammed)?;
+        *rounds -= 1;
+        if *rounds <= 0 {
+            self.jammed = None;
+            Ok(WeaponOk::Unjammed)
+        } else {
+            Ok(WeaponOk::Unjamming(*rounds))
+        }
+    }
+
+    pub fn clear_jam(&mut self) -> Result<WeaponOk, WeaponError> {
+        self.jammed.take().ok_or(WeaponError::NotJammed)?;
+        Ok(WeaponOk::Unjammed)
+    }
+
+    pub fn use_ammo(&mut self, rounds: i32) -> Result<WeaponOk, WeaponError> {
+        if let Some(weapon) = self.ammo.as_mut() {
+            Ok(weapon.shoot_rounds(rounds)?)
+        } else {
+            Err(WeaponError::DoesntUseAmmo)
+        }
+    }
+
+    pub fn add_ammo(&mut self, rounds: i32) -> Result<WeaponOk, WeaponError> {
+        if let Some(weapon) = self.ammo.as_mut() {
+            Ok(weapon.add_rounds(rounds))
+        } else {
+            Err(WeaponError::DoesntUseAmmo)
+        }
+    }
+
+    pub fn remove_ammo(&mut self, rounds: i32) -> Result<WeaponOk, WeaponError> {
+        if let Some(weapon) = self.ammo.as_mut() {
+            Ok(weapon.remove_rounds(rounds)?)
+        } else {
+            Err(WeaponError::DoesntUseAmmo)
+        }
+    }
+
+    pub fn damage_dice(&self, lang: LocaleLang, distance: Option<i32>) -> String {
+        let index = match distance {
+            Some(m) => self
+                .range_dmgs
+                .iter()
+                .rposition(|w| w.range.unwrap_or(i32::MAX) <= m)
+                .unwrap_or(0),
+            None => 0,
+        };
+        let dmg = match self.loaded_ammo_type().and_then(|t| t.damage.as_ref()) {
+            Some(damage) => {
+                let damages: Vec<_> = damage.split('/').collect();
+                damages.get(index).or(damages.last()).unwrap().to_string()
+            }
+            None => self.range_dmgs[index].damage.clone(),
+        };
+        locale_dice(lang, &dmg)
+    }
+
+    pub fn loaded_ammo_type(&self) -> Option<&AmmoType> {
+        let ammo = self.ammo.as_ref()?;
+        let loaded = ammo.loaded_type.as_ref()?;
+        ammo.types.iter().find(|t| &t.name == loaded)
+    }
+
+    pub fn is_impaling(&self) -> bool {
+        self.loaded_ammo_type()
+            .and_then(|t| t.impaling)
+            .unwrap_or(self.impaling)
+    }
+
+    pub fn apply_half_dm(&mut self) {
+        self.half_damage_modifier = true;
+    }
+}
+
+impl PartialOrd for Weapon {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Weapon {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.name.cmp(&other.name)
+    }
+}
+
+impl PartialEq for Weapon {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.eq(&other.name)
+    }
+}
+
+impl Eq for Weapon {}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Ammo {
+    pub clip_rounds: i32,
+    /// Loose rounds of standard ammunition.
+    pub loose_rounds: i32,
+    pub clip_size: i32,
+    #[serde(default)]
+    pub types: Vec<AmmoType>,
+    /// Type of the rounds in the clip; `None` for standard ammunition.
+    #[serde(default)]
+    pub loaded_type: Option<String>,
+}
+
+/// Ammo type name choosing the weapon's standard rounds when reloading.
+pub const STANDARD_AMMO_TYPE: &str = "standard";
+
+/// Named ammunition with its own damage profile, e.g. buckshot or hollow-point rounds.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AmmoType {
+    pub name: String,
+    /// Damage per range, separated with `/`, replacing weapon's damage.
+    pub damage: Option<String>,
+    pub impaling: Option<bool>,
+    pub rounds: i32,
+    /// Name of an inventory item the rounds are taken from, shared between weapons.
+    pub pool: Option<String>,
+}
+
+impl Ammo {
+    pub fn new(clip_size: i32, rounds: i32) -> Self {
+        Self {
+            clip_size,
+            loose_rounds: rounds,
+            clip_rounds: 0,
+            types: vec![],
+            loaded_type: None,
+        }
+    }
+
+    pub fn find_type(&self, name: &str) -> Result<&AmmoType, WeaponError> {
+        self.types
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(name.trim()))
+            .ok_or(WeaponError::NoSuchAmmoType(name.to_string()))
+    }
+
+    pub fn add_type(&mut self, ammo_type: AmmoType) -> Result<(), WeaponError> {
+        if self.find_type(&ammo_type.name).is_ok() || ammo_type.name.trim().eq_ignore_ascii_case(STANDARD_AMMO_TYPE) {
+            return Err(WeaponError::AmmoTypeExists(ammo_type.name));
+        }
+        self.types.push(ammo_type);
+        Ok(())
+    }
+
+    /// Returns counter of rounds of given type: loose standard rounds, type's own rounds or shared inventory pool.
+    pub fn source<'a>(
+        &'a mut self,
+        items: &'a mut [Item],
+        type_name: Option<&str>,
+    ) -> Result<&'a mut i32, WeaponError> {
+        let Some(type_name) = type_name else {
+            return Ok(&mut self.loose_rounds);
+        };
+        let ammo_type = self
+            .types
+            .iter_mut()
+            .find(|t| t.name.eq_ignore_ascii_case(type_name))
+            .ok_or(WeaponError::NoSuchAmmoType(type_name.to_string()))?;
+        match ammo_type.pool.clone() {
+            Some(pool) => items
+                .iter_mut()
+                .find(|i| i.name.eq_ignore_ascii_case(&pool))
+                .map(|i| &mut i.quantity)
+                .ok_or(WeaponError::NoAmmo),
+            None => Ok(&mut ammo_type.rounds),
+        }
+    }
+
+    /// Fills the clip with rounds of given type (currently loaded type if `None`, standard rounds for
+    /// [`STANDARD_AMMO_TYPE`]), unloading other type first. Nothing changes when the reload fails.
+    pub fn reload(&mut self, items: &mut [Item], type_name: Option<&str>) -> Result<WeaponOk, WeaponError> {
+        let target = match type_name {
+            Some(type_name) if type_name.trim().eq_ignore_ascii_case(STANDARD_AMMO_TYPE) => None,
+            Some(type_name) => Some(self.find_type(type_name)?.name.clone()),
+            None => self.loaded_type.clone(),
+        };
+        let switching = target != self.loaded_type;
+
+        let kept_rounds = if switching { 0 } else { self.clip_rounds };
+        let rounds_to_load = self.clip_size - kept_rounds;
+        if rounds_to_load == 0 {
+            return Err(WeaponError::NoNeedToReload);
+        }
+        let available = *self.source(items, target.as_deref())?;
+        let rounds_to_load = rounds_to_load.min(available);
+        if rounds_to_load <= 0 {
+            return Err(WeaponError::NoAmmo);
+        }
+
+        if switching && self.clip_rounds > 0 {
+            let unloaded = self.clip_rounds;
+            let previous = self.loaded_type.clone();
+            *self.source(items, previous.as_deref())? += unloaded;
+            self.clip_rounds = 0;
+        }
+        self.loaded_type = target.clone();
+
+        *self.source(items, target.as_deref())? -= rounds_to_load;
+        self.clip_rounds += rounds_to_load;
+
+        Ok(WeaponOk::Loaded(rounds_to_load))
+    }
+
+    pub fn add_typed_rounds(
+        &mut self,
+        items: &mut [Item],
+        type_name: &str,
+        rounds_to_add: i32,
+    ) -> Result<WeaponOk, WeaponError> {
+        *self.source(items, Some(type_name))? += rounds_to_add;
+        Ok(WeaponOk::AddedRounds(rounds_to_add))
+    }
+
+    pub fn to_string(&self, items: &[Item]) -> String {
+        let mut out = format!("{}/{} [{}]", self.clip_rounds, self.clip_size, self.loose_rounds);
+        if let Some(loaded_type) = &self.loaded_type {
+            out = format!("{out} {loaded_type}");
+        }
+        for ammo_type in &self.types {
+            let rounds = match &ammo_type.pool {
+                Some(pool) => items
+                    .iter()
+                    .find(|i| i.name.eq_ignore_ascii_case(pool))
+                    .map_or(0, |i| i.quantity),
+                None => ammo_type.rounds,
+            };
+            out = format!("{out}\n{}: {}", ammo_type.name, rounds);
+        }
+        out
+    }
+
+    pub fn shoot_rounds(&mut self, rounds_to_shoot: i32) -> Result<WeaponOk, WeaponError> {
+        if self.clip_rounds == 0 {
+            return Err(WeaponError::ClipEmpty);
+        }
+
+        if self.clip_rounds < rounds_to_shoot {
+            return Err(WeaponError::CantShootRounds(rounds_to_shoot));
+        }
+
+        self.clip_rounds -= rounds_to_shoot;
+
+        Ok(WeaponOk::Shot(rounds_to_shoot))
+    }
+
+    pub fn add_rounds(&mut self, rounds_to_add: i32) -> WeaponOk {
+        self.loose_rounds += rounds_to_add;
+        WeaponOk::AddedRounds(rounds_to_add)
+    }
+
+    pub fn remove_rounds(&mut self, rounds_to_remove: i32) -> Result<WeaponOk, WeaponError> {
+        if rounds_to_remove > self.loose_rounds + self.clip_rounds {
+            return Err(WeaponError::CantRemoveRounds(rounds_to_remove));
+        }
+
+        if rounds_to_remove > self.loose_rounds {
+            self.clip_rounds -= rounds_to_remove - self.loose_rounds;
+            self.loose_rounds = 0;
+        } else {
+            self.loose_rounds -= rounds_to_remove;
+        }
+
+        Ok(WeaponOk::RemovedRounds(rounds_to_remove))
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum AttacksModifier {
+    None,
+    OnePenaltyToAll,
+    IncreasingPenalty,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Attack {
+    pub count: i32,
+    pub modifier: AttacksModifier,
+}
+
+impl Attack {
+    pub fn new(count: i32, modifier: AttacksModifier) -> Self {
+        Self { count, modifier }
+    }
+
+    pub fn get_modifier(&self, attack_number: i32) -> String {
+        match self.modifier {
+            AttacksModifier::None => "".into(),
+            AttacksModifier::OnePenaltyToAll => "-".into(),
+            AttacksModifier::IncreasingPenalty => "-".repeat(attack_number as usize),
+        }
+    }
+}
+
+pub fn attacks_from(value: &str) -> Result<Vec<Attack>, Error> {
+    let value = value.replace(' ', "");
+    let value = value.as_str();
+    match value {
+        "1" => Ok(vec![Attack::new(1, AttacksModifier::None)]),
+        "2" => Ok(vec![
+            Attack::new(1, AttacksModifier::None),
+            Attack::new(2, AttacksModifier::None),
+        ]),
+        "1(2)" => Ok(vec![
+            Attack::new(1, AttacksModifier::None),
+            Attack::new(2, AttacksModifier::OnePenaltyToAll),
+        ]),
+        "1(3)" => Ok(vec![
+            Attack::new(1, AttacksModifier::None),
+            Attack::new(3, AttacksModifier::OnePenaltyToAll),
+        ]),
+        "1or2" | "1lub2" => Ok(vec![
+            Attack::new(1, AttacksModifier::None),
+            Attack::new(2, AttacksModifier::None),
+        ]),
+        _ => Err("Couldn't parse into Attack obj".into()),
+    }
+}
+
+#[derive(Debug)]
+pub enum RangeDamageError {
+    RangeDamageDiffers,
+}
+
+impl RangeDamageError {
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        match self {
+            RangeDamageError::RangeDamageDiffers => locale_text_by_tag_lang(lang, LocaleTag::RangeDamageDiffers),
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RangeDamage {
+    pub range: Option<i32>,
+    pub damage: String,
+}
+
+impl RangeDamage {
+    pub fn from(ranges: Vec<String>, damages: Vec<String>) -> Result<Vec<Self>, RangeDamageError> {
+        if ranges.len() != damages.len() {
+            return Err(RangeDamageError::RangeDamageDiffers);
+        }
+
+        Ok(zip(ranges, damages)
+            .map(|(range, damage)| Self {
+                range: range.parse::<i32>().ok(),
+                damage,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jammed_weapon_cant_fire_until_unjammed() {
+        let mut weapon = Weapon::new(
+            ".38 Revolver",
+            vec![],
+            "1",
+            false,
+            true,
+            "Firearms (Handgun)",
+            Some(100),
+            None,
+        );
+        assert!(weapon.can_fire().is_ok());
+        assert!(weapon.work_on_jam().is_err());
+
+        weapon.jam(2);
+        assert!(weapon.can_fire().is_err());
+        assert!(matches!(weapon.work_on_jam(), Ok(WeaponOk::Unjamming(1))));
+        assert!(matches!(weapon.work_on_jam(), Ok(WeaponOk::Unjammed)));
+        assert!(weapon.can_fire().is_ok());
+
+        weapon.jam(5);
+        assert!(weapon.clear_jam().is_ok());
+        assert!(weapon.can_fire().is_ok());
+    }
+
+    fn shotgun() -> Weapon {
+        let mut ammo = Ammo::new(2, 4);
+        ammo.add_type(AmmoType {
+            name: "Slug".into(),
+            damage: Some("1d10+7".into()),
+            impaling: Some(true),
+            rounds: 3,
+            pool: None,
+        })
+        .ok()
+        .unwrap();
+        ammo.add_type(AmmoType {
+            name: "Buckshot".into(),
+            damage: None,
+            impaling: None,
+            rounds: 0,
+            pool: Some("12-gauge shells".into()),
+        })
+        .ok()
+        .unwrap();
+        Weapon::new(
+            "Shotgun",
+            RangeDamage::from(
+                vec!["10".into(), "20".into(), "50".into()],
+                vec!["4d6".into(), "2d6".into(), "1d6".into()],
+            )
+            .ok()
+            .unwrap(),
+            "1",
+            false,
+            false,
+            "Firearms (Rifle/Shotgun)",
+            Some(100),
+            Some(ammo),
+        )
+    }
+
+    #[test]
+    fn test_loaded_ammo_type_changes_damage_profile() {
+        let mut weapon = shotgun();
+        let mut items = vec![];
+        assert_eq!(weapon.damage_dice(LocaleLang::ENGLISH, Some(20)), "2d6");
+        assert!(!weapon.is_impaling());
+
+        let ammo = weapon.ammo.as_mut().unwrap();
+        assert!(ammo.reload(&mut items, Some("slug")).is_ok());
+        assert_eq!(weapon.damage_dice(LocaleLang::ENGLISH, Some(20)), "1d10+7");
+        assert!(weapon.is_impaling());
+    }
+
+    #[test]
+    fn test_switching_ammo_type_unloads_clip_to_shared_pool() {
+        let mut weapon = shotgun();
+        let mut items = vec![Item::new("12-gauge shells", 5, None)];
+        let ammo = weapon.ammo.as_mut().unwrap();
+
+        assert!(matches!(
+            ammo.reload(&mut items, Some("Buckshot")),
+            Ok(WeaponOk::Loaded(2))
+        ));
+        assert_eq!(items[0].quantity, 3);
+        assert!(matches!(ammo.reload(&mut items, Some("Slug")), Ok(WeaponOk::Loaded(2))));
+        assert_eq!(items[0].quantity, 5);
+        assert_eq!(ammo.find_type("slug").ok().unwrap().rounds, 1);
+        assert!(ammo.reload(&mut items, Some("Flechette")).is_err());
+    }
+
+    #[test]
+    fn test_reload_back_to_standard_rounds() {
+        let mut weapon = shotgun();
+        let mut items = vec![];
+        let ammo = weapon.ammo.as_mut().unwrap();
+
+        assert!(matches!(ammo.reload(&mut items, Some("Slug")), Ok(WeaponOk::Loaded(2))));
+        assert!(matches!(
+            ammo.reload(&mut items, Some(STANDARD_AMMO_TYPE)),
+            Ok(WeaponOk::Loaded(2))
+        ));
+        assert_eq!(ammo.loaded_type, None);
+        assert_eq!(ammo.loose_rounds, 2);
+        assert_eq!(ammo.find_type("slug").ok().unwrap().rounds, 3);
+    }
+
+    #[test]
+    fn test_failed_reload_keeps_clip() {
+        let mut weapon = shotgun();
+        let mut items = vec![Item::new("12-gauge shells", 2, None)];
+        let ammo = weapon.ammo.as_mut().unwrap();
+
+        assert!(ammo.reload(&mut items, Some("Buckshot")).is_ok());
+        assert_eq!(items[0].quantity, 0);
+        // Shared pool is missing, so the rounds in the clip have nowhere to go.
+        let mut no_items = vec![];
+        assert!(ammo.reload(&mut no_items, Some("Slug")).is_err());
+        assert_eq!(ammo.clip_rounds, 2);
+        assert_eq!(ammo.loaded_type.as_deref(), Some("Buckshot"));
+    }
+}
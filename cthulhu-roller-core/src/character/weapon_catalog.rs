@@ -0,0 +1,166 @@
+use crate::{
+    calendar::Era,
+    character::{Ammo, RangeDamage, Weapon, attacks_from},
+    locale::{LocaleLang, LocaleTag, locale_text_fmt},
+    types::Error,
+};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+const BUILT_IN_WEAPONS_JSON: &str = include_str!("data/weapons.json");
+/// Optional server-specific catalog entries, loaded from the bot's working directory.
+pub const CUSTOM_WEAPONS_JSON: &str = "weapons.json";
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct CatalogWeapon {
+    pub name: String,
+    pub skill: String,
+    pub damage: String,
+    #[serde(default)]
+    pub range: Option<String>,
+    pub attacks: String,
+    #[serde(default)]
+    pub malfunction: Option<i32>,
+    #[serde(default)]
+    pub clip_size: Option<i32>,
+    #[serde(default)]
+    pub impaling: bool,
+    #[serde(default)]
+    pub apply_damage_modifier: bool,
+    #[serde(default)]
+    pub half_damage_modifier: bool,
+    /// Eras the weapon is available in; empty means all of them.
+    #[serde(default)]
+    pub eras: Vec<Era>,
+}
+
+pub enum CatalogError {
+    NoSuchWeapon(String),
+    MissingField(&'static str),
+}
+
+impl CatalogError {
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        match self {
+            CatalogError::NoSuchWeapon(name) => locale_text_fmt(lang, LocaleTag::NoSuchCatalogWeapon, &[name]),
+            CatalogError::MissingField(field) => locale_text_fmt(lang, LocaleTag::MissingParameter, &[field]),
+        }
+    }
+}
+
+impl CatalogWeapon {
+    pub fn is_available_in(&self, era: Era) -> bool {
+        self.eras.is_empty() || self.eras.contains(&era)
+    }
+
+    pub fn validate(&self) -> Result<(), CatalogError> {
+        for (field, value) in [
+            ("name", &self.name),
+            ("skill", &self.skill),
+            ("damage", &self.damage),
+            ("attacks", &self.attacks),
+        ] {
+            if value.trim().is_empty() {
+                return Err(CatalogError::MissingField(field));
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds character's weapon; loose rounds default to a single clip.
+    pub fn to_weapon(&self, lang: LocaleLang, start_ammunition: Option<i32>) -> Result<Weapon, Error> {
+        self.validate().map_err(|e| Error::from(e.to_string(lang)))?;
+        attacks_from(&self.attacks)?;
+
+        let range_dmgs = RangeDamage::from(
+            self.range
+                .clone()
+                .unwrap_or("-".into())
+                .replace(' ', "")
+                .split('/')
+                .map(str::to_string)
+                .collect(),
+            self.damage
+                .replace(' ', "")
+                .replace('k', "d")
+                .split('/')
+                .map(str::to_string)
+                .collect(),
+        )
+        .map_err(|e| Error::from(e.to_string(lang)))?;
+
+        let ammo = self
+            .clip_size
+            .map(|clip_size| Ammo::new(clip_size, start_ammunition.unwrap_or(clip_size)));
+
+        let mut weapon = Weapon::new(
+            &self.name,
+            range_dmgs,
+            &self.attacks,
+            self.apply_damage_modifier,
+            self.impaling,
+            &self.skill,
+            self.malfunction,
+            ammo,
+        );
+        if self.half_damage_modifier {
+            weapon.apply_half_dm();
+        }
+        Ok(weapon)
+    }
+}
+
+fn load_catalog() -> Vec<CatalogWeapon> {
+    let mut catalog: Vec<CatalogWeapon> =
+        serde_json::from_str(BUILT_IN_WEAPONS_JSON).expect("Invalid built-in weapon catalog");
+
+    if let Ok(custom_json) = std::fs::read_to_string(CUSTOM_WEAPONS_JSON) {
+        match serde_json::from_str::<Vec<CatalogWeapon>>(&custom_json) {
+            Ok(custom) => {
+                for weapon in custom {
+                    catalog.retain(|w| !w.name.eq_ignore_ascii_case(&weapon.name));
+                    catalog.push(weapon);
+                }
+            }
+            Err(e) => println!("Invalid {CUSTOM_WEAPONS_JSON}: {e}"),
+        }
+    }
+
+    catalog.sort_by(|a, b| a.name.cmp(&b.name));
+    catalog
+}
+
+lazy_static! {
+    pub static ref WEAPON_CATALOG: Vec<CatalogWeapon> = load_catalog();
+}
+
+pub fn find_catalog_weapon(name: &str) -> Result<&'static CatalogWeapon, CatalogError> {
+    WEAPON_CATALOG
+        .iter()
+        .find(|w| w.name.eq_ignore_ascii_case(name.trim()))
+        .ok_or(CatalogError::NoSuchWeapon(name.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_built_in_catalog_weapons_are_valid() {
+        let catalog: Vec<CatalogWeapon> = serde_json::from_str(BUILT_IN_WEAPONS_JSON).unwrap();
+        assert!(!catalog.is_empty());
+        for entry in &catalog {
+            let weapon = entry.to_weapon(LocaleLang::ENGLISH, None);
+            assert!(weapon.is_ok(), "{}", entry.name);
+        }
+    }
+
+    #[test]
+    fn test_catalog_era_filter() {
+        let thompson = find_catalog_weapon("thompson smg").ok().unwrap();
+        assert!(thompson.is_available_in(Era::Classic));
+        assert!(!thompson.is_available_in(Era::Gaslight));
+        let knife = find_catalog_weapon("Knife, medium").ok().unwrap();
+        assert!(Era::ALL.iter().all(|era| knife.is_available_in(*era)));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
+    message::MessageContent,
+    types::UsersHashMap,
+};
+use serde::{Deserialize, Serialize};
+
+/// Pinned message of a guild that mirrors the status of every active character.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Dashboard {
+    pub channel_id: u64,
+    pub message_id: u64,
+    pub lang: LocaleLang,
+    /// Last content sent to Discord; the message is edited only when this changes.
+    #[serde(default)]
+    pub rendered: String,
+}
+
+impl Dashboard {
+    pub fn new(channel_id: u64, message_id: u64, lang: LocaleLang, users: &UsersHashMap) -> Self {
+        Self {
+            channel_id,
+            message_id,
+            lang,
+            rendered: render(lang, users),
+        }
+    }
+
+    /// Returns the new content when the party status changed since the last edit.
+    pub fn update(&mut self, users: &UsersHashMap) -> Option<String> {
+        let rendered = render(self.lang, users);
+        if rendered == self.rendered {
+            return None;
+        }
+        self.rendered = rendered.clone();
+        Some(rendered)
+    }
+
+    /// Forces the next update to edit the message, e.g. after a restart.
+    pub fn invalidate(&mut self) {
+        self.rendered.clear();
+    }
+
+    pub fn to_message_content(&self) -> MessageContent {
+        MessageContent {
+            title: locale_text_by_tag_lang(self.lang, LocaleTag::Party),
+            description: self.rendered.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+fn render(lang: LocaleLang, users: &UsersHashMap) -> String {
+    let mut characters: Vec<_> = users
+        .values()
+        .filter_map(|user_data| {
+            user_data
+                .active_character
+                .as_ref()
+                .and_then(|active| user_data.characters.get(active))
+        })
+        .collect();
+    if characters.is_empty() {
+        return locale_text_by_tag_lang(lang, LocaleTag::NoCharacters);
+    }
+    characters.sort();
+
+    characters
+        .iter()
+        .map(|character| format!("**{}**\n{}", character.name, character.status_one_line()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bot_data::UserData,
+        character::{Attributes, Character},
+        ruleset::RulesetKind,
+    };
+    use std::collections::HashMap;
+
+    fn party() -> UsersHashMap {
+        let character = Character::new(
+            "Harvey",
+            &None,
+            Attributes::new(50, 50, 50, 50, 50, 50, 50, 50).unwrap(),
+            50,
+            None,
+            RulesetKind::Classic,
+        )
+        .unwrap();
+        let mut user_data = UserData {
+            active_character: Some(character.name.clone()),
+            ..Default::default()
+        };
+        user_data.characters.insert(character.name.clone(), character);
+        HashMap::from([(1, user_data)])
+    }
+
+    #[test]
+    fn updates_only_after_changes() {
+        let mut users = party();
+        let mut dashboard = Dashboard::new(1, 2, LocaleLang::default(), &users);
+        assert_eq!(dashboard.update(&users), None);
+
+        let character = users.get_mut(&1).unwrap().characters.get_mut("Harvey").unwrap();
+        character.hp.modify(-3);
+        character.major_wound = true;
+        let rendered = dashboard.update(&users).unwrap();
+        assert!(rendered.contains("Harvey"));
+        assert!(rendered.contains("🤕"));
+        assert_eq!(dashboard.update(&users), None);
+
+        dashboard.invalidate();
+        assert!(dashboard.update(&users).is_some());
+    }
+
+    #[test]
+    fn inactive_characters_are_left_out() {
+        let mut users = party();
+        users.get_mut(&1).unwrap().active_character = None;
+        let dashboard = Dashboard::new(1, 2, LocaleLang::default(), &users);
+        assert!(!dashboard.rendered.contains("Harvey"));
+    }
+}
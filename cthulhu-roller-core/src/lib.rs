@@ -0,0 +1,23 @@
+//! Call of Cthulhu 7e rules engine behind Cthulhu Roller: dice rolling, success levels, characters,
+//! weapons, battle and localization, without any Discord dependency.
+//!
+//! Characters, weapons, sessions and dashboards need the `character-sheet` feature.
+
+pub mod bot_data;
+#[cfg(feature = "character-sheet")]
+pub mod calendar;
+#[cfg(feature = "character-sheet")]
+pub mod character;
+#[cfg(feature = "character-sheet")]
+pub mod dashboard;
+pub mod locale;
+pub mod message;
+pub mod roller;
+pub mod ruleset;
+#[cfg(feature = "character-sheet")]
+pub mod session;
+pub mod settings;
+pub mod types;
+pub mod utils;
+
+pub use types::Error;
@@ -0,0 +1,866 @@
+use crate::types::{LocaleEntryMap, LocaleVec};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+};
+
+const BUILT_IN_LOCALES: [(&str, &str); 4] = [
+    ("en", include_str!("locales/en.toml")),
+    ("pl", include_str!("locales/pl.toml")),
+    ("de", include_str!("locales/de.toml")),
+    ("es", include_str!("locales/es.toml")),
+];
+
+/// Directory with `<code>.toml` files adding new languages or overriding built-in texts.
+pub const CUSTOM_LOCALES_DIR: &str = "locales";
+
+struct Language {
+    code: String,
+    texts: HashMap<LocaleTag, String>,
+}
+
+fn parse_locale(toml_text: &str) -> Result<HashMap<LocaleTag, String>, String> {
+    let table: HashMap<String, String> = toml::from_str(toml_text).map_err(|e| e.to_string())?;
+    table
+        .into_iter()
+        .map(|(key, text)| {
+            LocaleTag::from_name(&key)
+                .map(|tag| (tag, text))
+                .ok_or(format!("unknown key `{key}`"))
+        })
+        .collect()
+}
+
+fn load_languages() -> Vec<Language> {
+    let mut languages: Vec<_> = BUILT_IN_LOCALES
+        .iter()
+        .map(|(code, toml_text)| Language {
+            code: code.to_string(),
+            texts: parse_locale(toml_text).unwrap_or_else(|e| panic!("Invalid built-in locale `{code}`: {e}")),
+        })
+        .collect();
+
+    let Ok(dir) = std::fs::read_dir(CUSTOM_LOCALES_DIR) else {
+        return languages;
+    };
+    let mut paths: Vec<_> = dir
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+    for path in paths {
+        let Some(code) = path.file_stem().and_then(|stem| stem.to_str()).map(str::to_lowercase) else {
+            continue;
+        };
+        let texts = match std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|toml_text| parse_locale(&toml_text))
+        {
+            Ok(texts) => texts,
+            Err(e) => {
+                println!("Invalid {}: {e}", path.display());
+                continue;
+            }
+        };
+        match languages.iter_mut().find(|language| language.code == code) {
+            Some(language) => language.texts.extend(texts),
+            None => languages.push(Language { code, texts }),
+        }
+    }
+    languages
+}
+
+/// Index of a language loaded from the locale files; English is always the first one and the fallback.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LocaleLang(usize);
+
+impl LocaleLang {
+    pub const ENGLISH: Self = Self(0);
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..LANGUAGES.len()).map(Self)
+    }
+
+    pub fn code(self) -> &'static str {
+        &self.language().code
+    }
+
+    fn language(self) -> &'static Language {
+        LANGUAGES.get(self.0).unwrap_or(&LANGUAGES[Self::ENGLISH.0])
+    }
+}
+
+impl From<&str> for LocaleLang {
+    fn from(value: &str) -> Self {
+        let value = value.replace(' ', "").to_lowercase();
+        let code = value.split(['-', '_']).next().unwrap_or_default();
+        Self::all()
+            .find(|lang| {
+                let name = lang.to_string().to_lowercase();
+                lang.code() == code || name == value || (value.chars().count() >= 3 && name.starts_with(&value))
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl From<String> for LocaleLang {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl Display for LocaleLang {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let language = self.language();
+        f.write_str(language.texts.get(&LocaleTag::LanguageName).unwrap_or(&language.code))
+    }
+}
+
+impl Serialize for LocaleLang {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for LocaleLang {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(String::deserialize(deserializer)?.into())
+    }
+}
+
+/// Texts keyed by language code; missing languages are resolved through the matching tag or English.
+#[derive(Eq, PartialEq, Hash, Clone, Serialize, Deserialize, Debug, Default, PartialOrd, Ord)]
+#[serde(transparent)]
+pub struct LocaleEntry {
+    texts: BTreeMap<String, String>,
+}
+
+impl LocaleEntry {
+    #[cfg(feature = "character-sheet")]
+    pub fn new_single_lang(text: &str) -> Self {
+        Self {
+            texts: BTreeMap::from([(LocaleLang::ENGLISH.code().to_string(), text.to_string())]),
+        }
+    }
+
+    pub fn en(&self) -> &str {
+        self.texts
+            .get(LocaleLang::ENGLISH.code())
+            .or(self.texts.values().next())
+            .map_or("", String::as_str)
+    }
+
+    pub fn get(&self, lang: LocaleLang) -> String {
+        self.texts
+            .get(lang.code())
+            .cloned()
+            .or_else(|| self.to_enum().map(|tag| locale_text_by_tag_lang(lang, tag)))
+            .unwrap_or_else(|| self.en().to_string())
+    }
+
+    pub fn to_enum(&self) -> Option<LocaleTag> {
+        LOCALE_TEXTS
+            .iter()
+            .find_map(|(tag, locale)| if self.en() == locale.en() { Some(*tag) } else { None })
+    }
+
+    #[cfg(feature = "character-sheet")]
+    pub fn partial_match_ignore_case(&self, text: &str) -> bool {
+        self.texts
+            .values()
+            .any(|t| t.to_lowercase().contains(&text.to_lowercase()))
+    }
+
+    pub fn equals_ignore_case(&self, text: &str) -> bool {
+        self.texts.values().any(|t| t.to_lowercase() == text.to_lowercase())
+    }
+}
+
+macro_rules! locale_tags {
+    ($($tag:ident),* $(,)?) => {
+        #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Hash, Clone, Copy)]
+        pub enum LocaleTag {
+            $($tag),*
+        }
+
+        impl LocaleTag {
+            pub const ALL: &[LocaleTag] = &[$(LocaleTag::$tag),*];
+
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $(stringify!($tag) => Some(LocaleTag::$tag),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+locale_tags! {
+    Accounting,
+    Adventurer,
+    Agony,
+    Alert,
+    AmbiguousCharacter,
+    Ammo,
+    AnimalCompanion,
+    AnimalHandling,
+    Anthropology,
+    App,
+    Appearance,
+    Appraise,
+    ArcaneInsight,
+    Archeology,
+    ArtCraft,
+    ArtCraftAny,
+    Artillery,
+    Attacks,
+    BeadyEye,
+    Beefcake,
+    BodyHealed,
+    BodyWounded,
+    Bonus,
+    BonusDieToFirstAction,
+    BonVivant,
+    Build,
+    CantRemoveDefaultWeapon,
+    CantSpendLuck,
+    Characteristic,
+    CharacterNotFound,
+    Charm,
+    Climb,
+    ColdBlooded,
+    ComesBackWithFullStrength,
+    ComputerUse,
+    Con,
+    Constitution,
+    CoreCharacteristic,
+    CreditRating,
+    CriticalFailure,
+    CriticalSuccess,
+    CthulhuMythos,
+    Damage,
+    DamageBonus,
+    Db,
+    DeathInevitable,
+    Demolitions,
+    Dex,
+    Dexterity,
+    Dice,
+    Disguise,
+    Diving,
+    Dodge,
+    Dreamer,
+    DriveAuto,
+    Edu,
+    Education,
+    Egghead,
+    ElectricalRepair,
+    Electronics,
+    Endurance,
+    Explorer,
+    ExtremeDamage,
+    ExtremeSuccess,
+    Failure,
+    FastLoad,
+    FastTalk,
+    FemmeFatale,
+    Fight,
+    FightEnd,
+    Fighting,
+    FightingBrawl,
+    Firearms,
+    FirearmsHandgun,
+    FirearmsRifleShotgun,
+    FirearmsSubMachineGun,
+    FirstAid,
+    FleetFooted,
+    From,
+    Gadget,
+    GoneMad,
+    GreaseMonkey,
+    Handy,
+    HardBoiled,
+    Hardened,
+    HardSuccess,
+    Harlequin,
+    HeavyHitter,
+    History,
+    HitPoints,
+    Hp,
+    Hunter,
+    Hypnosis,
+    Impaling,
+    InClip,
+    IndefInsanity,
+    Int,
+    Intelligence,
+    Intimidate,
+    IronLiver,
+    Item,
+    Items,
+    Jump,
+    KeenHearing,
+    KeenVision,
+    KnockOut,
+    LanguageAnyOther,
+    LanguageOther,
+    LanguageOwn,
+    Law,
+    LibraryUse,
+    Linguist,
+    Listen,
+    Locksmith,
+    Lore,
+    LosesFirstRound,
+    Luck,
+    LuckCritical,
+    Lucky,
+    MagicPoints,
+    MajorWound,
+    MajorWoundHealed,
+    Malfunction,
+    ManeuverBuildError,
+    MasterOfDisguise,
+    MaxSanitySet,
+    MechanicalRepair,
+    Medicine,
+    MindHealed,
+    MindShattered,
+    Move,
+    Mp,
+    Mystic,
+    MythosKnowledge,
+    Name,
+    NaturalWorld,
+    Navigate,
+    NightVision,
+    Nimble,
+    NoCharacters,
+    NoCharacterSelected,
+    NoItems,
+    NoSuchItem,
+    NoSuchSkill,
+    NoSuchWeapon,
+    NotMarked,
+    Occult,
+    Occupation,
+    OperateHeavyMachinery,
+    Outmaneuver,
+    Outsider,
+    Pcs,
+    Penalty,
+    Persuade,
+    PhotographicMemory,
+    Pilot,
+    PilotAny,
+    PointsTo,
+    Pow,
+    Power,
+    PowerLifter,
+    PrepareForTheConsequences,
+    PsychicPower,
+    Psychoanalysis,
+    Psychology,
+    PulpArchetype,
+    PulpTalents,
+    PushRoll,
+    QuickDraw,
+    QuickHealer,
+    QuickStudy,
+    Range,
+    RapidAttack,
+    RapidFire,
+    ReadLips,
+    Received,
+    Reloaded,
+    Resilient,
+    Resourceful,
+    Result,
+    Results,
+    Ride,
+    Rogue,
+    RollConCheckBlackOut,
+    RollConCheckDie,
+    RollIntCheck,
+    Rolls,
+    Rounds,
+    Sanity,
+    Scary,
+    Scholar,
+    Science,
+    ScienceAny,
+    Seeker,
+    SetLanguageTo,
+    Shadow,
+    SharpWitted,
+    Sidekick,
+    Siz,
+    Size,
+    Skill,
+    SkillMarked,
+    SkillUnmarked,
+    SleightOfHand,
+    SmoothTalker,
+    SorryTooManyCharacters,
+    Sp,
+    SpotHidden,
+    Steadfast,
+    Stealth,
+    StoutConstitution,
+    Str,
+    Strength,
+    StrongWilled,
+    Success,
+    Survival,
+    SurvivalAny,
+    Swashbuckler,
+    Swim,
+    TempInsanity,
+    TempInsanityThreat,
+    ThrillSeeker,
+    Throw,
+    ToughGuy,
+    Track,
+    TwoFisted,
+    Unarmed,
+    Value,
+    Weapon,
+    WeaponJammed,
+    Weapons,
+    WeirdScience,
+    YouBlackOut,
+    YouFell,
+    YouGotLuckyThisTIme,
+    RoundsToUnjam,
+    Tomes,
+    NoTomes,
+    Spells,
+    NoSpells,
+    MythosRating,
+    InitialReading,
+    FullStudy,
+    ReadingProgress,
+    SpellsLearned,
+    BoutRealTimeAmnesia,
+    BoutRealTimePsychosomatic,
+    BoutRealTimeViolence,
+    BoutRealTimeParanoia,
+    BoutRealTimeSignificantPerson,
+    BoutRealTimeFaint,
+    BoutRealTimeFlee,
+    BoutRealTimeHysterics,
+    BoutSummaryAmnesia,
+    BoutSummaryRobbed,
+    BoutSummaryBattered,
+    BoutSummaryViolence,
+    BoutSummaryIdeology,
+    BoutSummarySignificantPeople,
+    BoutSummaryInstitutionalized,
+    BoutSummaryFlee,
+    BoutPhobia,
+    BoutMania,
+    BoutOfMadness,
+    InsanityEpisodes,
+    NoInsanityEpisodes,
+    IntCheck,
+    MemoryRepressed,
+    Hours,
+    Months,
+    Stabilized,
+    RecoveryRoll,
+    NaturalHealing,
+    EraGaslight,
+    EraClassic,
+    EraModern,
+    TimeAdvanced,
+    NewDay,
+    TimeAdvanceTooLong,
+    InsanityEnded,
+    ActiveSpells,
+    SpellEnded,
+    SpellCast,
+    InvalidTime,
+    CarriedWeight,
+    Encumbered,
+    ItemUsed,
+    LightOn,
+    LightOff,
+    BonusDieReady,
+    InvalidItemEffect,
+    LanguageName,
+    DiceSymbol,
+    HealingTooLate,
+    FirstAidAlreadyApplied,
+    MedicineAlreadyApplied,
+    NoMajorWound,
+    RecoveryTooSoon,
+    ItemEffectHeal,
+    ItemEffectBonusDie,
+    ItemEffectLight,
+    CantRemoveUnits,
+    ItemCantBeUsed,
+    ItemUsedUp,
+    InvalidCharges,
+    SkillAlreadyExists,
+    CantRemoveBasicSkill,
+    SkillCantBeModified,
+    UnknownSpell,
+    NotEnoughMagicPoints,
+    TomeAlreadyExists,
+    NoSuchTome,
+    TomeAlreadyStudied,
+    InvalidSanityLoss,
+    InvalidMythosGain,
+    AddedRounds,
+    CriticalHit,
+    Hit,
+    LoadedRounds,
+    Miss,
+    RemovedRounds,
+    ShotRounds,
+    WeaponUnjammed,
+    RoundsLeftToUnjam,
+    DoesntUseAmmo,
+    CantRemoveRounds,
+    CantShootRounds,
+    ClipEmpty,
+    NoAmmoLeft,
+    NoNeedToReload,
+    WeaponIsJammed,
+    WeaponNotJammed,
+    NoSuchAmmoType,
+    AmmoTypeExists,
+    RangeDamageDiffers,
+    NoSuchCatalogWeapon,
+    MissingParameter,
+    NoReceiver,
+    Ruleset,
+    UnknownRuleset,
+    RulesetClassic,
+    RulesetPulp,
+    RulesetDeltaGreen,
+    RulesetAchtung,
+    Alertness,
+    Athletics,
+    Bureaucracy,
+    Criminology,
+    Forensics,
+    HeavyWeapons,
+    Humint,
+    MeleeWeapons,
+    MilitaryScience,
+    Pharmacy,
+    Search,
+    Sigint,
+    Surgery,
+    NoPulpArchetype,
+    NotArchetypeSkill,
+    NotEnoughArchetypePoints,
+    InvalidArchetypePoints,
+    NotCoreCharacteristic,
+    NoSuchTalent,
+    NotEnoughLuck,
+    NotDying,
+    NoRecentDamage,
+    CantAvoidDeath,
+    ArchetypePoints,
+    TalentReRoll,
+    DeathAvoided,
+    DamageShruggedOff,
+    Settings,
+    UnknownSetting,
+    InvalidSettingValue,
+    SessionStarted,
+    SessionRecap,
+    SessionAlreadyStarted,
+    NoActiveSession,
+    SkillsMarked,
+    SkillsImproved,
+    ItemsGained,
+    ItemsLost,
+    CharacterDied,
+    CharacterWentInsane,
+    NetChange,
+    DamageTaken,
+    SanityLost,
+    RollHighlights,
+    NoChanges,
+    RollStatistics,
+    Party,
+    NoRolls,
+    AverageRoll,
+    LuckSpent,
+    MostUsedSkills,
+    DiceFairness,
+    DiceFair,
+    DiceBiased,
+    NotEnoughRolls,
+    WhisperedRoll,
+    NoSuchAttribute,
+    DashboardStarted,
+    DashboardStopped,
+    NoDashboard,
+    PreviousTurn,
+    NextTurn,
+    RemoveCombatant,
+    EndBattle,
+    BattleTrackerUpdated,
+    BattleButtonDenied,
+    SheetCharacteristics,
+    SheetSkills,
+    SheetCombat,
+    SheetInventory,
+    SheetBackstory,
+    SheetPulp,
+    CharacterWizard,
+    RollCharacteristics,
+    EnterCharacteristics,
+    Age,
+    SkillPoints,
+    CreateCharacter,
+    DiscardDraft,
+    DraftDiscarded,
+    DraftSaved,
+    DraftIncomplete,
+    InvalidCharacteristics,
+    InvalidAge,
+    InvalidLine,
+    NotEnoughSkillPoints,
+    InlineRollsEnabled,
+    InlineRollsDisabled,
+    InvalidExpression,
+}
+
+fn locale_entries(tags: &[LocaleTag]) -> LocaleVec {
+    tags.iter()
+        .map(|tag| (*tag, locale_entry_by_tag(*tag).clone()))
+        .collect()
+}
+
+lazy_static! {
+    static ref LANGUAGES: Vec<Language> = load_languages();
+    static ref LOCALE_TEXTS: LocaleEntryMap = LocaleTag::ALL
+        .iter()
+        .map(|tag| {
+            let texts = LANGUAGES
+                .iter()
+                .filter_map(|language| Some((language.code.clone(), language.texts.get(tag)?.clone())))
+                .collect();
+            (*tag, LocaleEntry { texts })
+        })
+        .collect();
+    pub static ref LOCALE_ATTRIBUTES: LocaleVec = locale_entries(&[
+        LocaleTag::Appearance,
+        LocaleTag::Constitution,
+        LocaleTag::Dexterity,
+        LocaleTag::Education,
+        LocaleTag::Intelligence,
+        LocaleTag::Power,
+        LocaleTag::Size,
+        LocaleTag::Strength,
+    ]);
+    pub static ref LOCALE_PULP_ARCHETYPES: LocaleVec = locale_entries(&[
+        LocaleTag::Adventurer,
+        LocaleTag::Beefcake,
+        LocaleTag::BonVivant,
+        LocaleTag::ColdBlooded,
+        LocaleTag::Dreamer,
+        LocaleTag::Egghead,
+        LocaleTag::Explorer,
+        LocaleTag::FemmeFatale,
+        LocaleTag::GreaseMonkey,
+        LocaleTag::HardBoiled,
+        LocaleTag::Harlequin,
+        LocaleTag::Hunter,
+        LocaleTag::Mystic,
+        LocaleTag::Outsider,
+        LocaleTag::Rogue,
+        LocaleTag::Scholar,
+        LocaleTag::Seeker,
+        LocaleTag::Sidekick,
+        LocaleTag::Steadfast,
+        LocaleTag::Swashbuckler,
+        LocaleTag::ThrillSeeker,
+        LocaleTag::TwoFisted,
+    ]);
+    pub static ref LOCALE_PULP_TALENTS: LocaleVec = locale_entries(&[
+        LocaleTag::Alert,
+        LocaleTag::AnimalCompanion,
+        LocaleTag::ArcaneInsight,
+        LocaleTag::BeadyEye,
+        LocaleTag::Endurance,
+        LocaleTag::FastLoad,
+        LocaleTag::FleetFooted,
+        LocaleTag::Gadget,
+        LocaleTag::Handy,
+        LocaleTag::Hardened,
+        LocaleTag::HeavyHitter,
+        LocaleTag::IronLiver,
+        LocaleTag::KeenHearing,
+        LocaleTag::KeenVision,
+        LocaleTag::Linguist,
+        LocaleTag::Lore,
+        LocaleTag::Lucky,
+        LocaleTag::MasterOfDisguise,
+        LocaleTag::MythosKnowledge,
+        LocaleTag::NightVision,
+        LocaleTag::Nimble,
+        LocaleTag::Outmaneuver,
+        LocaleTag::PhotographicMemory,
+        LocaleTag::PowerLifter,
+        LocaleTag::PsychicPower,
+        LocaleTag::QuickDraw,
+        LocaleTag::QuickHealer,
+        LocaleTag::QuickStudy,
+        LocaleTag::RapidAttack,
+        LocaleTag::RapidFire,
+        LocaleTag::Resilient,
+        LocaleTag::Resourceful,
+        LocaleTag::Scary,
+        LocaleTag::Shadow,
+        LocaleTag::SharpWitted,
+        LocaleTag::SmoothTalker,
+        LocaleTag::StoutConstitution,
+        LocaleTag::StrongWilled,
+        LocaleTag::ToughGuy,
+        LocaleTag::WeirdScience,
+    ]);
+    static ref DICE_NOTATION: Regex = Regex::new(r"(\d*)(?i:[dkw])(\d+)").unwrap();
+}
+
+pub fn locale_entry_by_tag<'a>(tag: LocaleTag) -> &'a LocaleEntry {
+    LOCALE_TEXTS
+        .get(&tag)
+        .unwrap_or_else(|| panic!("Missing LOCALE_TEXTS entry for tag: {tag:?}"))
+}
+
+#[cfg(feature = "character-sheet")]
+pub fn locale_entry_by_str(string: &str) -> Option<&LocaleEntry> {
+    let find_result = LOCALE_TEXTS.iter().find(|(_, text)| text.equals_ignore_case(string));
+    if let Some(pair) = find_result {
+        Some(pair.1)
+    } else {
+        None
+    }
+}
+
+pub fn locale_tag_by_str(string: &str) -> Option<LocaleTag> {
+    let find_result = LOCALE_TEXTS.iter().find(|(_, text)| text.equals_ignore_case(string));
+    find_result.map(|pair| *pair.0)
+}
+
+pub fn locale_text_by_tag_lang(lang: LocaleLang, tag: LocaleTag) -> String {
+    lang.language()
+        .texts
+        .get(&tag)
+        .or_else(|| LocaleLang::ENGLISH.language().texts.get(&tag))
+        .unwrap_or_else(|| panic!("Missing locale entry for tag: {tag:?}"))
+        .clone()
+}
+
+/// Text of the tag with `{0}`, `{1}`, ... placeholders replaced by `args`.
+#[cfg(feature = "character-sheet")]
+pub fn locale_text_fmt(lang: LocaleLang, tag: LocaleTag, args: &[&dyn Display]) -> String {
+    args.iter()
+        .enumerate()
+        .fold(locale_text_by_tag_lang(lang, tag), |text, (i, arg)| {
+            text.replace(&format!("{{{i}}}"), &arg.to_string())
+        })
+}
+
+/// Writes dice in `text` (`2d6`, `1k10`) with the die symbol of given language.
+pub fn locale_dice(lang: LocaleLang, text: &str) -> String {
+    let symbol = locale_text_by_tag_lang(lang, LocaleTag::DiceSymbol);
+    DICE_NOTATION
+        .replace_all(text, format!("${{1}}{symbol}${{2}}"))
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn placeholders(text: &str) -> Vec<usize> {
+        (0..10).filter(|i| text.contains(&format!("{{{i}}}"))).collect()
+    }
+
+    #[test]
+    fn built_in_locales_have_all_keys() {
+        let english = parse_locale(BUILT_IN_LOCALES[0].1).unwrap();
+        for (code, toml_text) in BUILT_IN_LOCALES {
+            let texts = parse_locale(toml_text).unwrap_or_else(|e| panic!("{code}: {e}"));
+            let missing: Vec<_> = LocaleTag::ALL.iter().filter(|tag| !texts.contains_key(tag)).collect();
+            assert!(missing.is_empty(), "{code} is missing keys: {missing:?}");
+            for (tag, text) in &texts {
+                assert_eq!(
+                    placeholders(text),
+                    placeholders(&english[tag]),
+                    "{code}: placeholders of {tag:?} differ from English"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_key_is_rejected() {
+        assert!(parse_locale("NoSuchTag = \"text\"").is_err());
+    }
+
+    #[rstest]
+    #[case("en", "en")]
+    #[case("en-US", "en")]
+    #[case("pl", "pl")]
+    #[case("Polski", "pl")]
+    #[case("pol", "pl")]
+    #[case("de", "de")]
+    #[case("deutsch", "de")]
+    #[case("es-ES", "es")]
+    #[case("Español", "es")]
+    #[case("klingon", "en")]
+    fn lang_from_str(#[case] input: &str, #[case] code: &str) {
+        assert_eq!(LocaleLang::from(input).code(), code);
+    }
+
+    #[test]
+    fn lang_serializes_as_code_and_reads_old_names() {
+        let lang = LocaleLang::from("pl");
+        assert_eq!(serde_json::to_string(&lang).unwrap(), "\"pl\"");
+        assert_eq!(serde_json::from_str::<LocaleLang>("\"Polski\"").unwrap(), lang);
+        assert_eq!(
+            serde_json::from_str::<LocaleLang>("\"English\"").unwrap(),
+            LocaleLang::ENGLISH
+        );
+    }
+
+    #[test]
+    fn old_entry_falls_back_to_tag_translation() {
+        let entry: LocaleEntry = serde_json::from_str(r#"{"pl":"Unik","en":"Dodge"}"#).unwrap();
+        assert_eq!(entry.get(LocaleLang::from("de")), "Ausweichen");
+        assert_eq!(entry.to_enum(), Some(LocaleTag::Dodge));
+    }
+
+    #[cfg(feature = "character-sheet")]
+    #[test]
+    fn text_fmt_replaces_placeholders() {
+        assert_eq!(
+            locale_text_fmt(LocaleLang::ENGLISH, LocaleTag::CantRemoveRounds, &[&3]),
+            "Can't remove 3 rounds."
+        );
+    }
+
+    #[rstest]
+    #[case("en", "1d10+2d4", "1d10+2d4")]
+    #[case("en", "1k6", "1d6")]
+    #[case("pl", "d6+1", "k6+1")]
+    #[case("de", "+1d4 dodge", "+1W4 dodge")]
+    #[case("en", "1w6+1D4", "1d6+1d4")]
+    fn dice_notation(#[case] lang: &str, #[case] text: &str, #[case] expected: &str) {
+        assert_eq!(locale_dice(LocaleLang::from(lang), text), expected);
+    }
+}
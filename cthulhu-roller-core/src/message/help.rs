@@ -0,0 +1,181 @@
+pub const CROLL_HELP: &str = r#"Bonus (`+`) and penalty (`-`) dice are being resolved automatically for easier adding circumstances of the roll, for example: test you firearms skill test of threshold `70`, you've been aiming entire previous round (bonus), target is really big (bonus) but moving fast (penalty) so you can roll `70++-`.
+
+Syntax: `<threshold><optional modifier dice symbols>`
+
+Examples: `30+` `20--` `50` `50+` `50-` `70++` `20++---`
+
+With an active character the threshold can use sheet values: `STR` `CON` `SIZ` `DEX` `APP` `INT` `POW` `EDU` `LUCK` `SAN` `HP` `MP` and `skill("Spot Hidden")`, e.g. `(POW+INT)/2+` `DEX*2` `skill("Dodge")/5-`"#;
+
+pub const ROLL_HELP: &str = r#"Syntax: `<optional number of dice>` `d/k/W` `<sides>` `<optional multiplier>` `<optional modifier>`
+
+Examples: `2d4` `3k6` `3W6` `24k6+10` `12d8x3` `4k12*2` `6d6x6+6` `d4-2` `k8+k4` `1k6+1k4+1`
+
+With an active character dice can use sheet values: `DB` (damage bonus), `BUILD`, `MOV`, characteristics like `STR` or `CON/2` and `skill("Firearms (Handgun)")`, e.g. `1d8+DB` `1d6+CON/10`"#;
+
+pub const LANGUAGE_HELP: &str = r#"Built-in languages: `english`, `polski`, `deutsch`, `español`; a language code such as `de` works too.
+
+Translations are kept in TOML files keyed by message tag. Servers can add a language or override texts with `locales/<code>.toml` placed next to `db.json`, e.g. `locales/fr.toml`:
+```toml
+LanguageName = "Français"
+DiceSymbol = "d"
+Luck = "Chance"
+```
+Missing texts fall back to English."#;
+
+pub const IMPROVE_HELP: &str = r#"Syntax: `<threshold>`
+
+Examples: `40` `60`"#;
+
+pub const INITIATIVE_HELP: &str = r#"Initiative order is defined by: dexterity test success level, dexterity value and lowest roll value.
+
+Bonus and penalty dice are being resolved automatically for easier adding circumstances of the roll, for example: you gain bonus die for initiative roll for being prepared (armed) at the beginning of fight. (see `/croll` command)
+
+Syntax: `<character_name>` `<dexterity>` `<character_name>` `<dexterity>` (and so on)
+
+The bot posts one battle tracker and keeps editing it: `/next_round`, `/previous_round`, `/add_to_fight`, `/remove_from_fight` and `/end_battle` update it in place. Its buttons switch turns, take the current combatant out of the fight or end the battle; only the GM and the player whose character has the turn can press them.
+
+Example: `/initiative Anna 50+ Brian 60- Celine 60 Douglas 70 Emma 50 Frank 50 George 50`"#;
+
+pub const LEVELS_HELP: &str = r#"Syntax: `<threshold>`
+
+Examples: `40` `60`"#;
+
+#[cfg(feature = "character-sheet")]
+pub const WEAPON_HELP: &str = r#"`weapon add_catalog` copies a weapon from the built-in catalog, any other given parameter overrides the catalog entry. `weapon add` creates a custom weapon from scratch.
+
+Catalog autocomplete shows weapons available in the current era (`time set`).
+
+`weapon reload` with ammo type `standard` switches back from special ammunition to standard rounds.
+
+A jammed weapon needs 1D6 rounds of work: in battle each turn of its owner counts one down (`next_round`), outside of battle use `weapon unjam`, optionally with a Mechanical Repair or weapon skill roll to clear it at once.
+
+Servers can add or replace catalog entries with `weapons.json` placed next to `db.json`, e.g.:
+```json
+[{"name": "Elephant gun", "skill": "Firearms (Rifle/Shotgun)", "damage": "3d6+4", "range": "100", "attacks": "1or2", "malfunction": 100, "clip_size": 2, "impaling": true, "eras": ["Classic"]}]
+```
+"#;
+
+#[cfg(feature = "character-sheet")]
+pub const TIME_HELP: &str = r#"Advancing time applies daily and weekly rules to all characters:
+- daily Sanity baseline and natural healing,
+- weekly recovery roll for Major Wound,
+- reading progress of the tome a player keeps reading (`/tome read` with `keep_reading`),
+- insanity and spell durations.
+
+Time syntax: `<number><optional unit>`, units: `h` (hours, default), `d` (days), `w` (weeks), at most a year at once
+"#;
+
+#[cfg(feature = "character-sheet")]
+pub const PULP_HELP: &str = r#"Pulp Cthulhu hero actions:
+- `archetype_skill`: spend archetype bonus skill points (100 at creation) on archetype skills
+- `shrug_off`: Tough Guy talent, spend 10 Luck to shrug off up to 5 HP of the latest damage taken (`hp`)
+- `avoid_death`: spend all remaining Luck to survive with 1 HP
+
+Talents with mechanical effects:
+- bonus die: Keen Vision, Keen Hearing, Smooth Talker, Scary, Shadow, Master of Disguise, Handy, Linguist, Endurance, Power Lifter, Strong Willed, Sharp Witted, Photographic Memory
+- Beady Eye: re-roll failed Spot Hidden or Library Use for 10 Luck
+- Lucky: additional `1d10` Luck recovery
+- Quick Healer: 3 HP natural healing per day
+"#;
+
+#[cfg(feature = "character-sheet")]
+pub const RULESET_HELP: &str = r#"Rules used by the server; set by the GM.
+- `Call of Cthulhu`: 7th Edition rules (default)
+- `Pulp Cthulhu`: double Hit Points, larger Luck recovery
+- `Delta Green`: no hard or extreme successes, matching digits are criticals/fumbles; skills marked on failure and improved by `1d4` without a check; no Luck spending
+- `Achtung! Cthulhu`: 7th Edition rules with Artillery and Demolitions on the sheet
+
+Characters keep the ruleset they were created with, also for `/croll` of a player with an active character; Pulp archetype always selects Pulp rules.
+"#;
+
+#[cfg(feature = "character-sheet")]
+pub const SETTINGS_HELP: &str = r#"House rules of the server; `show` for everyone, `set` for the GM.
+- `improve_dice`: skill improvement dice, e.g. `1d10`; `-` restores the ruleset default (`1d6`, Delta Green `1d4`)
+- `min_attribute_value`: lowest total accepted by `/roll_attributes` (default 40)
+- `max_characters_per_user`: character limit per player (default 3)
+- `gm_role_name`: name of the role allowed to use GM commands (default `GM`)
+- `luck_in_combat`: allow spending Luck on combat rolls (`on`/`off`, default on)
+- `keeper`: user receiving whispered rolls, as a mention or ID (default: server owner)
+
+Example: `/settings set improve_dice 1d10`
+"#;
+
+#[cfg(feature = "character-sheet")]
+pub const SESSION_HELP: &str = r#"Game session lifecycle.
+- `start`: GM snapshots HP, Sanity, Luck, Magic Points, skills and items of every character
+- `recap`: show changes since the start without ending the session
+- `end`: GM ends the session and posts the recap with a Markdown file for the campaign wiki
+
+Recap lists per character changes (damage, Sanity lost, skills marked or improved, items gained or lost, deaths and insanities) and critical successes and fumbles rolled during the session.
+"#;
+
+#[cfg(feature = "character-sheet")]
+pub const STATS_HELP: &str = r#"Statistics of recorded percentile rolls.
+- `character`: rolls of a character (default: your active character)
+- `user`: all rolls of a player, including plain `/croll`
+- `party`: rolls of every character
+
+Shows success levels, criticals and fumbles, average d100, Luck spent, most used skills and a chi-square fairness check of the d100 (needs at least 50 rolls). Fairness uses raw dice, so bonus/penalty dice and Luck don't affect it. Hidden rolls are not counted.
+"#;
+
+#[cfg(feature = "character-sheet")]
+pub const WHISPER_HELP: &str = r#"Secret rolls for the Keeper.
+- `/wcroll`, `/wroll`: full result goes to the Keeper in a direct message, the channel only sees that a roll was made
+- `/gmroll`: GM secretly tests a skill of a player's character against the current sheet; only the GM sees the result
+
+The Keeper is set with `/settings set keeper`, otherwise it's the server owner. Secret rolls are kept in the hidden roll history and left out of recaps and statistics.
+"#;
+
+#[cfg(feature = "character-sheet")]
+pub const GM_EDIT_HELP: &str = r#"Edit any player's character; the first argument is the character name.
+- `/gmskill`: `add`, `set`, `change`, `remove` (custom skills only), `mark`, `unmark`
+- `/gmattribute`: `set`, `change` characteristics (derived values like HP, Build and Move follow), `luck`, `magic`, `occupation`
+
+`change` takes a value or dice, e.g. `-1d10` or `+5`.
+
+Examples: `/gmskill set Harvey Spot Hidden 60` `/gmattribute change Harvey Strength -1d10` `/gmattribute occupation Harvey Professor`"#;
+
+#[cfg(feature = "character-sheet")]
+pub const DASHBOARD_HELP: &str = r#"Live status of every active character: HP, SAN, Luck, MP, wounds and insanity.
+- `/dashboard start`: post and pin the dashboard in this channel, replacing the previous one
+- `/dashboard stop`: delete the dashboard
+
+The bot edits the message only after a change and keeps updating it after a restart. Pinning needs the Manage Messages permission."#;
+
+#[cfg(feature = "character-sheet")]
+pub const SHEET_HELP: &str = r#"Interactive sheet of the active character, visible only to you.
+- the menu switches tabs: Characteristics, Skills, Combat, Inventory, Backstory and Pulp (Pulp heroes only)
+- the Skills tab has page buttons and a 🎲 button for every skill that makes a skill check
+- `/skill list` opens the sheet on the Skills tab
+
+Set the backstory with `/character backstory`. The buttons stop working after 10 minutes of inactivity."#;
+
+#[cfg(feature = "character-sheet")]
+pub const CHARACTER_HELP: &str = r#"`/character wizard` creates a character step by step, visible only to you:
+- roll characteristics and Luck (respecting `min_attribute_value`) or enter them
+- pick an age; age modifiers apply EDU improvement checks and lower physical characteristics, APP and Move
+- set name and occupation
+- spend EDU×4 + INT×2 skill points, one `Skill: points` per line; unknown skills are added as custom ones
+- add catalog weapons and items, one per line
+
+Every step is saved, so you can run the wizard again later to finish or `Discard` the draft.
+`/character create` takes all values at once."#;
+
+#[cfg(feature = "inline-rolls")]
+pub const INLINE_ROLLS_HELP: &str = r#"Rolls written inside regular chat messages, answered in a thread started from the message.
+- `[[1d8+1d4]]`: dice roll, same syntax as `/roll`
+- `[[croll 45+]]`: skill check, same syntax as `/croll`
+
+`/inline_rolls true` turns them on in the current channel, `/inline_rolls false` turns them off. Up to 10 rolls per message.
+The bot needs the Message Content intent enabled in the Discord developer portal.
+
+Example: `I swing the crowbar [[1d8+1d4]]`"#;
+
+#[cfg(feature = "character-sheet")]
+pub const TOME_HELP: &str = r#"Reading time syntax: `<number><optional unit>`, units: `h` (hours, default), `d` (days), `w` (weeks)
+
+Reading with `keep_reading` continues the tome while the Keeper advances time; reading another tome that way stops it.
+
+Finishing initial reading or full study applies Cthulhu Mythos gain and Sanity loss; full study unlocks contained spells.
+
+Examples: `/tome add Necronomicon Arabic 2w 68w 5 12 1d10 45 Dominate,Summon Night-gaunt` `/tome read Necronomicon 3d`"#;
@@ -1,5 +1,5 @@
 #[cfg(feature = "character-sheet")]
-use crate::character::{Character, Item, Skill};
+use crate::character::{Character, Item, ReadingPhase, Skill};
 use crate::roller::attribute_roll::AttributeRollResult;
 use crate::roller::croll::CrollResult;
 use crate::roller::improve_roll::ImproveResult;
@@ -9,13 +9,12 @@ use crate::types::SkillMap;
 #[cfg(feature = "character-sheet")]
 use crate::utils::to_uppercase_first_letter;
 use crate::{
-    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
+    locale::{LocaleLang, LocaleTag, locale_dice, locale_text_by_tag_lang},
     roller::{battle::Battle, success_level::SuccessLevel},
 };
 #[cfg(feature = "character-sheet")]
 use itertools::EitherOrBoth::{Both, Left, Right};
 use itertools::Itertools;
-use poise::serenity_prelude::{Colour, CreateEmbed, CreateEmbedFooter, Embed};
 #[cfg(feature = "character-sheet")]
 use tabled::builder::Builder;
 use tabled::settings::Style;
@@ -32,18 +31,6 @@ pub struct MessageContent {
 }
 
 impl MessageContent {
-    pub fn to_embed(&self) -> CreateEmbed {
-        let mut embed = CreateEmbed::default();
-        embed = embed.title(&self.title);
-        embed = embed.description(&self.description);
-        embed = embed.footer(CreateEmbedFooter::new(&self.footer));
-        embed = embed.colour(match self.colour {
-            Some(colour) => Colour::from(colour),
-            None => Colour::from(SuccessLevel::Success.hex()),
-        });
-        embed
-    }
-
     #[cfg(feature = "character-sheet")]
     pub fn to_content(&self) -> String {
         let mut out = String::new();
@@ -54,6 +41,20 @@ impl MessageContent {
         out
     }
 
+    /// Title, description and footer without Discord markdown, e.g. for a terminal.
+    pub fn to_plain_text(&self) -> String {
+        [&self.title, &self.description, &self.footer]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                part.lines()
+                    .filter(|line| !line.starts_with("```"))
+                    .map(|line| line.replace("**", "").replace("||", "").replace('`', ""))
+                    .join("\n")
+            })
+            .join("\n\n")
+    }
+
     pub fn from_dice_result(lang: LocaleLang, roll_result: RollResult, hide_details: bool) -> Self {
         let mut mc = Self {
             title: format!("**{}**", roll_result.result()),
@@ -75,9 +76,7 @@ impl MessageContent {
             if mc.footer.starts_with('+') {
                 mc.footer = mc.footer.replacen('+', "", 1);
             }
-            if lang == LocaleLang::Polski {
-                mc.footer = mc.footer.replace("d", "k");
-            }
+            mc.footer = locale_dice(lang, &mc.footer);
         }
 
         mc
@@ -403,7 +402,18 @@ impl MessageContent {
         let mut out = String::new();
 
         let mut items = character_items.to_owned();
-        items.sort();
+        items.sort_by_key(|item| {
+            (
+                item.container.clone().unwrap_or(item.name.clone()),
+                item.container.is_some(),
+                item.name.clone(),
+                item.quantity,
+            )
+        });
+        let item_line = |item: &Item| {
+            let bullet = if item.container.is_some() { "  ↳ " } else { "• " };
+            format!("{}{}", bullet, item.to_string(lang))
+        };
 
         out.push_str(format!("{}\n```text\n", locale_text_by_tag_lang(lang, LocaleTag::Items)).as_str());
 
@@ -418,14 +428,9 @@ impl MessageContent {
 
             for pair in items.iter().zip_longest(tail.iter()) {
                 match pair {
-                    Both(left, right) => table.push_record([
-                        &("• ".to_string() + left.to_string(lang).as_str()),
-                        &("• ".to_string() + right.to_string(lang).as_str()),
-                    ]),
-
-                    Left(left) => table.push_record([&("• ".to_string() + left.to_string(lang).as_str()), ""]),
-
-                    Right(right) => table.push_record(["", &("• ".to_string() + right.to_string(lang).as_str())]),
+                    Both(left, right) => table.push_record([item_line(left), item_line(right)]),
+                    Left(left) => table.push_record([item_line(left), "".into()]),
+                    Right(right) => table.push_record(["".into(), item_line(right)]),
                 }
             }
 
@@ -443,40 +448,24 @@ impl MessageContent {
 
     #[cfg(feature = "character-sheet")]
     pub fn from_character_to_items(lang: LocaleLang, character: &Character) -> Self {
-        let mut out = String::new();
+        let mut out = Self::from_character_items(lang, &character.items).description;
+        out.truncate(out.trim_end().len());
 
-        let mut items: Vec<_> = character.items.clone();
-        items.sort();
-
-        out.push_str(format!("{}\n```text\n", locale_text_by_tag_lang(lang, LocaleTag::Items)).as_str());
-
-        if items.is_empty() {
-            out.push_str(format!("({})\n", locale_text_by_tag_lang(lang, LocaleTag::NoItems)).as_str());
-        } else {
-            let mut table = Builder::new();
-
-            let columns = 2;
-            let records_per_column = items.len() / columns + items.len() % columns;
-            let tail = items.split_off(records_per_column);
-
-            for pair in items.iter().zip_longest(tail.iter()) {
-                match pair {
-                    Both(left, right) => table.push_record([
-                        &("• ".to_string() + left.to_string(lang).as_str()),
-                        &("• ".to_string() + right.to_string(lang).as_str()),
-                    ]),
-
-                    Left(left) => table.push_record([&("• ".to_string() + left.to_string(lang).as_str()), ""]),
-
-                    Right(right) => table.push_record(["", &("• ".to_string() + right.to_string(lang).as_str())]),
-                }
+        let carried_weight = character.carried_weight();
+        if carried_weight > 0.0 {
+            out.push_str(
+                format!(
+                    "\n⚖️ {}: **{:.1}**/{:.1} kg",
+                    locale_text_by_tag_lang(lang, LocaleTag::CarriedWeight),
+                    carried_weight,
+                    character.carry_limit()
+                )
+                .as_str(),
+            );
+            if character.is_encumbered() {
+                out.push_str(format!(" ⚠️ **{}**", locale_text_by_tag_lang(lang, LocaleTag::Encumbered)).as_str());
             }
-
-            let mut table = table.build();
-            table.with(Style::empty());
-            out.push_str(table.to_string().as_str());
         }
-        out.push_str("```");
 
         Self {
             description: out,
@@ -510,11 +499,7 @@ impl MessageContent {
             ]);
 
             for weapon in weapon_list {
-                let mut weapon_dmg = weapon.range_dmgs.iter().map(|rd| &rd.damage).join("/");
-                match lang {
-                    LocaleLang::English => weapon_dmg = weapon_dmg.replace('k', "d"),
-                    LocaleLang::Polski => weapon_dmg = weapon_dmg.replace('d', "k"),
-                }
+                let weapon_dmg = locale_dice(lang, &weapon.range_dmgs.iter().map(|rd| &rd.damage).join("/"));
                 let dmg = format!(
                     "{}{}",
                     weapon_dmg,
@@ -537,7 +522,10 @@ impl MessageContent {
                     .map(|rd| rd.range.map_or("-".to_string(), |r| format!("{} m", r)))
                     .join("/");
 
-                let weapon_name = weapon.name;
+                let weapon_name = match weapon.jammed {
+                    Some(rounds) => format!("🔧 {} ({})", weapon.name, rounds),
+                    None => weapon.name,
+                };
                 let weapon_skill = character.get_skill(&weapon.skill).unwrap();
 
                 table.push_record([
@@ -546,9 +534,10 @@ impl MessageContent {
                     dmg,
                     range,
                     weapon.malfunction.map_or("-".to_string(), |n| n.to_string()),
-                    weapon.ammo.clone().map_or("-".to_string(), |a| {
-                        format!("{}/{} [{}]", a.clip_rounds, a.clip_size, a.loose_rounds)
-                    }),
+                    weapon
+                        .ammo
+                        .as_ref()
+                        .map_or("-".to_string(), |a| a.to_string(&character.items)),
                     format!("{} {}", weapon_skill.value, weapon_skill.name.get(lang)),
                 ]);
             }
@@ -607,6 +596,12 @@ impl MessageContent {
         let mut mcs = vec![Self::from_character_to_attributes(lang, character)];
         mcs.extend(Self::from_character_skills(lang, &character.skills));
         mcs.push(Self::from_character_to_equipment(lang, character));
+        if !character.tomes.is_empty() || !character.spells.is_empty() {
+            mcs.push(Self::from_character_to_tomes(lang, character));
+        }
+        if !character.insanity_episodes.is_empty() {
+            mcs.push(Self::from_character_to_insanity(lang, character));
+        }
         mcs
     }
 
@@ -638,6 +633,28 @@ impl MessageContent {
             );
         }
 
+        if let Some(core_characteristic) = &character.pulp_core_characteristic {
+            out.push_str(
+                format!(
+                    "{}: **{}**\n",
+                    locale_text_by_tag_lang(lang, LocaleTag::CoreCharacteristic),
+                    core_characteristic.get(lang),
+                )
+                .as_str(),
+            );
+        }
+
+        if character.archetype_points > 0 {
+            out.push_str(
+                format!(
+                    "{}: **{}**\n",
+                    locale_text_by_tag_lang(lang, LocaleTag::ArchetypePoints),
+                    character.archetype_points,
+                )
+                .as_str(),
+            );
+        }
+
         if !character.pulp_talents.is_empty() {
             out.push_str(
                 format!(
@@ -727,15 +744,114 @@ impl MessageContent {
             ..Default::default()
         }
     }
-}
 
-impl From<Embed> for MessageContent {
-    fn from(embed: Embed) -> Self {
+    #[cfg(feature = "character-sheet")]
+    pub fn from_character_to_insanity(lang: LocaleLang, character: &Character) -> Self {
+        let description = if character.insanity_episodes.is_empty() {
+            format!("({})", locale_text_by_tag_lang(lang, LocaleTag::NoInsanityEpisodes))
+        } else {
+            character
+                .insanity_episodes
+                .iter()
+                .map(|episode| episode.to_string(lang))
+                .join("\n")
+        };
+
         Self {
-            title: embed.title.unwrap_or_default(),
-            description: embed.description.unwrap_or_default(),
-            footer: embed.footer.map_or_else(|| "".into(), |f| f.text),
-            colour: embed.colour.map_or_else(|| None, |c| Some(c.0)),
+            title: format!(
+                "`{}`: {}",
+                character.name,
+                locale_text_by_tag_lang(lang, LocaleTag::InsanityEpisodes)
+            ),
+            description,
+            ..Default::default()
         }
     }
+
+    #[cfg(feature = "character-sheet")]
+    pub fn from_character_to_tomes(lang: LocaleLang, character: &Character) -> Self {
+        let mut out = String::new();
+
+        out.push_str(format!("{}\n```text\n", locale_text_by_tag_lang(lang, LocaleTag::Tomes)).as_str());
+
+        if character.tomes.is_empty() {
+            out.push_str(format!("({})\n", locale_text_by_tag_lang(lang, LocaleTag::NoTomes)).as_str());
+        } else {
+            let mut table = Builder::new();
+            table.push_record([
+                "".into(),
+                locale_text_by_tag_lang(lang, LocaleTag::LanguageOther),
+                locale_text_by_tag_lang(lang, LocaleTag::CthulhuMythos),
+                locale_text_by_tag_lang(lang, LocaleTag::MythosRating),
+                locale_text_by_tag_lang(lang, LocaleTag::Sanity),
+                locale_text_by_tag_lang(lang, LocaleTag::ReadingProgress),
+            ]);
+            for tome in &character.tomes {
+                let phase = match tome.phase {
+                    ReadingPhase::Unread => locale_text_by_tag_lang(lang, LocaleTag::InitialReading),
+                    ReadingPhase::Initial => locale_text_by_tag_lang(lang, LocaleTag::FullStudy),
+                    ReadingPhase::Full => String::new(),
+                };
+                table.push_record([
+                    format!("{}{}", if tome.reading { "📖 " } else { "" }, tome.name),
+                    tome.language.clone(),
+                    format!("+{}/+{}", tome.mythos_initial, tome.mythos_full),
+                    tome.mythos_rating.to_string(),
+                    tome.sanity_loss.clone(),
+                    format!("{} {}", phase, tome.progress()).trim().to_string(),
+                ]);
+            }
+            let mut table = table.build();
+            table.with(Style::empty());
+            out.push_str(table.to_string().as_str());
+            out.push('\n');
+        }
+        out.push_str("```\n");
+
+        out.push_str(format!("{}\n```text\n", locale_text_by_tag_lang(lang, LocaleTag::Spells)).as_str());
+        if character.spells.is_empty() {
+            out.push_str(format!("({})\n", locale_text_by_tag_lang(lang, LocaleTag::NoSpells)).as_str());
+        } else {
+            for spell in character.spells.iter().sorted() {
+                out.push_str(format!("• {spell}\n").as_str());
+            }
+        }
+        out.push_str("```");
+
+        if !character.active_spells.is_empty() {
+            out.push_str(
+                format!(
+                    "\n{}\n```text\n",
+                    locale_text_by_tag_lang(lang, LocaleTag::ActiveSpells)
+                )
+                .as_str(),
+            );
+            for spell in &character.active_spells {
+                out.push_str(format!("✨ {} ({}h)\n", spell.name, spell.remaining_hours).as_str());
+            }
+            out.push_str("```");
+        }
+
+        Self {
+            description: out,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_text_without_markdown() {
+        let mc = MessageContent {
+            title: "**45**".into(),
+            description: "```text\nSTR 50\n```\n`Harvey` ||hidden||".into(),
+            footer: "1d100".into(),
+            ..Default::default()
+        };
+        assert_eq!(mc.to_plain_text(), "45\n\nSTR 50\nHarvey hidden\n\n1d100");
+        assert_eq!(MessageContent::default().to_plain_text(), "");
+    }
 }
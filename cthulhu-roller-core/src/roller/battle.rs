@@ -0,0 +1,178 @@
+pub use crate::roller::croll::CrollResult;
+use crate::{Error, roller::croll::croll_impl, ruleset::RulesetKind};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct CharacterInitiative {
+    pub croll_result: CrollResult,
+    pub name: String,
+}
+
+/// Battle message that the bot keeps editing instead of posting a new one every turn.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TrackerMessage {
+    pub channel_id: u64,
+    pub message_id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Battle {
+    pub characters: Vec<CharacterInitiative>,
+    pub current_position: usize,
+    #[serde(default)]
+    pub tracker: Option<TrackerMessage>,
+}
+
+impl Battle {
+    pub fn new(characters: Vec<CharacterInitiative>) -> Self {
+        let mut battle = Self {
+            characters,
+            current_position: 0,
+            tracker: None,
+        };
+        battle.characters.sort();
+        battle
+    }
+
+    pub fn current_character_name(&self) -> Option<&str> {
+        self.characters.get(self.current_position).map(|c| c.name.as_str())
+    }
+
+    pub fn next_round(&mut self) {
+        if self.characters.is_empty() {
+            return;
+        }
+        self.current_position += 1;
+        self.current_position %= self.characters.len();
+    }
+
+    pub fn previous_round(&mut self) {
+        if self.characters.is_empty() {
+            return;
+        }
+        if self.current_position == 0 {
+            self.current_position = self.characters.len() - 1;
+        } else {
+            self.current_position -= 1;
+        }
+        self.current_position %= self.characters.len();
+    }
+
+    pub fn add_characters(&mut self, characters: &[CharacterInitiative]) -> Result<(), String> {
+        for character in characters {
+            self.add_character(character)?;
+        }
+        Ok(())
+    }
+
+    pub fn add_character(&mut self, character: &CharacterInitiative) -> Result<(), String> {
+        if self
+            .characters
+            .iter()
+            .map(|el| &el.name)
+            .any(|el| el.eq_ignore_ascii_case(&character.name))
+        {
+            return Err(format!("Character `{}` already in battle!", character.name));
+        }
+        self.characters.push(character.clone());
+        self.characters.sort();
+        Ok(())
+    }
+
+    pub fn remove_character(&mut self, name: &str) -> Result<(), String> {
+        let position = self
+            .characters
+            .iter()
+            .position(|c| c.name.eq(name))
+            .ok_or(format!("Character `{}` not found.", name))?;
+        self.characters.remove(position);
+        if position < self.current_position {
+            self.current_position -= 1;
+        }
+        if self.current_position >= self.characters.len() {
+            self.current_position = 0;
+        }
+        Ok(())
+    }
+
+    /// Removes the combatant whose turn it is; the turn passes to the next one.
+    pub fn remove_current_character(&mut self) -> Option<String> {
+        let name = self.current_character_name()?.to_string();
+        self.remove_character(&name).ok()?;
+        Some(name)
+    }
+}
+
+/// Rolls initiative for a list of `Name` and `Dex` threshold pairs, e.g. `Harvey 60 Cultist 45+`.
+pub fn initiative_impl(list: &str, ruleset: RulesetKind) -> Result<Vec<CharacterInitiative>, Error> {
+    let words: Vec<&str> = list.split_whitespace().collect();
+    if words.is_empty() || !words.len().is_multiple_of(2) {
+        return Err(format!("Query must contain pairs of `Name` and `Dex` thresholds: \"{list}\"").into());
+    }
+
+    let mut characters: Vec<CharacterInitiative> = vec![];
+    for pair in words.chunks(2) {
+        let name = pair[0];
+        let threshold = pair[1];
+        let skill_result = croll_impl(threshold, ruleset)?;
+        if characters
+            .iter()
+            .map(|el| &el.name)
+            .any(|el| el.eq_ignore_ascii_case(name))
+        {
+            return Err(format!("Non-unique character list: `{}`", list).into());
+        }
+        characters.push(CharacterInitiative {
+            croll_result: skill_result,
+            name: name.to_string(),
+        });
+    }
+    Ok(characters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{roller::croll::croll, ruleset::RulesetKind};
+
+    fn battle(names: &[&str]) -> Battle {
+        Battle::new(
+            names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    let mut croll_result = croll("50", 50, 0, 0, RulesetKind::Classic).unwrap();
+                    croll_result.set_result(10 * (i as i32 + 1));
+                    CharacterInitiative {
+                        croll_result,
+                        name: name.to_string(),
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn removing_keeps_current_turn() {
+        let mut battle = battle(&["Harvey", "Joe", "Amelia"]);
+        let names: Vec<_> = battle.characters.iter().map(|c| c.name.clone()).collect();
+        battle.next_round();
+        battle.next_round();
+        let current = battle.current_character_name().unwrap().to_string();
+        battle.remove_character(&names[0]).unwrap();
+        assert_eq!(battle.current_character_name(), Some(current.as_str()));
+    }
+
+    #[test]
+    fn removing_current_passes_turn() {
+        let mut battle = battle(&["Harvey", "Joe"]);
+        battle.next_round();
+        let last = battle.remove_current_character().unwrap();
+        assert!(battle.current_character_name().is_some_and(|name| name != last));
+        battle.remove_current_character();
+        assert_eq!(battle.current_character_name(), None);
+        battle.next_round();
+        battle.previous_round();
+        assert_eq!(battle.remove_current_character(), None);
+    }
+}
@@ -8,7 +8,9 @@ use crate::{
         roll::roll_range,
         success_level::SuccessLevel,
     },
+    ruleset::RulesetKind,
 };
+use regex::Regex;
 use std::cmp::Ordering;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
@@ -20,6 +22,8 @@ pub struct CrollResult {
     pub ten_rolls: Vec<i32>,
     pub threshold: i32,
     pub modifier_dice: Option<ModifierDice>,
+    #[serde(default)]
+    pub ruleset: RulesetKind,
 }
 
 impl CrollResult {
@@ -30,6 +34,7 @@ impl CrollResult {
         one_roll: i32,
         ten_rolls: Vec<i32>,
         modifier_dice: Option<ModifierDice>,
+        ruleset: RulesetKind,
     ) -> Self {
         let mut slf = Self {
             query: query.into(),
@@ -38,6 +43,7 @@ impl CrollResult {
             one_roll,
             ten_rolls,
             modifier_dice,
+            ruleset,
             success_level: SuccessLevel::Failure,
         };
         slf.update_success_level();
@@ -45,23 +51,7 @@ impl CrollResult {
     }
 
     fn update_success_level(&mut self) {
-        self.success_level = match self.result {
-            100 => SuccessLevel::CriticalFailure,
-            1 => SuccessLevel::CriticalSuccess,
-            _ => {
-                if self.threshold < 50 && self.result >= 96 {
-                    SuccessLevel::CriticalFailure
-                } else if self.result <= self.threshold / 5 {
-                    SuccessLevel::ExtremeSuccess
-                } else if self.result <= self.threshold / 2 {
-                    SuccessLevel::HardSuccess
-                } else if self.result <= self.threshold {
-                    SuccessLevel::Success
-                } else {
-                    SuccessLevel::Failure
-                }
-            }
-        };
+        self.success_level = self.ruleset.ruleset().success_level(self.result, self.threshold);
     }
 
     pub fn result(&self) -> i32 {
@@ -100,7 +90,13 @@ fn reduce_modifier_dice(penalty_dice: i32, bonus_dice: i32) -> Option<ModifierDi
     }
 }
 
-pub fn croll(query: &str, threshold: i32, penalty_dice: i32, bonus_dice: i32) -> Result<CrollResult, Error> {
+pub fn croll(
+    query: &str,
+    threshold: i32,
+    penalty_dice: i32,
+    bonus_dice: i32,
+    ruleset: RulesetKind,
+) -> Result<CrollResult, Error> {
     let mut rng = RealRng::new();
     let one_result = roll_range(&mut rng, 0, 9);
     let mut ten_result = roll_range(&mut rng, 0, 9);
@@ -139,5 +135,25 @@ pub fn croll(query: &str, threshold: i32, penalty_dice: i32, bonus_dice: i32) ->
         one_result,
         ten_results,
         modifier_dice,
+        ruleset,
     ))
 }
+
+pub fn croll_impl(query: &str, ruleset: RulesetKind) -> Result<CrollResult, Error> {
+    let pattern = r"^(\d+)([\+-]*)$";
+    let re = Regex::new(pattern)?;
+    let q = query.replace(' ', "");
+    let captures = re.captures(&q).ok_or(format!("Invalid query: \"{q}\""))?;
+    let threshold = captures.get(1).ok_or("Invalid threshold:")?.as_str().parse()?;
+    let mut penalty = 0;
+    let mut bonus = 0;
+    match captures.get(2) {
+        None => (),
+        Some(captures_match) => {
+            let penalty_bonus_str = captures_match.as_str();
+            penalty = penalty_bonus_str.chars().filter(|c| *c == '-').count() as i32;
+            bonus = penalty_bonus_str.chars().filter(|c| *c == '+').count() as i32;
+        }
+    }
+    croll(query, threshold, penalty, bonus, ruleset)
+}
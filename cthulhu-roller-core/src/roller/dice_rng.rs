@@ -15,6 +15,12 @@ impl RealRng {
     }
 }
 
+impl Default for RealRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DiceRng for RealRng {
     fn random_range(&mut self, range: RangeInclusive<i32>) -> i32 {
         self.rng.random_range(range)
@@ -5,6 +5,9 @@ pub mod dice_rng;
 pub mod improve_roll;
 pub mod modifier_dice;
 pub mod roll;
+pub mod roll_log;
+#[cfg(feature = "character-sheet")]
+pub mod roll_stats;
 pub mod success_level;
 
 #[cfg(test)]
@@ -31,6 +34,9 @@ mod tests {
     #[case("-2", vec![RollRegex::new(0,0,1.0,-2)])]
     #[case("k10", vec![RollRegex::new(1,10,1.0,0)])]
     #[case("1k10", vec![RollRegex::new(1,10,1.0,0)])]
+    #[case("1w6", vec![RollRegex::new(1,6,1.0,0)])]
+    #[case("1W6", vec![RollRegex::new(1,6,1.0,0)])]
+    #[case("2D4", vec![RollRegex::new(2,4,1.0,0)])]
     #[case("k10x3", vec![RollRegex::new(1,10,3.0,0)])]
     #[case("k10x0.5", vec![RollRegex::new(1,10,0.5,0)])]
     #[case("k10+2", vec![RollRegex::new(1,10,1.0,0),RollRegex::new(0,0,1.0,2)])]
@@ -95,6 +101,20 @@ mod tests {
         assert_eq!(dr.result(), expected);
     }
 
+    #[rstest]
+    #[case("1d0")]
+    #[case("d0")]
+    #[case("2k0+1d6")]
+    fn test_roll_zero_sides(#[case] query: &str) {
+        use crate::roller::{
+            dice_rng::RealRng,
+            roll::{roll_parse, roll_query},
+        };
+
+        assert!(roll_parse(query).is_err());
+        assert!(roll_query(&mut RealRng::new(), query).is_err());
+    }
+
     #[cfg(feature = "character-sheet")]
     #[rstest]
     #[case("2", 2)]
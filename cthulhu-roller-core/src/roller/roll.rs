@@ -118,7 +118,7 @@ pub fn roll_dice<D: DiceRng>(
 }
 
 pub fn roll_parse(query: &str) -> Result<Vec<RollRegex>, Error> {
-    let pattern = r"(?P<dice>(?P<sign>[+-])?(?P<count>\d+)?[dk](?P<sides>\d+))?(?P<mult>[x*]([0-9]*[.])?[0-9]+)?(?P<mod>[+-]?\d+)?";
+    let pattern = r"(?P<dice>(?P<sign>[+-])?(?P<count>\d+)?(?i:[dkw])(?P<sides>\d+))?(?P<mult>[x*]([0-9]*[.])?[0-9]+)?(?P<mod>[+-]?\d+)?";
     let re = Regex::new(pattern)?;
     let dice_stripped = query.replace(' ', "");
     let tokens = split_inclusive(&dice_stripped, &['+', '-']);
@@ -134,6 +134,9 @@ pub fn roll_parse(query: &str) -> Result<Vec<RollRegex>, Error> {
                 Some(m) => m.as_str().parse::<i32>()?,
                 None => 0,
             };
+            if let Some(dice) = captures.name("dice").filter(|_| dice_sides < 1) {
+                return Err(format!("Invalid dice: `{}`", dice.as_str()).into());
+            }
             let dice_count = match captures.name("count") {
                 Some(m) => m.as_str().parse()?,
                 None => {
@@ -0,0 +1,53 @@
+use crate::roller::croll::CrollResult;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Percentile roll kept for session recaps and statistics.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RollRecord {
+    pub time: DateTime<Utc>,
+    pub user_id: u64,
+    #[serde(default)]
+    pub character: Option<String>,
+    #[serde(default)]
+    pub skill: Option<String>,
+    pub croll_result: CrollResult,
+    #[serde(default)]
+    pub luck_spent: i32,
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+impl RollRecord {
+    pub fn new(user_id: u64, croll_result: &CrollResult) -> Self {
+        Self {
+            time: Utc::now(),
+            user_id,
+            character: None,
+            skill: None,
+            croll_result: croll_result.clone(),
+            luck_spent: 0,
+            hidden: false,
+        }
+    }
+
+    pub fn with_character(mut self, character: &str) -> Self {
+        self.character = Some(character.to_string());
+        self
+    }
+
+    pub fn with_skill(mut self, skill: &str) -> Self {
+        self.skill = Some(skill.to_string());
+        self
+    }
+
+    pub fn with_luck_spent(mut self, luck_spent: i32) -> Self {
+        self.luck_spent = luck_spent;
+        self
+    }
+
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+}
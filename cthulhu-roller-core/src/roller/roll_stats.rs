@@ -0,0 +1,232 @@
+use crate::{
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang, locale_text_fmt},
+    roller::{croll::CrollResult, roll_log::RollRecord, success_level::SuccessLevel},
+};
+use std::collections::HashMap;
+
+/// Chi-square critical value for 9 degrees of freedom at 5% significance.
+const CHI_SQUARE_CRITICAL: f64 = 16.919;
+/// Ten buckets need at least five expected rolls each for the fairness test to mean anything.
+pub const MIN_FAIRNESS_ROLLS: usize = 50;
+const TOP_SKILLS: usize = 5;
+const BUCKETS: usize = 10;
+
+const LEVELS: [SuccessLevel; 6] = [
+    SuccessLevel::CriticalSuccess,
+    SuccessLevel::ExtremeSuccess,
+    SuccessLevel::HardSuccess,
+    SuccessLevel::Success,
+    SuccessLevel::Failure,
+    SuccessLevel::CriticalFailure,
+];
+
+/// Unmodified d100 from the ones die and the first tens die, unaffected by bonus/penalty dice and Luck.
+pub fn raw_d100(croll_result: &CrollResult) -> i32 {
+    match (
+        croll_result.ten_rolls.first().copied().unwrap_or_default(),
+        croll_result.one_roll,
+    ) {
+        (0, 0) => 100,
+        (ten, one) => ten * 10 + one,
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RollStats {
+    pub total: usize,
+    success_levels: [usize; 6],
+    pub skills: Vec<(String, usize)>,
+    d100_sum: i64,
+    buckets: [usize; BUCKETS],
+    pub luck_spent: i32,
+}
+
+impl RollStats {
+    pub fn new<'a>(records: impl IntoIterator<Item = &'a RollRecord>) -> Self {
+        let mut stats = Self::default();
+        let mut skills: HashMap<String, usize> = HashMap::new();
+        for record in records {
+            let d100 = raw_d100(&record.croll_result);
+            stats.total += 1;
+            stats.success_levels[record.croll_result.success_level.rank() as usize] += 1;
+            stats.d100_sum += d100 as i64;
+            stats.buckets[((d100 - 1) / 10).clamp(0, BUCKETS as i32 - 1) as usize] += 1;
+            stats.luck_spent += record.luck_spent;
+            if let Some(skill) = &record.skill {
+                *skills.entry(skill.clone()).or_default() += 1;
+            }
+        }
+        stats.skills = skills.into_iter().collect();
+        stats
+            .skills
+            .sort_by(|(a_name, a_count), (b_name, b_count)| b_count.cmp(a_count).then(a_name.cmp(b_name)));
+        stats
+    }
+
+    pub fn count(&self, success_level: SuccessLevel) -> usize {
+        self.success_levels[success_level.rank() as usize]
+    }
+
+    pub fn successes(&self) -> usize {
+        LEVELS
+            .iter()
+            .filter(|level| **level >= SuccessLevel::Success)
+            .map(|level| self.count(*level))
+            .sum()
+    }
+
+    pub fn average_d100(&self) -> Option<f64> {
+        (self.total > 0).then(|| self.d100_sum as f64 / self.total as f64)
+    }
+
+    /// Pearson's statistic of raw d100 results split into ten equal buckets.
+    pub fn chi_square(&self) -> Option<f64> {
+        if self.total < MIN_FAIRNESS_ROLLS {
+            return None;
+        }
+        let expected = self.total as f64 / BUCKETS as f64;
+        Some(
+            self.buckets
+                .iter()
+                .map(|observed| (*observed as f64 - expected).powi(2) / expected)
+                .sum(),
+        )
+    }
+
+    pub fn is_fair(&self) -> Option<bool> {
+        self.chi_square().map(|chi_square| chi_square <= CHI_SQUARE_CRITICAL)
+    }
+
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        if self.total == 0 {
+            return locale_text_by_tag_lang(lang, LocaleTag::NoRolls);
+        }
+
+        let percent = |count: usize| count as f64 * 100.0 / self.total as f64;
+        let mut lines = vec![format!(
+            "{} **{}**, {}: **{}** ({:.1}%)",
+            locale_text_by_tag_lang(lang, LocaleTag::Rolls),
+            self.total,
+            locale_text_by_tag_lang(lang, LocaleTag::Success),
+            self.successes(),
+            percent(self.successes())
+        )];
+        lines.extend(LEVELS.iter().map(|level| {
+            format!(
+                "- {}: **{}** ({:.1}%)",
+                level.to_string_lang(lang),
+                self.count(*level),
+                percent(self.count(*level))
+            )
+        }));
+        if let Some(average) = self.average_d100() {
+            lines.push(format!(
+                "{}: **{:.1}** (50.5)",
+                locale_text_by_tag_lang(lang, LocaleTag::AverageRoll),
+                average
+            ));
+        }
+        lines.push(format!(
+            "{}: 🍀 **{}**",
+            locale_text_by_tag_lang(lang, LocaleTag::LuckSpent),
+            self.luck_spent
+        ));
+        if !self.skills.is_empty() {
+            lines.push(format!(
+                "{}: {}",
+                locale_text_by_tag_lang(lang, LocaleTag::MostUsedSkills),
+                self.skills
+                    .iter()
+                    .take(TOP_SKILLS)
+                    .map(|(skill, count)| format!("{skill} ×{count}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        lines.push(format!(
+            "{}: {}",
+            locale_text_by_tag_lang(lang, LocaleTag::DiceFairness),
+            match self.chi_square() {
+                Some(chi_square) => format!(
+                    "χ² = **{:.2}** / {} → {}",
+                    chi_square,
+                    CHI_SQUARE_CRITICAL,
+                    locale_text_by_tag_lang(
+                        lang,
+                        if self.is_fair() == Some(true) {
+                            LocaleTag::DiceFair
+                        } else {
+                            LocaleTag::DiceBiased
+                        }
+                    )
+                ),
+                None => locale_text_fmt(lang, LocaleTag::NotEnoughRolls, &[&MIN_FAIRNESS_ROLLS]),
+            }
+        ));
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{roller::croll::croll, ruleset::RulesetKind};
+    use rstest::rstest;
+
+    fn record(d100: i32, skill: Option<&str>) -> RollRecord {
+        let mut croll_result = croll("50", 50, 0, 0, RulesetKind::Classic).unwrap();
+        croll_result.ten_rolls = vec![(d100 / 10) % 10];
+        croll_result.one_roll = d100 % 10;
+        croll_result.set_result(d100);
+        let record = RollRecord::new(1, &croll_result);
+        match skill {
+            Some(skill) => record.with_skill(skill),
+            None => record,
+        }
+    }
+
+    #[rstest]
+    #[case(1, 0, 1)]
+    #[case(10, 1, 0)]
+    #[case(55, 5, 5)]
+    #[case(100, 0, 0)]
+    fn raw_d100_from_dice(#[case] expected: i32, #[case] ten: i32, #[case] one: i32) {
+        let mut croll_result = croll("50", 50, 0, 0, RulesetKind::Classic).unwrap();
+        croll_result.ten_rolls = vec![ten, 9];
+        croll_result.one_roll = one;
+        assert_eq!(raw_d100(&croll_result), expected);
+    }
+
+    #[test]
+    fn counts_and_skills() {
+        let records = [
+            record(1, Some("Dodge")),
+            record(40, Some("Spot Hidden")),
+            record(99, Some("Spot Hidden")),
+            record(100, None),
+        ];
+        let stats = RollStats::new(&records);
+        assert_eq!(stats.total, 4);
+        assert_eq!(stats.count(SuccessLevel::CriticalSuccess), 1);
+        assert_eq!(stats.count(SuccessLevel::Failure), 1);
+        assert_eq!(stats.count(SuccessLevel::CriticalFailure), 1);
+        assert_eq!(stats.successes(), 2);
+        assert_eq!(stats.skills[0], ("Spot Hidden".to_string(), 2));
+        assert_eq!(stats.average_d100(), Some(60.0));
+        assert_eq!(stats.chi_square(), None);
+    }
+
+    #[test]
+    fn uniform_rolls_are_fair() {
+        let records: Vec<_> = (1..=100).map(|d100| record(d100, None)).collect();
+        let stats = RollStats::new(&records);
+        assert_eq!(stats.chi_square(), Some(0.0));
+        assert_eq!(stats.is_fair(), Some(true));
+    }
+
+    #[test]
+    fn skewed_rolls_are_biased() {
+        let records: Vec<_> = (0..100).map(|_| record(95, None)).collect();
+        assert_eq!(RollStats::new(&records).is_fair(), Some(false));
+    }
+}
@@ -0,0 +1,313 @@
+#[cfg(feature = "character-sheet")]
+use crate::locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang};
+use crate::roller::success_level::SuccessLevel;
+#[cfg(feature = "character-sheet")]
+use crate::{
+    character::{Attributes, default_skills, skill_map_wrapper, skill_unimprovable_map_wrapper},
+    types::{Error, SkillMap},
+};
+use serde::{Deserialize, Serialize};
+
+/// Game rules that differ between Call of Cthulhu flavours; defaults follow Call of Cthulhu 7th Edition.
+pub trait Ruleset: Sync {
+    fn success_level(&self, result: i32, threshold: i32) -> SuccessLevel {
+        match result {
+            100 => SuccessLevel::CriticalFailure,
+            1 => SuccessLevel::CriticalSuccess,
+            _ => {
+                if threshold < 50 && result >= 96 {
+                    SuccessLevel::CriticalFailure
+                } else if result <= threshold / 5 {
+                    SuccessLevel::ExtremeSuccess
+                } else if result <= threshold / 2 {
+                    SuccessLevel::HardSuccess
+                } else if result <= threshold {
+                    SuccessLevel::Success
+                } else {
+                    SuccessLevel::Failure
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "character-sheet")]
+    fn hit_points(&self, attributes: &Attributes) -> i32 {
+        (attributes.constitution() + attributes.size()) / 10
+    }
+
+    #[cfg(feature = "character-sheet")]
+    fn starting_luck(&self, rolled: i32) -> i32 {
+        rolled
+    }
+
+    #[cfg(feature = "character-sheet")]
+    fn default_skills(&self, attributes: &Attributes) -> Result<SkillMap, Error> {
+        default_skills(attributes)
+    }
+
+    /// Dice added to a skill that passed its improvement check; the rulebook's `1d10` is available as a house rule.
+    fn improve_dice(&self) -> &'static str {
+        "1d6"
+    }
+
+    /// Whether a marked skill has to pass an improvement roll before gaining `improve_dice`.
+    fn improvement_check(&self) -> bool {
+        true
+    }
+
+    /// Whether a skill roll of given level marks the skill for improvement.
+    fn marks_skill(&self, success_level: SuccessLevel) -> bool {
+        success_level >= SuccessLevel::Success
+    }
+
+    /// Luck gained by `improve_luck` depending on whether the improvement check passed.
+    fn luck_recovery(&self, check_passed: bool) -> Option<&'static str> {
+        check_passed.then_some("1d10")
+    }
+
+    fn luck_spending(&self) -> bool {
+        true
+    }
+
+    /// Pulp heroes may spend all remaining Luck to cheat death.
+    fn luck_avoids_death(&self) -> bool {
+        false
+    }
+}
+
+pub struct Classic;
+
+impl Ruleset for Classic {}
+
+pub struct Pulp;
+
+impl Ruleset for Pulp {
+    #[cfg(feature = "character-sheet")]
+    fn hit_points(&self, attributes: &Attributes) -> i32 {
+        (attributes.constitution() + attributes.size()) / 5
+    }
+
+    fn luck_recovery(&self, check_passed: bool) -> Option<&'static str> {
+        Some(if check_passed { "2d10+10" } else { "1d10+5" })
+    }
+
+    fn luck_avoids_death(&self) -> bool {
+        true
+    }
+}
+
+pub struct DeltaGreen;
+
+impl Ruleset for DeltaGreen {
+    /// Matching digits turn a success into a critical and a failure into a fumble; there are no hard or extreme levels.
+    fn success_level(&self, result: i32, threshold: i32) -> SuccessLevel {
+        let doubles = result < 100 && result % 11 == 0;
+        match result {
+            1 => SuccessLevel::CriticalSuccess,
+            100 => SuccessLevel::CriticalFailure,
+            _ if result <= threshold && doubles => SuccessLevel::CriticalSuccess,
+            _ if result <= threshold => SuccessLevel::Success,
+            _ if doubles => SuccessLevel::CriticalFailure,
+            _ => SuccessLevel::Failure,
+        }
+    }
+
+    /// Half of STR + CON on the 3-18 scale, rounded up.
+    #[cfg(feature = "character-sheet")]
+    fn hit_points(&self, attributes: &Attributes) -> i32 {
+        (attributes.strength() / 5 + attributes.constitution() / 5 + 1) / 2
+    }
+
+    #[cfg(feature = "character-sheet")]
+    fn starting_luck(&self, _rolled: i32) -> i32 {
+        50
+    }
+
+    #[cfg(feature = "character-sheet")]
+    fn default_skills(&self, _attributes: &Attributes) -> Result<SkillMap, Error> {
+        let mut skill_map = SkillMap::new();
+        for (locale_tag, value) in DELTA_GREEN_SKILLS {
+            let (k, v) = skill_map_wrapper(locale_tag, value)?;
+            skill_map.insert(k, v);
+        }
+        let (k, v) = skill_unimprovable_map_wrapper(LocaleTag::CthulhuMythos, 0)?;
+        skill_map.insert(k, v);
+        Ok(skill_map)
+    }
+
+    fn improve_dice(&self) -> &'static str {
+        "1d4"
+    }
+
+    fn improvement_check(&self) -> bool {
+        false
+    }
+
+    fn marks_skill(&self, success_level: SuccessLevel) -> bool {
+        success_level < SuccessLevel::Success
+    }
+
+    fn luck_recovery(&self, _check_passed: bool) -> Option<&'static str> {
+        None
+    }
+
+    fn luck_spending(&self) -> bool {
+        false
+    }
+}
+
+/// Delta Green skills with their base values; Unnatural is tracked as Cthulhu Mythos.
+#[cfg(feature = "character-sheet")]
+const DELTA_GREEN_SKILLS: [(LocaleTag, i32); 38] = [
+    (LocaleTag::Accounting, 10),
+    (LocaleTag::Alertness, 20),
+    (LocaleTag::Anthropology, 0),
+    (LocaleTag::Archeology, 0),
+    (LocaleTag::Artillery, 0),
+    (LocaleTag::Athletics, 30),
+    (LocaleTag::Bureaucracy, 10),
+    (LocaleTag::ComputerUse, 0),
+    (LocaleTag::Criminology, 10),
+    (LocaleTag::Demolitions, 0),
+    (LocaleTag::Disguise, 10),
+    (LocaleTag::Dodge, 30),
+    (LocaleTag::DriveAuto, 20),
+    (LocaleTag::Firearms, 20),
+    (LocaleTag::FirstAid, 10),
+    (LocaleTag::Forensics, 0),
+    (LocaleTag::OperateHeavyMachinery, 10),
+    (LocaleTag::HeavyWeapons, 0),
+    (LocaleTag::History, 10),
+    (LocaleTag::Humint, 10),
+    (LocaleTag::Law, 0),
+    (LocaleTag::Medicine, 0),
+    (LocaleTag::MeleeWeapons, 30),
+    (LocaleTag::MilitaryScience, 0),
+    (LocaleTag::Navigate, 10),
+    (LocaleTag::Occult, 10),
+    (LocaleTag::Persuade, 20),
+    (LocaleTag::Pharmacy, 0),
+    (LocaleTag::Pilot, 0),
+    (LocaleTag::Psychoanalysis, 10),
+    (LocaleTag::Ride, 10),
+    (LocaleTag::Search, 20),
+    (LocaleTag::Sigint, 0),
+    (LocaleTag::Stealth, 10),
+    (LocaleTag::Surgery, 0),
+    (LocaleTag::Survival, 10),
+    (LocaleTag::Swim, 20),
+    (LocaleTag::FightingBrawl, 40),
+];
+
+pub struct Achtung;
+
+impl Ruleset for Achtung {
+    /// Wartime investigators start with artillery and demolitions training available.
+    #[cfg(feature = "character-sheet")]
+    fn default_skills(&self, attributes: &Attributes) -> Result<SkillMap, Error> {
+        let mut skill_map = default_skills(attributes)?;
+        for (locale_tag, value) in [(LocaleTag::Artillery, 1), (LocaleTag::Demolitions, 1)] {
+            let (k, v) = skill_map_wrapper(locale_tag, value)?;
+            skill_map.insert(k, v);
+        }
+        Ok(skill_map)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RulesetKind {
+    #[default]
+    Classic,
+    Pulp,
+    DeltaGreen,
+    Achtung,
+}
+
+impl RulesetKind {
+    #[cfg(feature = "character-sheet")]
+    pub const ALL: [RulesetKind; 4] = [
+        RulesetKind::Classic,
+        RulesetKind::Pulp,
+        RulesetKind::DeltaGreen,
+        RulesetKind::Achtung,
+    ];
+
+    pub fn ruleset(self) -> &'static dyn Ruleset {
+        match self {
+            RulesetKind::Classic => &Classic,
+            RulesetKind::Pulp => &Pulp,
+            RulesetKind::DeltaGreen => &DeltaGreen,
+            RulesetKind::Achtung => &Achtung,
+        }
+    }
+
+    #[cfg(feature = "character-sheet")]
+    pub fn to_locale_tag(self) -> LocaleTag {
+        match self {
+            RulesetKind::Classic => LocaleTag::RulesetClassic,
+            RulesetKind::Pulp => LocaleTag::RulesetPulp,
+            RulesetKind::DeltaGreen => LocaleTag::RulesetDeltaGreen,
+            RulesetKind::Achtung => LocaleTag::RulesetAchtung,
+        }
+    }
+
+    #[cfg(feature = "character-sheet")]
+    pub fn from_text(text: &str) -> Option<Self> {
+        let text = text.trim();
+        Self::ALL.into_iter().find(|kind| {
+            format!("{kind:?}").eq_ignore_ascii_case(text)
+                || LocaleLang::all()
+                    .any(|lang| locale_text_by_tag_lang(lang, kind.to_locale_tag()).eq_ignore_ascii_case(text))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, 50, SuccessLevel::CriticalSuccess)]
+    #[case(10, 50, SuccessLevel::ExtremeSuccess)]
+    #[case(25, 50, SuccessLevel::HardSuccess)]
+    #[case(44, 50, SuccessLevel::Success)]
+    #[case(51, 50, SuccessLevel::Failure)]
+    #[case(97, 40, SuccessLevel::CriticalFailure)]
+    fn classic_success_level(#[case] result: i32, #[case] threshold: i32, #[case] expected: SuccessLevel) {
+        assert_eq!(Classic.success_level(result, threshold), expected);
+    }
+
+    #[rstest]
+    #[case(1, 50, SuccessLevel::CriticalSuccess)]
+    #[case(10, 50, SuccessLevel::Success)]
+    #[case(33, 50, SuccessLevel::CriticalSuccess)]
+    #[case(50, 50, SuccessLevel::Success)]
+    #[case(66, 50, SuccessLevel::CriticalFailure)]
+    #[case(97, 40, SuccessLevel::Failure)]
+    #[case(100, 99, SuccessLevel::CriticalFailure)]
+    fn delta_green_success_level(#[case] result: i32, #[case] threshold: i32, #[case] expected: SuccessLevel) {
+        assert_eq!(DeltaGreen.success_level(result, threshold), expected);
+    }
+
+    #[cfg(feature = "character-sheet")]
+    #[rstest]
+    #[case(RulesetKind::Classic, 11)]
+    #[case(RulesetKind::Pulp, 23)]
+    #[case(RulesetKind::DeltaGreen, 12)]
+    fn ruleset_hit_points(#[case] kind: RulesetKind, #[case] expected: i32) {
+        let attributes = Attributes::new(60, 55, 60, 50, 50, 50, 50, 50).unwrap();
+        assert_eq!(kind.ruleset().hit_points(&attributes), expected);
+    }
+
+    #[cfg(feature = "character-sheet")]
+    #[rstest]
+    #[case("pulp", Some(RulesetKind::Pulp))]
+    #[case("DeltaGreen", Some(RulesetKind::DeltaGreen))]
+    #[case("Delta Green", Some(RulesetKind::DeltaGreen))]
+    #[case("Zew Cthulhu", Some(RulesetKind::Classic))]
+    #[case("Warhammer", None)]
+    fn ruleset_from_text(#[case] text: &str, #[case] expected: Option<RulesetKind>) {
+        assert_eq!(RulesetKind::from_text(text), expected);
+    }
+}
@@ -0,0 +1,480 @@
+use crate::{
+    character::{Character, InsanityEpisode},
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
+    roller::{roll_log::RollRecord, success_level::SuccessLevel},
+    types::UsersHashMap,
+};
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// State of a character when the session started.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CharacterSnapshot {
+    pub user_id: u64,
+    pub name: String,
+    pub hp: i32,
+    pub sanity: i32,
+    pub luck: i32,
+    pub magic: i32,
+    pub dead: bool,
+    #[serde(default)]
+    pub damage_taken: i32,
+    #[serde(default)]
+    pub sanity_lost: i32,
+    /// Number of insanity episodes, later ones started during the session.
+    #[serde(default)]
+    pub insanity_episodes: usize,
+    pub marked_skills: Vec<String>,
+    pub items: BTreeMap<String, i32>,
+    pub skills: BTreeMap<String, i32>,
+}
+
+impl CharacterSnapshot {
+    pub fn new(user_id: u64, character: &Character) -> Self {
+        Self {
+            user_id,
+            name: character.name.clone(),
+            hp: character.hp.current,
+            sanity: character.sanity.current,
+            luck: character.luck.current,
+            magic: character.magic.current,
+            dead: character.dead,
+            damage_taken: character.damage_taken,
+            sanity_lost: character.sanity_lost,
+            insanity_episodes: character.insanity_episodes.len(),
+            marked_skills: character
+                .skills
+                .iter()
+                .filter(|(_, skill)| skill.to_improve)
+                .map(|(key, _)| key.clone())
+                .collect(),
+            items: item_quantities(character),
+            skills: character
+                .skills
+                .iter()
+                .map(|(key, skill)| (key.clone(), skill.value))
+                .collect(),
+        }
+    }
+}
+
+fn item_quantities(character: &Character) -> BTreeMap<String, i32> {
+    let mut items = BTreeMap::new();
+    for item in &character.items {
+        *items.entry(item.name.clone()).or_default() += item.quantity;
+    }
+    items
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Session {
+    pub started: DateTime<Utc>,
+    pub snapshots: Vec<CharacterSnapshot>,
+}
+
+impl Session {
+    pub fn start(users: &UsersHashMap) -> Self {
+        Self {
+            started: Utc::now(),
+            snapshots: users
+                .iter()
+                .flat_map(|(user_id, user_data)| {
+                    user_data
+                        .characters
+                        .values()
+                        .map(|character| CharacterSnapshot::new(*user_id, character))
+                })
+                .collect(),
+        }
+    }
+
+    /// Compares snapshots with the current sheets; characters created or deleted meanwhile are skipped.
+    pub fn recap(&self, users: &UsersHashMap, rolls: &[RollRecord], lang: LocaleLang) -> SessionRecap {
+        let mut characters: Vec<CharacterChanges> = self
+            .snapshots
+            .iter()
+            .filter_map(|snapshot| {
+                let character = users.get(&snapshot.user_id)?.characters.get(&snapshot.name)?;
+                Some(CharacterChanges::new(snapshot, character, lang))
+            })
+            .collect();
+        characters.sort_by(|a, b| a.name.cmp(&b.name));
+
+        SessionRecap {
+            started: self.started,
+            ended: Utc::now(),
+            characters,
+            highlights: rolls
+                .iter()
+                .filter(|record| record.time >= self.started && !record.hidden)
+                .filter(|record| {
+                    matches!(
+                        record.croll_result.success_level,
+                        SuccessLevel::CriticalSuccess | SuccessLevel::CriticalFailure
+                    )
+                })
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// Changes of a character during the session; stat pairs are values before and after.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CharacterChanges {
+    pub name: String,
+    pub hp: (i32, i32),
+    pub sanity: (i32, i32),
+    /// Hit Points lost during the session, healing not counted.
+    pub damage_taken: i32,
+    /// Sanity points lost during the session, recovery not counted.
+    pub sanity_lost: i32,
+    pub luck: (i32, i32),
+    pub magic: (i32, i32),
+    pub skills_marked: Vec<String>,
+    pub skills_improved: Vec<(String, i32)>,
+    pub items_gained: Vec<(String, i32)>,
+    pub items_lost: Vec<(String, i32)>,
+    pub died: bool,
+    pub insanities: Vec<InsanityEpisode>,
+}
+
+impl CharacterChanges {
+    fn new(snapshot: &CharacterSnapshot, character: &Character, lang: LocaleLang) -> Self {
+        let skill_name = |key: &str| {
+            character
+                .skills
+                .get(key)
+                .map(|skill| skill.name.get(lang))
+                .unwrap_or(key.to_string())
+        };
+        let mut skills_marked: Vec<String> = character
+            .skills
+            .iter()
+            .filter(|(key, skill)| skill.to_improve && !snapshot.marked_skills.contains(key))
+            .map(|(key, _)| skill_name(key))
+            .collect();
+        skills_marked.sort();
+        let mut skills_improved: Vec<(String, i32)> = character
+            .skills
+            .iter()
+            .filter_map(|(key, skill)| {
+                let before = snapshot.skills.get(key).copied().unwrap_or_default();
+                (skill.value > before).then(|| (skill_name(key), skill.value - before))
+            })
+            .collect();
+        skills_improved.sort();
+
+        let items = item_quantities(character);
+        let mut items_gained = vec![];
+        let mut items_lost = vec![];
+        for name in snapshot
+            .items
+            .keys()
+            .chain(items.keys().filter(|k| !snapshot.items.contains_key(*k)))
+        {
+            let delta =
+                items.get(name).copied().unwrap_or_default() - snapshot.items.get(name).copied().unwrap_or_default();
+            if delta > 0 {
+                items_gained.push((name.clone(), delta));
+            } else if delta < 0 {
+                items_lost.push((name.clone(), -delta));
+            }
+        }
+        items_gained.sort();
+        items_lost.sort();
+
+        Self {
+            name: character.name.clone(),
+            hp: (snapshot.hp, character.hp.current),
+            sanity: (snapshot.sanity, character.sanity.current),
+            damage_taken: character.damage_taken.saturating_sub(snapshot.damage_taken).max(0),
+            sanity_lost: character.sanity_lost.saturating_sub(snapshot.sanity_lost).max(0),
+            luck: (snapshot.luck, character.luck.current),
+            magic: (snapshot.magic, character.magic.current),
+            skills_marked,
+            skills_improved,
+            items_gained,
+            items_lost,
+            died: !snapshot.dead && character.dead,
+            insanities: character
+                .insanity_episodes
+                .iter()
+                .skip(snapshot.insanity_episodes)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        [self.hp, self.sanity, self.luck, self.magic]
+            .iter()
+            .all(|(before, after)| before == after)
+            && self.damage_taken == 0
+            && self.sanity_lost == 0
+            && self.skills_marked.is_empty()
+            && self.skills_improved.is_empty()
+            && self.items_gained.is_empty()
+            && self.items_lost.is_empty()
+            && !self.died
+            && self.insanities.is_empty()
+    }
+
+    fn to_markdown(&self, lang: LocaleLang) -> String {
+        let mut lines = vec![];
+        for (icon, tag, (before, after), lost) in [
+            (
+                "❤️",
+                LocaleTag::Hp,
+                self.hp,
+                Some((LocaleTag::DamageTaken, self.damage_taken)),
+            ),
+            (
+                "🧠",
+                LocaleTag::Sanity,
+                self.sanity,
+                Some((LocaleTag::SanityLost, self.sanity_lost)),
+            ),
+            ("🍀", LocaleTag::Luck, self.luck, None),
+            ("🪄", LocaleTag::MagicPoints, self.magic, None),
+        ] {
+            let lost = lost.filter(|(_, lost)| *lost > 0);
+            if before == after && lost.is_none() {
+                continue;
+            }
+            let mut line = format!(
+                "- {} {}: {} → {} ({} **{:+}**",
+                icon,
+                locale_text_by_tag_lang(lang, tag),
+                before,
+                after,
+                locale_text_by_tag_lang(lang, LocaleTag::NetChange).to_lowercase(),
+                after - before
+            );
+            if let Some((lost_tag, lost)) = lost {
+                line.push_str(&format!(
+                    "; {} **{}**",
+                    locale_text_by_tag_lang(lang, lost_tag).to_lowercase(),
+                    lost
+                ));
+            }
+            line.push(')');
+            lines.push(line);
+        }
+        if !self.skills_marked.is_empty() {
+            lines.push(format!(
+                "- {}: {}",
+                locale_text_by_tag_lang(lang, LocaleTag::SkillsMarked),
+                self.skills_marked.join(", ")
+            ));
+        }
+        if !self.skills_improved.is_empty() {
+            lines.push(format!(
+                "- {}: {}",
+                locale_text_by_tag_lang(lang, LocaleTag::SkillsImproved),
+                format_amounts(&self.skills_improved, "+")
+            ));
+        }
+        if !self.items_gained.is_empty() {
+            lines.push(format!(
+                "- {}: {}",
+                locale_text_by_tag_lang(lang, LocaleTag::ItemsGained),
+                format_amounts(&self.items_gained, "×")
+            ));
+        }
+        if !self.items_lost.is_empty() {
+            lines.push(format!(
+                "- {}: {}",
+                locale_text_by_tag_lang(lang, LocaleTag::ItemsLost),
+                format_amounts(&self.items_lost, "×")
+            ));
+        }
+        if self.died {
+            lines.push(format!(
+                "- 💀 {}",
+                locale_text_by_tag_lang(lang, LocaleTag::CharacterDied)
+            ));
+        }
+        for episode in &self.insanities {
+            let mut line = format!(
+                "- 🤯 {}: {} ({})",
+                locale_text_by_tag_lang(lang, LocaleTag::CharacterWentInsane),
+                locale_text_by_tag_lang(lang, episode.kind.to_locale_tag()),
+                episode.duration_to_string(lang)
+            );
+            if let Some(cause) = &episode.cause {
+                line.push_str(format!(" — {cause}").as_str());
+            }
+            lines.push(line);
+        }
+        format!("### {}\n{}", self.name, lines.join("\n"))
+    }
+}
+
+fn format_amounts(amounts: &[(String, i32)], prefix: &str) -> String {
+    amounts
+        .iter()
+        .map(|(name, amount)| format!("{name} {prefix}{amount}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionRecap {
+    pub started: DateTime<Utc>,
+    pub ended: DateTime<Utc>,
+    pub characters: Vec<CharacterChanges>,
+    pub highlights: Vec<RollRecord>,
+}
+
+impl SessionRecap {
+    pub fn title(&self, lang: LocaleLang) -> String {
+        format!(
+            "{} {} – {}",
+            locale_text_by_tag_lang(lang, LocaleTag::SessionRecap),
+            self.started.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+            self.ended.with_timezone(&Local).format("%H:%M")
+        )
+    }
+
+    pub fn file_name(&self) -> String {
+        format!("session-{}.md", self.started.with_timezone(&Local).format("%Y-%m-%d"))
+    }
+
+    /// Body shared by the embed and the exported file.
+    pub fn to_markdown_body(&self, lang: LocaleLang) -> String {
+        let mut sections: Vec<String> = self
+            .characters
+            .iter()
+            .filter(|changes| !changes.is_empty())
+            .map(|changes| changes.to_markdown(lang))
+            .collect();
+        if sections.is_empty() {
+            sections.push(locale_text_by_tag_lang(lang, LocaleTag::NoChanges));
+        }
+
+        if !self.highlights.is_empty() {
+            let highlights = self
+                .highlights
+                .iter()
+                .map(|record| {
+                    let who = match (&record.character, &record.skill) {
+                        (Some(character), Some(skill)) => format!("{character} ({skill})"),
+                        (Some(character), None) => character.clone(),
+                        (None, Some(skill)) => skill.clone(),
+                        (None, None) => format!("<@{}>", record.user_id),
+                    };
+                    format!(
+                        "- {}: **{}** / {} {}",
+                        who,
+                        record.croll_result.result(),
+                        record.croll_result.threshold,
+                        record.croll_result.success_level.to_string_lang(lang)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            sections.push(format!(
+                "### {}\n{}",
+                locale_text_by_tag_lang(lang, LocaleTag::RollHighlights),
+                highlights
+            ));
+        }
+
+        sections.join("\n\n")
+    }
+
+    pub fn to_markdown(&self, lang: LocaleLang) -> String {
+        format!("## {}\n\n{}\n", self.title(lang), self.to_markdown_body(lang))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bot_data::UserData,
+        character::{Attributes, BoutKind, InsanityKind, Item, hp_impl, san_impl},
+        roller::{croll::croll, dice_rng::RealRng},
+        ruleset::RulesetKind,
+    };
+    use std::collections::HashMap;
+
+    fn party() -> UsersHashMap {
+        let mut character = Character::new(
+            "Harvey",
+            &None,
+            Attributes::new(50, 50, 50, 50, 50, 50, 50, 50).unwrap(),
+            50,
+            None,
+            RulesetKind::Classic,
+        )
+        .unwrap();
+        character.items.push(Item::new("Lantern", 1, None));
+        let mut user_data = UserData::default();
+        user_data.characters.insert(character.name.clone(), character);
+        HashMap::from([(1, user_data)])
+    }
+
+    #[test]
+    fn recap_lists_changes() {
+        let mut users = party();
+        let character = users.get_mut(&1).unwrap().characters.get_mut("Harvey").unwrap();
+        character.start_insanity(&mut RealRng::new(), InsanityKind::Temporary, None, None);
+        let session = Session::start(&users);
+        let character = users.get_mut(&1).unwrap().characters.get_mut("Harvey").unwrap();
+        character.start_insanity(
+            &mut RealRng::new(),
+            InsanityKind::Indefinite,
+            Some("Ghoul".to_string()),
+            None,
+        );
+        assert!(hp_impl(character, "-8", LocaleLang::default()).is_ok());
+        character.hp.modify(5);
+        assert!(san_impl(character, "-5", LocaleLang::default(), BoutKind::Summary).is_ok());
+        character.items.clear();
+        character.items.push(Item::new("Revolver", 1, None));
+        character.skills.values_mut().next().unwrap().to_improve = true;
+        character.dead = true;
+
+        let recap = session.recap(&users, &[], LocaleLang::default());
+        let changes = &recap.characters[0];
+        assert_eq!(changes.hp.1 - changes.hp.0, -3);
+        assert_eq!(changes.sanity.1 - changes.sanity.0, -5);
+        assert_eq!((changes.damage_taken, changes.sanity_lost), (8, 5));
+        assert_eq!(changes.items_gained, vec![("Revolver".to_string(), 1)]);
+        assert_eq!(changes.items_lost, vec![("Lantern".to_string(), 1)]);
+        assert_eq!(changes.skills_marked.len(), 1);
+        assert!(changes.died);
+        assert_eq!(changes.insanities.len(), 1);
+        assert_eq!(changes.insanities[0].kind, InsanityKind::Indefinite);
+        let markdown = recap.to_markdown(LocaleLang::default());
+        assert!(markdown.contains("### Harvey"));
+        assert!(markdown.contains("— Ghoul"));
+        assert!(markdown.contains("net change **-3**; damage taken **8**"));
+    }
+
+    #[test]
+    fn recap_without_changes() {
+        let users = party();
+        let session = Session::start(&users);
+        let recap = session.recap(&users, &[], LocaleLang::default());
+        assert!(recap.characters[0].is_empty());
+    }
+
+    #[test]
+    fn highlights_only_critical_rolls_from_session() {
+        let users = party();
+        let session = Session::start(&users);
+        let mut critical = croll("50", 50, 0, 0, RulesetKind::Classic).unwrap();
+        critical.set_result(1);
+        let mut regular = critical.clone();
+        regular.set_result(40);
+        let mut old = RollRecord::new(1, &critical);
+        old.time = session.started - chrono::Duration::hours(1);
+        let rolls = [old, RollRecord::new(1, &critical), RollRecord::new(1, &regular)];
+        let recap = session.recap(&users, &rolls, LocaleLang::default());
+        assert_eq!(recap.highlights.len(), 1);
+    }
+}
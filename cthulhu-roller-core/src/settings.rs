@@ -0,0 +1,276 @@
+#[cfg(feature = "character-sheet")]
+use crate::locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang, locale_text_fmt};
+use crate::ruleset::RulesetKind;
+#[cfg(feature = "character-sheet")]
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "character-sheet")]
+use std::fmt::Display;
+
+/// House rules of a guild; missing values fall back to defaults.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct GuildSettings {
+    pub ruleset: RulesetKind,
+    /// Overrides ruleset improvement dice, e.g. `1d10` instead of `1d6`.
+    pub improve_dice: Option<String>,
+    pub min_attribute_value: i32,
+    pub max_characters_per_user: usize,
+    pub gm_role_name: String,
+    pub luck_in_combat: bool,
+    /// Receives whispered rolls; the server owner when not set.
+    pub keeper_id: Option<u64>,
+    /// Channels where `[[1d6]]` markers in regular messages are rolled.
+    #[cfg(feature = "inline-rolls")]
+    pub inline_roll_channels: Vec<u64>,
+}
+
+impl Default for GuildSettings {
+    fn default() -> Self {
+        Self {
+            ruleset: RulesetKind::default(),
+            improve_dice: None,
+            min_attribute_value: 40,
+            max_characters_per_user: 3,
+            gm_role_name: "GM".into(),
+            luck_in_combat: true,
+            keeper_id: None,
+            #[cfg(feature = "inline-rolls")]
+            inline_roll_channels: vec![],
+        }
+    }
+}
+
+#[cfg(feature = "inline-rolls")]
+impl GuildSettings {
+    pub fn inline_rolls(&self, channel_id: u64) -> bool {
+        self.inline_roll_channels.contains(&channel_id)
+    }
+
+    pub fn set_inline_rolls(&mut self, channel_id: u64, enabled: bool) {
+        self.inline_roll_channels.retain(|id| *id != channel_id);
+        if enabled {
+            self.inline_roll_channels.push(channel_id);
+        }
+    }
+}
+
+#[cfg(feature = "character-sheet")]
+impl GuildSettings {
+    /// Improvement dice of a character played under `ruleset`: the house rule if set, otherwise the ruleset's dice.
+    pub fn improve_dice(&self, ruleset: RulesetKind) -> &str {
+        self.improve_dice.as_deref().unwrap_or(ruleset.ruleset().improve_dice())
+    }
+
+    pub fn set(&mut self, key: SettingKey, value: &str) -> Result<(), SettingsError> {
+        let value = value.trim();
+        let invalid = || SettingsError::InvalidValue(key, value.to_string());
+        match key {
+            SettingKey::ImproveDice => {
+                self.improve_dice = match value {
+                    "" | "-" => None,
+                    _ if DICE_PATTERN.is_match(value) => Some(value.to_string()),
+                    _ => return Err(invalid()),
+                }
+            }
+            SettingKey::MinAttributeValue => {
+                self.min_attribute_value = value
+                    .parse()
+                    .ok()
+                    .filter(|v| (0..=90).contains(v))
+                    .ok_or_else(invalid)?
+            }
+            SettingKey::MaxCharactersPerUser => {
+                self.max_characters_per_user = value.parse().ok().filter(|v| *v > 0).ok_or_else(invalid)?
+            }
+            SettingKey::GmRoleName => {
+                if value.is_empty() {
+                    return Err(invalid());
+                }
+                self.gm_role_name = value.to_string()
+            }
+            SettingKey::LuckInCombat => self.luck_in_combat = parse_bool(value).ok_or_else(invalid)?,
+            SettingKey::Keeper => {
+                self.keeper_id = match value {
+                    "" | "-" => None,
+                    _ => Some(parse_user_id(value).ok_or_else(invalid)?),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        SettingKey::ALL
+            .iter()
+            .map(|key| {
+                let value = match key {
+                    SettingKey::ImproveDice => self.improve_dice(self.ruleset).to_string(),
+                    SettingKey::MinAttributeValue => self.min_attribute_value.to_string(),
+                    SettingKey::MaxCharactersPerUser => self.max_characters_per_user.to_string(),
+                    SettingKey::GmRoleName => self.gm_role_name.clone(),
+                    SettingKey::LuckInCombat => self.luck_in_combat.to_string(),
+                    SettingKey::Keeper => self.keeper_id.map_or("-".to_string(), |id| format!("<@{id}>")),
+                };
+                format!("`{key}`: **{value}**")
+            })
+            .chain([format!(
+                "{}: **{}**",
+                locale_text_by_tag_lang(lang, LocaleTag::Ruleset),
+                locale_text_by_tag_lang(lang, self.ruleset.to_locale_tag())
+            )])
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(feature = "character-sheet")]
+lazy_static! {
+    static ref DICE_PATTERN: regex::Regex = regex::Regex::new(r"^([1-9]\d*)?(?i:[dkw])[1-9]\d*([+-]\d+)?$").unwrap();
+}
+
+#[cfg(feature = "character-sheet")]
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "true" | "yes" | "on" | "1" | "tak" => Some(true),
+        "false" | "no" | "off" | "0" | "nie" => Some(false),
+        _ => None,
+    }
+}
+
+/// Accepts a user mention (`<@123>`, `<@!123>`) or a raw user ID.
+#[cfg(feature = "character-sheet")]
+fn parse_user_id(value: &str) -> Option<u64> {
+    value
+        .trim_start_matches("<@")
+        .trim_start_matches('!')
+        .trim_end_matches('>')
+        .parse()
+        .ok()
+}
+
+#[cfg(feature = "character-sheet")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SettingKey {
+    ImproveDice,
+    MinAttributeValue,
+    MaxCharactersPerUser,
+    GmRoleName,
+    LuckInCombat,
+    Keeper,
+}
+
+#[cfg(feature = "character-sheet")]
+impl SettingKey {
+    pub const ALL: [SettingKey; 6] = [
+        SettingKey::ImproveDice,
+        SettingKey::MinAttributeValue,
+        SettingKey::MaxCharactersPerUser,
+        SettingKey::GmRoleName,
+        SettingKey::LuckInCombat,
+        SettingKey::Keeper,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SettingKey::ImproveDice => "improve_dice",
+            SettingKey::MinAttributeValue => "min_attribute_value",
+            SettingKey::MaxCharactersPerUser => "max_characters_per_user",
+            SettingKey::GmRoleName => "gm_role_name",
+            SettingKey::LuckInCombat => "luck_in_combat",
+            SettingKey::Keeper => "keeper",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|key| key.name().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+#[cfg(feature = "character-sheet")]
+impl Display for SettingKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(feature = "character-sheet")]
+pub enum SettingsError {
+    UnknownSetting(String),
+    InvalidValue(SettingKey, String),
+}
+
+#[cfg(feature = "character-sheet")]
+impl SettingsError {
+    pub fn to_string(&self, lang: LocaleLang) -> String {
+        match self {
+            SettingsError::UnknownSetting(n) => locale_text_fmt(lang, LocaleTag::UnknownSetting, &[n]),
+            SettingsError::InvalidValue(key, value) => {
+                locale_text_fmt(lang, LocaleTag::InvalidSettingValue, &[value, key])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "character-sheet")]
+    use rstest::rstest;
+
+    #[cfg(feature = "character-sheet")]
+    #[rstest]
+    #[case(SettingKey::ImproveDice, "1d6", true)]
+    #[case(SettingKey::ImproveDice, "-", true)]
+    #[case(SettingKey::ImproveDice, "six", false)]
+    #[case(SettingKey::ImproveDice, "1d0", false)]
+    #[case(SettingKey::ImproveDice, "0d6", false)]
+    #[case(SettingKey::ImproveDice, "1W10", true)]
+    #[case(SettingKey::MinAttributeValue, "30", true)]
+    #[case(SettingKey::MinAttributeValue, "100", false)]
+    #[case(SettingKey::MaxCharactersPerUser, "0", false)]
+    #[case(SettingKey::GmRoleName, "Keeper", true)]
+    #[case(SettingKey::GmRoleName, " ", false)]
+    #[case(SettingKey::LuckInCombat, "off", true)]
+    #[case(SettingKey::LuckInCombat, "maybe", false)]
+    #[case(SettingKey::Keeper, "<@!123456789>", true)]
+    #[case(SettingKey::Keeper, "-", true)]
+    #[case(SettingKey::Keeper, "@Keeper", false)]
+    fn set_setting(#[case] key: SettingKey, #[case] value: &str, #[case] valid: bool) {
+        assert_eq!(GuildSettings::default().set(key, value).is_ok(), valid);
+    }
+
+    #[cfg(feature = "character-sheet")]
+    #[test]
+    fn improve_dice_falls_back_to_ruleset() {
+        let mut settings = GuildSettings::default();
+        assert_eq!(settings.improve_dice(RulesetKind::Classic), "1d6");
+        assert_eq!(settings.improve_dice(RulesetKind::DeltaGreen), "1d4");
+        settings.set(SettingKey::ImproveDice, "1d10").ok();
+        assert_eq!(settings.improve_dice(RulesetKind::Classic), "1d10");
+        assert_eq!(settings.improve_dice(RulesetKind::DeltaGreen), "1d10");
+    }
+
+    #[test]
+    fn old_settings_get_defaults() {
+        let settings: GuildSettings = serde_json::from_str(r#"{"ruleset": "Pulp"}"#).unwrap();
+        assert_eq!(settings.ruleset, RulesetKind::Pulp);
+        assert_eq!(settings.min_attribute_value, 40);
+        assert!(settings.luck_in_combat);
+    }
+
+    #[cfg(feature = "inline-rolls")]
+    #[test]
+    fn inline_rolls_per_channel() {
+        let mut settings = GuildSettings::default();
+        settings.set_inline_rolls(1, true);
+        settings.set_inline_rolls(1, true);
+        settings.set_inline_rolls(2, true);
+        settings.set_inline_rolls(2, false);
+        assert_eq!(settings.inline_roll_channels, vec![1]);
+        assert!(settings.inline_rolls(1));
+        assert!(!settings.inline_rolls(2));
+    }
+}
@@ -0,0 +1,57 @@
+pub fn split_inclusive<'a>(input: &'a str, pattern: &[char]) -> Vec<&'a str> {
+    let mut slices = Vec::new();
+    let mut start = 0;
+    for (end, _) in input.match_indices(pattern) {
+        if start != end {
+            slices.push(&input[start..end]);
+        }
+        start = end;
+    }
+    slices.push(&input[start..]);
+    slices
+}
+
+#[cfg(feature = "character-sheet")]
+pub fn to_uppercase_first_letter(s: &str) -> String {
+    let mut c = s.chars();
+    match c.next() {
+        None => String::new(),
+        Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+    }
+}
+
+#[cfg(feature = "character-sheet")]
+pub fn parse_duration_hours(text: &str) -> Option<i32> {
+    let text = text.trim().to_lowercase();
+    let split = text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+    let (value, unit) = text.split_at(split);
+    let value: i32 = value.parse().ok()?;
+    let multiplier = match unit.trim() {
+        "" | "h" | "g" => 1,
+        "d" => 24,
+        "w" | "t" => 24 * 7,
+        _ => return None,
+    };
+    value.checked_mul(multiplier).filter(|hours| *hours > 0)
+}
+
+#[cfg(all(test, feature = "character-sheet"))]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("8", Some(8))]
+    #[case("8h", Some(8))]
+    #[case("2d", Some(48))]
+    #[case("3w", Some(504))]
+    #[case("3t", Some(504))]
+    #[case("w", None)]
+    #[case("3y", None)]
+    #[case("0d", None)]
+    #[case("-3d", None)]
+    #[case("99999999w", None)]
+    fn test_parse_duration_hours(#[case] text: &str, #[case] hours: Option<i32>) {
+        assert_eq!(parse_duration_hours(text), hours);
+    }
+}
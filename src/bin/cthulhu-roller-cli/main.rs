@@ -0,0 +1,48 @@
+mod repl;
+
+use cthulhu_roller_core::{bot_data::Data, types::Error};
+use repl::{LOCAL_USER_ID, Repl};
+use std::io::{BufRead, Write};
+
+/// Usage: `cthulhu-roller-cli [--user <discord user id>]`, run next to the bot's `db.json`.
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    let mut args = std::env::args().skip(1);
+    let user_id = match (args.next().as_deref(), args.next()) {
+        (Some("--user"), Some(user_id)) => user_id.parse()?,
+        (None, _) => LOCAL_USER_ID,
+        _ => return Err("Usage: cthulhu-roller-cli [--user <discord user id>]".into()),
+    };
+
+    let new_data = Data::default();
+    if !tokio::fs::try_exists(new_data.get_db_path()).await? {
+        new_data.save().await?;
+    }
+    let mut repl = Repl::new(Data::load().await, user_id);
+    println!("Type `help` for commands, `quit` to leave.");
+
+    let mut stdin = std::io::stdin().lock();
+    loop {
+        print!("🎲 ");
+        std::io::stdout().flush()?;
+        let mut line = String::new();
+        if stdin.read_line(&mut line)? == 0 {
+            break;
+        }
+        match line.trim() {
+            "" => continue,
+            "quit" | "exit" | "q" => break,
+            line => match repl.execute(line) {
+                Ok(contents) => {
+                    for mc in contents {
+                        println!("{}\n", mc.to_plain_text());
+                    }
+                    repl.data.save().await?;
+                }
+                Err(e) => eprintln!("🚫 {e}\n"),
+            },
+        }
+    }
+
+    Ok(())
+}
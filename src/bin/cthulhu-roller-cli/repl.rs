@@ -0,0 +1,367 @@
+use cthulhu_roller_core::{
+    bot_data::Data,
+    locale::LocaleLang,
+    message::{MessageContent, help::*},
+    roller::{
+        battle::{Battle, initiative_impl},
+        croll::croll_impl,
+        dice_rng::RealRng,
+        improve_roll::improve_skill,
+        roll::roll_query,
+        roll_log::RollRecord,
+    },
+    types::Error,
+};
+#[cfg(feature = "character-sheet")]
+use cthulhu_roller_core::{
+    character::{Character, CharacterDraft, hp_impl, luck_impl, marks_skill, san_impl},
+    locale::{LocaleTag, locale_text_by_tag_lang},
+    roller::{croll::CrollResult, roll::roll_attributes},
+};
+
+/// Owner of characters created in the terminal; `user <id>` switches to a Discord user from the bot's db.json.
+pub const LOCAL_USER_ID: u64 = 0;
+
+pub const COMMANDS_HELP: &str = r#"roll <dice>                  e.g. roll 1d8+1d4
+croll <threshold>            e.g. croll 45+
+levels <threshold>
+improve <threshold>
+initiative <name dex ...>    e.g. initiative Harvey 60 Cultist 45
+next | previous | battle
+language <language>
+user <discord user id>
+help [command]
+quit"#;
+
+#[cfg(feature = "character-sheet")]
+pub const CHARACTER_COMMANDS_HELP: &str = r#"characters
+create <name> [age]          random characteristics, e.g. create Harvey 32
+select <name>
+sheet | status
+skill <name> [bonus/penalty] e.g. skill Spot Hidden +
+hp <delta> | sanity [delta] | luck [delta]"#;
+
+/// Interactive session over the bot's data, as one user without Discord.
+pub struct Repl {
+    pub data: Data,
+    pub user_id: u64,
+}
+
+impl Repl {
+    pub fn new(data: Data, user_id: u64) -> Self {
+        Self { data, user_id }
+    }
+
+    fn lang(&self) -> LocaleLang {
+        self.data.users.get(&self.user_id).map(|u| u.lang).unwrap_or_default()
+    }
+
+    /// Runs one input line; the answers are the same messages the bot would send.
+    pub fn execute(&mut self, line: &str) -> Result<Vec<MessageContent>, Error> {
+        let line = line.trim();
+        let (command, args) = line
+            .split_once(char::is_whitespace)
+            .map_or((line, ""), |(command, args)| (command, args.trim()));
+        let command = command.to_lowercase();
+        let lang = self.lang();
+        let ruleset = self.data.ruleset(None);
+
+        let mc = match command.as_str() {
+            "roll" | "r" => {
+                let dice = self.data.resolve_roll(self.user_id, args)?;
+                MessageContent::from_dice_result(lang, roll_query(&mut RealRng::new(), &dice)?, false)
+            }
+            "croll" | "c" => {
+                let threshold = self.data.resolve_threshold(self.user_id, args)?;
+                let croll_result = croll_impl(&threshold, self.data.roll_ruleset(self.user_id, None))?;
+                self.data.record_roll(RollRecord::new(self.user_id, &croll_result));
+                MessageContent::from_croll_result(lang, &croll_result, false, false)
+            }
+            "levels" => MessageContent::from_levels(args.parse()?),
+            "improve" => MessageContent::from_improve(lang, &improve_skill(args.parse()?)),
+            "initiative" => {
+                let battle = Battle::new(initiative_impl(args, ruleset)?);
+                let mc = MessageContent::from_battle(lang, &battle, false, None);
+                self.data.battle = Some(battle);
+                mc
+            }
+            "next" | "previous" | "battle" => {
+                let battle = self.data.battle.as_mut().ok_or("No active battle")?;
+                match command.as_str() {
+                    "next" => battle.next_round(),
+                    "previous" => battle.previous_round(),
+                    _ => (),
+                }
+                MessageContent::from_battle(lang, battle, false, None)
+            }
+            "language" | "lang" => {
+                let user_data = self.data.users.entry(self.user_id).or_default();
+                user_data.lang = args.to_string().into();
+                MessageContent {
+                    title: format!("`{}`", user_data.lang),
+                    ..Default::default()
+                }
+            }
+            "user" => {
+                self.user_id = args.parse()?;
+                MessageContent {
+                    title: format!("➡️ `{}`", self.user_id),
+                    ..Default::default()
+                }
+            }
+            "help" => MessageContent {
+                description: help(args).to_string(),
+                ..Default::default()
+            },
+            #[cfg(feature = "character-sheet")]
+            _ => return self.execute_character(&command, args),
+            #[cfg(not(feature = "character-sheet"))]
+            _ => return Err(format!("Unknown command `{command}`, see `help`").into()),
+        };
+        Ok(vec![mc])
+    }
+
+    #[cfg(feature = "character-sheet")]
+    fn execute_character(&mut self, command: &str, args: &str) -> Result<Vec<MessageContent>, Error> {
+        let lang = self.lang();
+        let mc = match command {
+            "characters" => {
+                let characters = self
+                    .data
+                    .users
+                    .get(&self.user_id)
+                    .map(|user_data| user_data.characters.values().cloned().collect::<Vec<_>>())
+                    .unwrap_or_default();
+                MessageContent::from_characters_to_status(lang, characters)
+            }
+            "create" => self.create(args)?,
+            "select" => {
+                let user_data = self.data.users.entry(self.user_id).or_default();
+                let character = user_data.characters.get(args).ok_or(format!(
+                    "{}: `{}`",
+                    locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+                    args
+                ))?;
+                user_data.active_character = Some(character.name.clone());
+                MessageContent {
+                    title: format!("➡️ `{args}`"),
+                    ..Default::default()
+                }
+            }
+            "sheet" => return Ok(MessageContent::from_character_to_sheet(lang, self.character()?)),
+            "status" => {
+                let character = self.character()?;
+                MessageContent {
+                    title: format!("`{}`", character.name),
+                    description: character.status(),
+                    ..Default::default()
+                }
+            }
+            "skill" => self.skill(args)?,
+            "hp" => hp_impl(self.character()?, args, lang)?,
+            "sanity" | "san" if !args.is_empty() => {
+                let bout_kind = self.data.bout_kind();
+                san_impl(self.character()?, args, lang, bout_kind)?.0
+            }
+            "luck" if !args.is_empty() => luck_impl(self.character()?, args, lang)?,
+            "sanity" | "san" | "luck" => {
+                let character = self.character()?;
+                let (tag, value) = match command {
+                    "luck" => (LocaleTag::Luck, character.luck.current),
+                    _ => (LocaleTag::Sanity, character.sanity.current),
+                };
+                self.check(&locale_text_by_tag_lang(lang, tag), &value.to_string())?.0
+            }
+            _ => return Err(format!("Unknown command `{command}`, see `help`").into()),
+        };
+        Ok(vec![mc])
+    }
+
+    #[cfg(feature = "character-sheet")]
+    fn character(&mut self) -> Result<&mut Character, Error> {
+        let user_data = self.data.users.entry(self.user_id).or_default();
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
+        Ok(user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?)
+    }
+
+    /// `<name> [age]`, the name may contain spaces.
+    #[cfg(feature = "character-sheet")]
+    fn create(&mut self, args: &str) -> Result<MessageContent, Error> {
+        let (name, age) = match args.rsplit_once(' ') {
+            Some((name, age)) if age.parse::<i32>().is_ok() => (name.trim(), age.parse()?),
+            _ => (args, 25),
+        };
+        if name.is_empty() {
+            return Err("create <name> [age]".into());
+        }
+        let lang = self.lang();
+        let max = self.data.max_characters_per_user;
+        let ruleset = self.data.ruleset(None);
+        let user_data = self.data.users.entry(self.user_id).or_default();
+        if user_data.characters.len() >= max {
+            return Err(locale_text_by_tag_lang(lang, LocaleTag::SorryTooManyCharacters).into());
+        }
+
+        let mut rng = RealRng::new();
+        let mut draft = CharacterDraft {
+            name: Some(name.to_string()),
+            ..Default::default()
+        };
+        draft.roll(&mut rng, &roll_attributes(None));
+        draft.set_age(&mut rng, age).map_err(|e| e.to_string(lang))?;
+        let character = draft.build(lang, ruleset)?;
+        user_data.characters.insert(name.to_string(), character);
+        user_data.active_character = Some(name.to_string());
+
+        Ok(MessageContent {
+            title: format!("✅ `{name}`"),
+            description: draft.summary(lang),
+            ..Default::default()
+        })
+    }
+
+    /// `<skill name> [bonus/penalty dice]`; successes mark the skill for improvement like on Discord.
+    #[cfg(feature = "character-sheet")]
+    fn skill(&mut self, args: &str) -> Result<MessageContent, Error> {
+        let name = args.trim_end_matches(['+', '-', ' ']);
+        let modifier_dice = &args[name.len()..].replace(' ', "");
+        let lang = self.lang();
+        let character = self.character()?;
+        let skill = character
+            .get_skill(name)
+            .ok_or_else(|| format!("{}: {}", locale_text_by_tag_lang(lang, LocaleTag::NoSuchSkill), name))?;
+        let pending_bonus_dice =
+            "+".repeat((character.bonus_dice.max(0) + character.talent_bonus_dice(&skill.name)) as usize);
+        let croll_query = format!("{}{modifier_dice}{pending_bonus_dice}", skill.value);
+        let (mut mc, croll_result) = self.check(&skill.name.get(lang), &croll_query)?;
+        // Bonus dice from items are spent only by a roll that happened.
+        self.character()?.bonus_dice = 0;
+
+        if skill.improvable
+            && !skill.to_improve
+            && marks_skill(&croll_result)
+            && let Some(skill) = self.character()?.get_mut_skill(name)
+        {
+            skill.to_improve = true;
+            mc.description = format!(
+                "{}\n{}",
+                mc.description,
+                locale_text_by_tag_lang(lang, LocaleTag::SkillMarked)
+            );
+        }
+        Ok(mc)
+    }
+
+    /// Percentile check of the active character, kept in the roll history.
+    #[cfg(feature = "character-sheet")]
+    fn check(&mut self, name: &str, croll_query: &str) -> Result<(MessageContent, CrollResult), Error> {
+        let lang = self.lang();
+        let user_id = self.user_id;
+        let character = self.character()?;
+        let character_name = character.name.clone();
+        let croll_result = croll_impl(croll_query, character.ruleset())?;
+        self.data.record_roll(
+            RollRecord::new(user_id, &croll_result)
+                .with_character(&character_name)
+                .with_skill(name),
+        );
+        let mc = MessageContent::from_croll_result(lang, &croll_result, false, true)
+            .with_skill_name(name)
+            .with_character_name(&character_name);
+        Ok((mc, croll_result))
+    }
+}
+
+fn help(command: &str) -> String {
+    match command {
+        "roll" | "r" => ROLL_HELP.to_string(),
+        "croll" | "c" => CROLL_HELP.to_string(),
+        "levels" => LEVELS_HELP.to_string(),
+        "improve" => IMPROVE_HELP.to_string(),
+        "initiative" => INITIATIVE_HELP.to_string(),
+        "language" | "lang" => LANGUAGE_HELP.to_string(),
+        #[cfg(feature = "character-sheet")]
+        _ => format!("{COMMANDS_HELP}\n\n{CHARACTER_COMMANDS_HELP}"),
+        #[cfg(not(feature = "character-sheet"))]
+        _ => COMMANDS_HELP.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn repl() -> Repl {
+        Repl::new(Data::default(), LOCAL_USER_ID)
+    }
+
+    #[rstest]
+    #[case("roll 2d6+1")]
+    #[case("r d4")]
+    #[case("croll 45+")]
+    #[case("levels 60")]
+    #[case("improve 40")]
+    #[case("help")]
+    #[case("help croll")]
+    fn basic_commands(#[case] line: &str) {
+        let contents = repl().execute(line).ok().unwrap();
+        assert!(!contents[0].to_plain_text().is_empty());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("unknown 1")]
+    #[case("levels sixty")]
+    #[case("initiative Harvey")]
+    #[case("next")]
+    fn invalid_commands(#[case] line: &str) {
+        assert!(repl().execute(line).is_err());
+    }
+
+    #[test]
+    fn croll_is_recorded() {
+        let mut repl = repl();
+        repl.execute("croll 50").ok().unwrap();
+        assert_eq!(repl.data.rolls.len(), 1);
+        assert_eq!(repl.data.rolls[0].user_id, LOCAL_USER_ID);
+    }
+
+    #[test]
+    fn battle_rounds() {
+        let mut repl = repl();
+        repl.execute("initiative Harvey 60 Cultist 45").ok().unwrap();
+        let first = repl.data.battle.as_ref().unwrap().current_position;
+        repl.execute("next").ok().unwrap();
+        assert_ne!(repl.data.battle.as_ref().unwrap().current_position, first);
+    }
+
+    #[cfg(feature = "character-sheet")]
+    #[test]
+    fn character_commands() {
+        let mut repl = repl();
+        assert!(repl.execute("sheet").is_err());
+
+        repl.execute("create Harvey Walters 40").ok().unwrap();
+        let user_data = &repl.data.users[&LOCAL_USER_ID];
+        assert_eq!(user_data.active_character.as_deref(), Some("Harvey Walters"));
+
+        assert!(repl.execute("sheet").ok().unwrap().len() > 1);
+        repl.execute("hp -1").ok().unwrap();
+        let character = &repl.data.users[&LOCAL_USER_ID].characters["Harvey Walters"];
+        assert_eq!(character.hp.current, character.hp.max - 1);
+
+        repl.execute("skill Spot Hidden +").ok().unwrap();
+        repl.execute("luck").ok().unwrap();
+        assert_eq!(repl.data.rolls.len(), 2);
+        assert_eq!(repl.data.rolls[0].skill.as_deref(), Some("Spot Hidden"));
+        assert!(repl.execute("skill Necromancy").is_err());
+    }
+}
@@ -1,9 +1,9 @@
-use crate::bot_data::ContextData;
 use crate::commands::basic::{about_cmd, add_to_fight_cmd, remove_from_fight_cmd, roll_cmd};
 #[cfg(feature = "character-sheet")]
 use crate::commands::character::fight::fight_cmd;
 #[cfg(feature = "character-sheet")]
 use crate::commands::character::stats::improve_luck_cmd;
+use crate::commands::help::help;
 #[cfg(feature = "character-sheet")]
 use crate::commands::{
     character::sheet_cmd,
@@ -20,26 +20,51 @@ use crate::commands::{
 #[cfg(feature = "character-sheet")]
 use crate::commands::{
     character::{fight::damage_cmd, weapon::weapon_cmd},
-    gm::{characters::gmcharacter_cmd, item::gmitem_cmd, weapon::gmweapon_cmd},
+    gm::{
+        attribute::gmattribute_cmd,
+        characters::gmcharacter_cmd,
+        dashboard::dashboard_cmd,
+        item::gmitem_cmd,
+        roll::{gmroll_cmd, wcroll_cmd, wroll_cmd},
+        ruleset::ruleset_cmd,
+        session::session_cmd,
+        settings::settings_cmd,
+        skill::gmskill_cmd,
+        time::time_cmd,
+        weapon::gmweapon_cmd,
+    },
+};
+#[cfg(feature = "character-sheet")]
+use crate::message::help::{
+    CHARACTER_HELP, DASHBOARD_HELP, GM_EDIT_HELP, PULP_HELP, RULESET_HELP, SESSION_HELP, SETTINGS_HELP, SHEET_HELP,
+    STATS_HELP, TIME_HELP, TOME_HELP, WEAPON_HELP, WHISPER_HELP,
 };
-use crate::help;
+use crate::types::ContextData;
 use crate::types::Error;
+#[cfg(feature = "inline-rolls")]
+use crate::{commands::inline_roll::inline_rolls_cmd, message::help::INLINE_ROLLS_HELP};
 use poise::Command;
 
 #[cfg(feature = "character-sheet")]
 use crate::commands::character::{
+    heal::heal_cmd,
+    insanity::insanity_cmd,
     item::item_cmd,
+    pulp::pulp_cmd,
+    roll_stats::roll_stats_cmd,
     skills::{dodge_cmd, listen_cmd, maneuver_cmd, spot_hidden_cmd},
     sleep_cmd,
+    spell::spell_cmd,
     stats::{hp_cmd, luck_cmd, mp_cmd, sanity_cmd},
     status_cmd,
+    tome::tome_cmd,
 };
 use crate::{
     commands::basic::{
         croll_cmd, end_battle_cmd, hcroll_cmd, hroll_cmd, improve_test_cmd, initiative_cmd, language_cmd, levels_cmd,
         next_round_cmd, previous_round_cmd, roll_attributes_cmd,
     },
-    message::help::{CROLL_HELP, IMPROVE_HELP, INITIATIVE_HELP, LEVELS_HELP, ROLL_HELP},
+    message::help::{CROLL_HELP, IMPROVE_HELP, INITIATIVE_HELP, LANGUAGE_HELP, LEVELS_HELP, ROLL_HELP},
 };
 
 #[allow(dead_code)]
@@ -83,8 +108,8 @@ pub fn command_list() -> Vec<Command<ContextData, Error>> {
         cmd_with_meta(
             language_cmd(),
             CommandCategory::Basic,
-            "Set messages language; Available: `english`, `polski`",
-            "",
+            "Set messages language; Available: `english`, `polski`, `deutsch`, `español`",
+            LANGUAGE_HELP,
         ),
         cmd_with_meta(
             croll_cmd(),
@@ -158,14 +183,14 @@ pub fn command_list() -> Vec<Command<ContextData, Error>> {
     ];
 
     #[cfg(feature = "character-sheet")]
-    let commands_vec = commands_vec
+    let commands_vec: Vec<_> = commands_vec
         .into_iter()
         .chain(vec![
             cmd_with_meta(
                 character_cmd(),
                 CommandCategory::Character,
                 "Manage character entries",
-                "",
+                CHARACTER_HELP,
             ),
             cmd_with_meta(
                 attribute_cmd(),
@@ -174,10 +199,21 @@ pub fn command_list() -> Vec<Command<ContextData, Error>> {
                 "",
             ),
             cmd_with_meta(skill_cmd(), CommandCategory::Character, "Skill check or edit", ""),
-            cmd_with_meta(weapon_cmd(), CommandCategory::Character, "Character's weapons", ""),
+            cmd_with_meta(
+                weapon_cmd(),
+                CommandCategory::Character,
+                "Character's weapons",
+                WEAPON_HELP,
+            ),
             cmd_with_meta(item_cmd(), CommandCategory::Character, "Character's items", ""),
+            cmd_with_meta(
+                tome_cmd(),
+                CommandCategory::Character,
+                "Character's Mythos tomes and reading progress",
+                TOME_HELP,
+            ),
             cmd_with_meta(status_cmd(), CommandCategory::Character, "Character's status", ""),
-            cmd_with_meta(sheet_cmd(), CommandCategory::Character, "Character's sheet", ""),
+            cmd_with_meta(sheet_cmd(), CommandCategory::Character, "Character's sheet", SHEET_HELP),
             cmd_with_meta(
                 hp_cmd(),
                 CommandCategory::Character,
@@ -190,6 +226,24 @@ pub fn command_list() -> Vec<Command<ContextData, Error>> {
                 "Roll Sanity check or modify Sanity Points",
                 "",
             ),
+            cmd_with_meta(
+                heal_cmd(),
+                CommandCategory::Character,
+                "First Aid, Medicine and weekly recovery from Major Wound",
+                "",
+            ),
+            cmd_with_meta(
+                spell_cmd(),
+                CommandCategory::Character,
+                "Character's spells: learn and cast",
+                "",
+            ),
+            cmd_with_meta(
+                insanity_cmd(),
+                CommandCategory::Character,
+                "Character's insanity episodes and bouts of madness",
+                "",
+            ),
             cmd_with_meta(
                 luck_cmd(),
                 CommandCategory::Character,
@@ -197,6 +251,18 @@ pub fn command_list() -> Vec<Command<ContextData, Error>> {
                 "",
             ),
             cmd_with_meta(improve_luck_cmd(), CommandCategory::Character, "Roll Luck improve", ""),
+            cmd_with_meta(
+                roll_stats_cmd(),
+                CommandCategory::Character,
+                "Roll statistics and dice fairness for a character, player or party",
+                STATS_HELP,
+            ),
+            cmd_with_meta(
+                pulp_cmd(),
+                CommandCategory::Character,
+                "Pulp hero actions: archetype skill points, shrug off damage, avoid death",
+                PULP_HELP,
+            ),
             cmd_with_meta(
                 spot_hidden_cmd(),
                 CommandCategory::Character,
@@ -232,9 +298,27 @@ pub fn command_list() -> Vec<Command<ContextData, Error>> {
             cmd_with_meta(
                 sleep_cmd(),
                 CommandCategory::Character,
-                "Update initial Sanity level (for tracking daily Sanity loss)",
+                "End the day: update initial Sanity level, heal naturally and reset daily healing limits",
                 "",
             ),
+            cmd_with_meta(
+                wcroll_cmd(),
+                CommandCategory::Basic,
+                "Same as `/croll` but the result is sent only to the Keeper",
+                WHISPER_HELP,
+            ),
+            cmd_with_meta(
+                wroll_cmd(),
+                CommandCategory::Basic,
+                "Same as `/roll` but the result is sent only to the Keeper",
+                WHISPER_HELP,
+            ),
+            cmd_with_meta(
+                gmroll_cmd(),
+                CommandCategory::GM,
+                "Secret skill check of a player's character",
+                WHISPER_HELP,
+            ),
             cmd_with_meta(
                 gmstatus_cmd(),
                 CommandCategory::GM,
@@ -287,13 +371,13 @@ pub fn command_list() -> Vec<Command<ContextData, Error>> {
             cmd_with_meta(
                 gminsane_cmd(),
                 CommandCategory::GM,
-                "Add Fragile Mind to one of active characters",
+                "Start temporary or indefinite insanity of one of active characters",
                 "",
             ),
             cmd_with_meta(
                 gmsane_cmd(),
                 CommandCategory::GM,
-                "Remove Fragile Mind from one of active characters",
+                "End insanity episodes of one of active characters",
                 "",
             ),
             cmd_with_meta(
@@ -308,6 +392,48 @@ pub fn command_list() -> Vec<Command<ContextData, Error>> {
                 "GM API for active character items",
                 "",
             ),
+            cmd_with_meta(
+                gmskill_cmd(),
+                CommandCategory::GM,
+                "GM API for character skills",
+                GM_EDIT_HELP,
+            ),
+            cmd_with_meta(
+                gmattribute_cmd(),
+                CommandCategory::GM,
+                "GM API for characteristics, Luck, MP and occupation",
+                GM_EDIT_HELP,
+            ),
+            cmd_with_meta(
+                time_cmd(),
+                CommandCategory::GM,
+                "In-game calendar: show, set or advance time",
+                TIME_HELP,
+            ),
+            cmd_with_meta(
+                ruleset_cmd(),
+                CommandCategory::GM,
+                "Show or set server ruleset: Classic, Pulp, Delta Green, Achtung!",
+                RULESET_HELP,
+            ),
+            cmd_with_meta(
+                settings_cmd(),
+                CommandCategory::GM,
+                "Show or change server house rules",
+                SETTINGS_HELP,
+            ),
+            cmd_with_meta(
+                session_cmd(),
+                CommandCategory::GM,
+                "Start or end a game session and post its recap",
+                SESSION_HELP,
+            ),
+            cmd_with_meta(
+                dashboard_cmd(),
+                CommandCategory::GM,
+                "Pinned party status that updates itself",
+                DASHBOARD_HELP,
+            ),
             cmd_with_meta(gmdatabase_cmd(), CommandCategory::GM, "Download/Upload database", ""),
             cmd_with_meta(
                 gmquicksave_cmd(),
@@ -323,5 +449,16 @@ pub fn command_list() -> Vec<Command<ContextData, Error>> {
             ),
         ])
         .collect();
+
+    #[cfg(feature = "inline-rolls")]
+    let commands_vec = commands_vec
+        .into_iter()
+        .chain(vec![cmd_with_meta(
+            inline_rolls_cmd(),
+            CommandCategory::GM,
+            "Turn `[[1d6]]` rolls in chat messages on or off in this channel",
+            INLINE_ROLLS_HELP,
+        )])
+        .collect();
     commands_vec
 }
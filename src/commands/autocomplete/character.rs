@@ -2,7 +2,7 @@ use itertools::Itertools;
 use poise::serenity_prelude::ResolvedValue;
 
 use crate::bot_data::UserData;
-use crate::character::{ADDITIONAL_SKILLS, SPECIALIZED_SKILLS, skill_map_wrapper};
+use crate::character::{ADDITIONAL_SKILLS, SPECIALIZED_SKILLS, STANDARD_AMMO_TYPE, WEAPON_CATALOG, skill_map_wrapper};
 use crate::locale::{LOCALE_PULP_ARCHETYPES, LOCALE_PULP_TALENTS, locale_text_by_tag_lang};
 use crate::types::{ApplicationContext, AttributeMap, Context, SkillMap};
 
@@ -209,11 +209,11 @@ pub async fn autocomplete_my_fight_skills<'a>(ctx: Context<'a>, partial: &'a str
         .iter()
         .filter(|(_, v)| {
             v.name
-                .en
+                .en()
                 .to_lowercase()
                 .contains(&"Fighting".to_string().to_lowercase())
                 || v.name
-                    .en
+                    .en()
                     .to_lowercase()
                     .contains(&"Firearms".to_string().to_lowercase())
         })
@@ -342,6 +342,74 @@ pub async fn autocomplete_character_items<'a>(ctx: ApplicationContext<'a>, parti
     vec![]
 }
 
+/// Skills of the character chosen in the `character_name` argument, in the language of the caller.
+pub async fn autocomplete_character_skills<'a>(ctx: ApplicationContext<'a>, partial: &'a str) -> Vec<String> {
+    let character_name = if let Some(ResolvedValue::String(s)) = ctx
+        .args
+        .iter()
+        .find(|a| a.name.eq_ignore_ascii_case("character_name"))
+        .map(|a| &a.value)
+    {
+        *s
+    } else {
+        ""
+    };
+
+    let data = ctx.data().data.read().await;
+    let lang = data
+        .users
+        .get(&ctx.author().id.get())
+        .map(|user_data| user_data.lang)
+        .unwrap_or_default();
+    for user_data in data.users.values() {
+        if let Some(character) = user_data.characters.get(character_name) {
+            return character
+                .skills
+                .values()
+                .map(|skill| skill.name.get(lang))
+                .sorted()
+                .dedup()
+                .filter(|name| name.to_lowercase().contains(&partial.to_lowercase()))
+                .take(25)
+                .collect();
+        }
+    }
+
+    vec![]
+}
+
+pub async fn autocomplete_character_attributes<'a>(ctx: ApplicationContext<'a>, partial: &'a str) -> Vec<String> {
+    let character_name = if let Some(ResolvedValue::String(s)) = ctx
+        .args
+        .iter()
+        .find(|a| a.name.eq_ignore_ascii_case("character_name"))
+        .map(|a| &a.value)
+    {
+        *s
+    } else {
+        ""
+    };
+
+    let data = ctx.data().data.read().await;
+    let lang = data
+        .users
+        .get(&ctx.author().id.get())
+        .map(|user_data| user_data.lang)
+        .unwrap_or_default();
+    for user_data in data.users.values() {
+        if let Some(character) = user_data.characters.get(character_name) {
+            return AttributeMap::from(character.attributes.clone())
+                .values()
+                .map(|attribute| attribute.name.get(lang))
+                .sorted()
+                .filter(|name| name.to_lowercase().contains(&partial.to_lowercase()))
+                .collect();
+        }
+    }
+
+    vec![]
+}
+
 pub async fn autocomplete_character_weapons<'a>(ctx: ApplicationContext<'a>, partial: &'a str) -> Vec<String> {
     let character_name = if let ResolvedValue::String(s) = ctx
         .args
@@ -399,6 +467,61 @@ pub async fn autocomplete_my_weapons<'a>(ctx: Context<'a>, partial: &'a str) ->
     weapons
 }
 
+pub async fn autocomplete_my_tomes<'a>(ctx: Context<'a>, partial: &'a str) -> Vec<String> {
+    let data = ctx.data().data.read().await;
+    let user_id = ctx.author().id.get();
+
+    let Some(user_data) = data.users.get(&user_id) else {
+        return vec![];
+    };
+    let active = user_data.active_character.clone().unwrap_or_default();
+    let Some(character) = user_data.characters.get(&active) else {
+        return vec![];
+    };
+
+    let mut tomes: Vec<_> = character
+        .tomes
+        .iter()
+        .map(|tome| tome.name.clone())
+        .filter(|name| name.to_lowercase().contains(&partial.to_lowercase()))
+        .collect();
+    tomes.sort();
+    tomes
+}
+
+pub async fn autocomplete_weapon_catalog<'a>(ctx: Context<'a>, partial: &'a str) -> Vec<String> {
+    let era = ctx.data().data.read().await.calendar.era;
+
+    WEAPON_CATALOG
+        .iter()
+        .filter(|weapon| weapon.is_available_in(era))
+        .map(|weapon| weapon.name.clone())
+        .filter(|name| name.to_lowercase().contains(&partial.to_lowercase()))
+        .take(25)
+        .collect()
+}
+
+pub async fn autocomplete_my_spells<'a>(ctx: Context<'a>, partial: &'a str) -> Vec<String> {
+    let data = ctx.data().data.read().await;
+    let user_id = ctx.author().id.get();
+
+    let Some(user_data) = data.users.get(&user_id) else {
+        return vec![];
+    };
+    let active = user_data.active_character.clone().unwrap_or_default();
+    let Some(character) = user_data.characters.get(&active) else {
+        return vec![];
+    };
+
+    character
+        .spells
+        .iter()
+        .filter(|name| name.to_lowercase().contains(&partial.to_lowercase()))
+        .sorted()
+        .cloned()
+        .collect()
+}
+
 pub async fn autocomplete_my_weapon_attacks<'a>(ctx: ApplicationContext<'a>, _: &'a str) -> Vec<String> {
     let user_id = ctx.author().id.get();
     let mut data = ctx.data().data.write().await;
@@ -440,6 +563,39 @@ pub async fn autocomplete_my_weapon_attacks<'a>(ctx: ApplicationContext<'a>, _:
         .collect()
 }
 
+pub async fn autocomplete_my_weapon_ammo_types<'a>(ctx: ApplicationContext<'a>, partial: &'a str) -> Vec<String> {
+    let user_id = ctx.author().id.get();
+    let data = ctx.data().data.read().await;
+    let Some(user_data) = data.users.get(&user_id) else {
+        return vec![];
+    };
+    let active = user_data.active_character.clone().unwrap_or_default();
+    let Some(character) = user_data.characters.get(&active) else {
+        return vec![];
+    };
+
+    let weapon_name = match ctx.args.iter().find(|a| a.name.eq_ignore_ascii_case("weapon_name")) {
+        Some(arg) => match arg.value {
+            ResolvedValue::String(s) => s,
+            _ => "",
+        },
+        None => "",
+    };
+
+    character
+        .weapons
+        .iter()
+        .find(|weapon| weapon.name.to_ascii_lowercase().contains(&weapon_name.to_lowercase()))
+        .and_then(|weapon| weapon.ammo.as_ref())
+        .map(|ammo| {
+            std::iter::once(STANDARD_AMMO_TYPE.to_string())
+                .chain(ammo.types.iter().map(|t| t.name.clone()))
+                .filter(|name| name.to_lowercase().contains(&partial.to_lowercase()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 pub async fn autocomplete_any_character<'a>(ctx: Context<'a>, partial: &'a str) -> Vec<String> {
     let data = ctx.data().data.read().await.clone();
     let mut characters: Vec<_> = data
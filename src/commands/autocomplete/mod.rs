@@ -4,7 +4,11 @@ pub mod character;
 pub use character::*;
 use itertools::Itertools;
 
-use crate::{bot_data::UserData, locale::LOCALE_ATTRIBUTES, types::Context};
+use crate::{
+    bot_data::UserData,
+    locale::{LOCALE_ATTRIBUTES, LocaleLang},
+    types::Context,
+};
 
 pub async fn autocomplete_help<'a>(ctx: Context<'a>, partial: &'a str) -> Vec<String> {
     ctx.framework()
@@ -16,6 +20,13 @@ pub async fn autocomplete_help<'a>(ctx: Context<'a>, partial: &'a str) -> Vec<St
         .collect()
 }
 
+pub async fn autocomplete_language<'a>(_ctx: Context<'a>, partial: &'a str) -> Vec<String> {
+    LocaleLang::all()
+        .map(|lang| lang.to_string())
+        .filter(|name| name.to_lowercase().contains(&partial.to_lowercase()))
+        .collect()
+}
+
 pub async fn autocomplete_attributes<'a>(ctx: Context<'a>, partial: &'a str) -> Vec<String> {
     let user_id = ctx.author().id.get();
     let mut data = ctx.data().data.write().await;
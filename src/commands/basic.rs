@@ -1,24 +1,31 @@
-use crate::roller::croll::CrollResult;
 use crate::roller::improve_roll::improve_skill;
 use crate::{
     bot_data::*,
-    commands::autocomplete::{autocomplete_attributes, autocomplete_battle},
+    commands::{
+        autocomplete::{autocomplete_attributes, autocomplete_battle, autocomplete_language},
+        battle_tracker::{next_turn, show_tracker, tracker_updated},
+    },
     locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
-    message::MessageContent,
+    message::{MessageContent, ToEmbed},
     roller::{
-        battle::{Battle, CharacterInitiative},
-        croll::croll,
+        battle::{Battle, initiative_impl},
+        croll::croll_impl,
         dice_rng::RealRng,
         roll::{roll_attributes, roll_query},
+        roll_log::RollRecord,
     },
     types::*,
 };
 use itertools::Itertools;
 use poise::CreateReply;
-use regex::Regex;
 
 #[poise::command(prefix_command, slash_command, rename = "language", aliases("język"))]
-pub async fn language_cmd(ctx: Context<'_>, #[name_localized("pl", "język")] language: String) -> Result<(), Error> {
+pub async fn language_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_language"]
+    #[name_localized("pl", "język")]
+    language: String,
+) -> Result<(), Error> {
     let message_content;
     {
         let user_id = ctx.author().id.get();
@@ -46,46 +53,30 @@ pub async fn language_cmd(ctx: Context<'_>, #[name_localized("pl", "język")] la
     ctx.data().data.write().await.save().await
 }
 
-pub fn croll_impl(query: &str) -> Result<CrollResult, Error> {
-    let pattern = r"^(\d+)([\+-]*)$";
-    let re = Regex::new(pattern)?;
-    let q = query.replace(' ', "");
-    let captures = re.captures(&q).ok_or(format!("Invalid query: \"{q}\""))?;
-    let threshold = captures.get(1).ok_or("Invalid threshold:")?.as_str().parse()?;
-    let mut penalty = 0;
-    let mut bonus = 0;
-    match captures.get(2) {
-        None => (),
-        Some(captures_match) => {
-            let penalty_bonus_str = captures_match.as_str();
-            penalty = penalty_bonus_str.chars().filter(|c| *c == '-').count() as i32;
-            bonus = penalty_bonus_str.chars().filter(|c| *c == '+').count() as i32;
-        }
-    }
-    croll(query, threshold, penalty, bonus)
-}
-
 #[poise::command(prefix_command, slash_command, rename = "croll")]
 pub async fn croll_cmd(ctx: Context<'_>, #[name_localized("pl", "próg")] threshold: String) -> Result<(), Error> {
     let message_content;
 
     {
         let user_id = ctx.author().id.get();
-        let data = ctx.data().data.read().await;
-        let user_data = data.users.get(&user_id);
-        let user_lang = match user_data {
-            Some(ud) => ud.lang,
-            None => LocaleLang::default(),
-        };
+        let mut data = ctx.data().data.write().await;
+        let user_lang = data.users.get(&user_id).map(|ud| ud.lang).unwrap_or_default();
+
+        let threshold = data.resolve_threshold(user_id, &threshold)?;
+        let croll_result = croll_impl(
+            &threshold,
+            data.roll_ruleset(user_id, ctx.guild_id().map(|guild_id| guild_id.get())),
+        )?;
 
-        let croll_result = croll_impl(&threshold)?;
+        data.record_roll(RollRecord::new(user_id, &croll_result));
 
         message_content = MessageContent::from_croll_result(user_lang, &croll_result, false, false);
     }
 
     ctx.send(CreateReply::default().embed(message_content.to_embed()))
         .await?;
-    Ok(())
+
+    ctx.data().data.write().await.save().await
 }
 
 #[poise::command(prefix_command, slash_command, rename = "hcroll")]
@@ -94,21 +85,24 @@ pub async fn hcroll_cmd(ctx: Context<'_>, #[name_localized("pl", "próg")] thres
 
     {
         let user_id = ctx.author().id.get();
-        let data = ctx.data().data.read().await;
-        let user_data = data.users.get(&user_id);
-        let user_lang = match user_data {
-            Some(ud) => ud.lang,
-            None => LocaleLang::default(),
-        };
+        let mut data = ctx.data().data.write().await;
+        let user_lang = data.users.get(&user_id).map(|ud| ud.lang).unwrap_or_default();
+
+        let threshold = data.resolve_threshold(user_id, &threshold)?;
+        let croll_result = croll_impl(
+            &threshold,
+            data.roll_ruleset(user_id, ctx.guild_id().map(|guild_id| guild_id.get())),
+        )?;
 
-        let croll_result = croll_impl(&threshold)?;
+        data.record_roll(RollRecord::new(user_id, &croll_result).hidden());
 
         message_content = MessageContent::from_croll_result(user_lang, &croll_result, true, true);
     }
 
     ctx.send(CreateReply::default().embed(message_content.to_embed()))
         .await?;
-    Ok(())
+
+    ctx.data().data.write().await.save().await
 }
 
 #[poise::command(prefix_command, slash_command, rename = "improve_test", aliases("test_rozwoju"))]
@@ -149,6 +143,7 @@ pub async fn roll_cmd(ctx: Context<'_>, #[name_localized("pl", "kości")] dice:
             None => LocaleLang::default(),
         };
 
+        let dice = data.resolve_roll(user_id, &dice)?;
         let roll_result;
         {
             let mut rng = RealRng::new();
@@ -176,6 +171,7 @@ pub async fn hroll_cmd(ctx: Context<'_>, #[name_localized("pl", "kości")] dice:
             None => LocaleLang::default(),
         };
 
+        let dice = data.resolve_roll(user_id, &dice)?;
         let roll_result;
         {
             let mut rng = RealRng::new();
@@ -210,7 +206,6 @@ pub async fn initiative_cmd(ctx: Context<'_>, #[name_localized("pl", "lista")] l
     }
 
     let battle;
-    let message_content_a;
     let message_content_b;
     {
         let user_id = ctx.author().id.get();
@@ -221,35 +216,26 @@ pub async fn initiative_cmd(ctx: Context<'_>, #[name_localized("pl", "lista")] l
             None => LocaleLang::default(),
         };
 
-        let mut characters: Vec<CharacterInitiative> = vec![];
-        for pair in words.chunks(2) {
-            let name = pair[0];
-            let threshold = pair[1];
-            let skill_result = croll_impl(threshold)?;
-            if characters
-                .iter()
-                .map(|el| &el.name)
-                .any(|el| el.eq_ignore_ascii_case(name))
-            {
-                return Err(format!("Non-unique character list: `{}`", list).into());
-            }
-            characters.push(CharacterInitiative {
-                croll_result: skill_result,
-                name: name.to_string(),
-            });
-        }
-        battle = Battle::new(characters);
+        let ruleset = data.ruleset(ctx.guild_id().map(|guild_id| guild_id.get()));
+        battle = Battle::new(initiative_impl(&list, ruleset)?);
 
-        message_content_a = MessageContent::from_battle(user_lang, &battle, true, None);
         message_content_b = MessageContent::from_battle(user_lang, &battle, false, None);
     }
+    let tracker;
+    {
+        let mut data = ctx.data().data.write().await;
+        // A new battle replaces the old one, so its tracker is closed.
+        tracker = data.battle.take().and_then(|battle| battle.tracker);
+    }
+    if tracker.is_some() {
+        show_tracker(ctx, tracker).await?;
+    }
     {
         let mut data = ctx.data().data.write().await;
         data.battle = Some(battle);
     }
 
-    ctx.send(CreateReply::default().embed(message_content_a.to_embed()))
-        .await?;
+    show_tracker(ctx, None).await?;
     ctx.send(
         CreateReply::default()
             .embed(message_content_b.to_embed())
@@ -261,30 +247,18 @@ pub async fn initiative_cmd(ctx: Context<'_>, #[name_localized("pl", "lista")] l
 
 #[poise::command(prefix_command, slash_command, rename = "next_round", aliases("następna_runda"))]
 pub async fn next_round_cmd(ctx: Context<'_>) -> Result<(), Error> {
-    let mut message_content = None;
-
+    let tracker;
     {
-        let user_id = ctx.author().id.get();
         let mut data = ctx.data().data.write().await;
-        let user_data = data.users.get(&user_id);
-        let user_lang = match user_data {
-            Some(ud) => ud.lang,
-            None => LocaleLang::default(),
-        };
-
-        if let Some(battle) = &mut data.battle {
-            battle.next_round();
-            message_content = Some(MessageContent::from_battle(user_lang, battle, true, None));
-        }
+        data.battle.as_ref().ok_or("No active battle")?;
+        next_turn(&mut data);
+        tracker = data.battle.as_ref().and_then(|battle| battle.tracker);
     }
 
-    if let Some(message) = message_content {
-        ctx.send(CreateReply::default().embed(message.to_embed())).await?;
-        ctx.data().data.write().await.save().await?;
-        return Ok(());
+    if let Some(link) = show_tracker(ctx, tracker).await? {
+        tracker_updated(ctx, link, None).await?;
     }
-
-    Err("No active battle".into())
+    ctx.data().data.write().await.save().await
 }
 
 #[poise::command(
@@ -294,59 +268,31 @@ pub async fn next_round_cmd(ctx: Context<'_>) -> Result<(), Error> {
     aliases("poprzednia_runda")
 )]
 pub async fn previous_round_cmd(ctx: Context<'_>) -> Result<(), Error> {
-    let mut message_content = None;
-
+    let tracker;
     {
-        let user_id = ctx.author().id.get();
         let mut data = ctx.data().data.write().await;
-        let user_data = data.users.get(&user_id);
-        let user_lang = match user_data {
-            Some(ud) => ud.lang,
-            None => LocaleLang::default(),
-        };
-
-        if let Some(battle) = &mut data.battle {
-            battle.previous_round();
-            message_content = Some(MessageContent::from_battle(user_lang, battle, true, None));
-        }
+        let battle = data.battle.as_mut().ok_or("No active battle")?;
+        battle.previous_round();
+        tracker = battle.tracker;
     }
 
-    if let Some(message) = message_content {
-        ctx.send(CreateReply::default().embed(message.to_embed())).await?;
-        ctx.data().data.write().await.save().await?;
-        return Ok(());
+    if let Some(link) = show_tracker(ctx, tracker).await? {
+        tracker_updated(ctx, link, None).await?;
     }
-
-    Err("No active battle".into())
+    ctx.data().data.write().await.save().await
 }
 
 #[poise::command(prefix_command, slash_command, rename = "end_battle", aliases("koniec_walki"))]
 pub async fn end_battle_cmd(ctx: Context<'_>) -> Result<(), Error> {
-    let message_content;
-
+    let tracker;
     {
-        let user_id = ctx.author().id.get();
         let mut data = ctx.data().data.write().await;
-        let user_data = data.users.get(&user_id);
-        let user_lang = match user_data {
-            Some(ud) => ud.lang,
-            None => LocaleLang::default(),
-        };
-
-        if data.battle.is_none() {
-            return Err("No active battle".into());
-        }
-
-        data.battle = None;
-
-        message_content = MessageContent {
-            title: locale_text_by_tag_lang(user_lang, LocaleTag::FightEnd),
-            ..Default::default()
-        }
-        .to_embed();
+        tracker = data.battle.take().ok_or("No active battle")?.tracker;
     }
 
-    ctx.send(CreateReply::default().embed(message_content)).await?;
+    if let Some(link) = show_tracker(ctx, tracker).await? {
+        tracker_updated(ctx, link, None).await?;
+    }
     ctx.data().data.write().await.save().await
 }
 
@@ -369,7 +315,7 @@ pub async fn add_to_fight_cmd(ctx: Context<'_>, list: String) -> Result<(), Erro
         return Ok(());
     }
 
-    let message_content_a;
+    let tracker;
     let message_content_b;
     {
         let user_id = ctx.author().id.get();
@@ -380,30 +326,21 @@ pub async fn add_to_fight_cmd(ctx: Context<'_>, list: String) -> Result<(), Erro
             None => LocaleLang::default(),
         };
 
+        let ruleset = data.ruleset(ctx.guild_id().map(|guild_id| guild_id.get()));
         if let Some(battle) = &mut data.battle {
-            let mut characters: Vec<CharacterInitiative> = vec![];
-            for pair in words.chunks(2) {
-                let name = pair[0];
-                let threshold = pair[1];
-                let skill_result = croll_impl(threshold)?;
-                characters.push(CharacterInitiative {
-                    croll_result: skill_result,
-                    name: name.to_string(),
-                });
-            }
+            let characters = initiative_impl(&list, ruleset)?;
             battle.add_characters(&characters)?;
 
             let add_mes = Some(format!("**⚔️ {}**", characters.iter().map(|el| &el.name).join(", ")));
 
-            message_content_a = MessageContent::from_battle(user_lang, battle, true, add_mes.clone());
-            message_content_b = MessageContent::from_battle(user_lang, battle, false, add_mes.clone());
+            message_content_b = MessageContent::from_battle(user_lang, battle, false, add_mes);
+            tracker = battle.tracker;
         } else {
             return Err("No active battle".into());
         }
     }
 
-    ctx.send(CreateReply::default().embed(message_content_a.to_embed()))
-        .await?;
+    show_tracker(ctx, tracker).await?;
     ctx.send(
         CreateReply::default()
             .embed(message_content_b.to_embed())
@@ -418,27 +355,17 @@ pub async fn remove_from_fight_cmd(
     ctx: Context<'_>,
     #[autocomplete = "autocomplete_battle"] name: String,
 ) -> Result<(), Error> {
-    let message_content;
+    let tracker;
     {
-        let user_id = ctx.author().id.get();
         let mut data = ctx.data().data.write().await;
-        let user_data = data.users.get(&user_id);
-        let user_lang = match user_data {
-            Some(ud) => ud.lang,
-            None => LocaleLang::default(),
-        };
-
-        if let Some(battle) = &mut data.battle {
-            battle.remove_character(&name)?;
-
-            message_content = MessageContent::from_battle(user_lang, battle, true, Some(format!("**💀 {}**", name)));
-        } else {
-            return Err("No active battle".into());
-        }
+        let battle = data.battle.as_mut().ok_or("No active battle")?;
+        battle.remove_character(&name)?;
+        tracker = battle.tracker;
     }
 
-    ctx.send(CreateReply::default().embed(message_content.to_embed()))
-        .await?;
+    if let Some(link) = show_tracker(ctx, tracker).await? {
+        tracker_updated(ctx, link, Some(format!("**💀 {}**", name))).await?;
+    }
     ctx.data().data.write().await.save().await
 }
 
@@ -468,6 +395,10 @@ pub async fn roll_attributes_cmd(
         Some(ud) => ud.lang,
         None => LocaleLang::default(),
     };
+    let min_attribute_value = min_attribute_value.unwrap_or(
+        data.settings(ctx.guild_id().map(|guild_id| guild_id.get()))
+            .min_attribute_value,
+    );
 
     let mut attribute_roll_result;
 
@@ -486,7 +417,7 @@ pub async fn roll_attributes_cmd(
         if reroll_low_sum.unwrap_or_default() && attribute_roll_result.is_sum_lt_quick_rules() {
             continue;
         }
-        if attribute_roll_result.lowest_attribute_value() < min_attribute_value.unwrap_or(40) {
+        if attribute_roll_result.lowest_attribute_value() < min_attribute_value {
             continue;
         }
         break;
@@ -0,0 +1,270 @@
+#[cfg(feature = "character-sheet")]
+use crate::character::Character;
+use crate::{
+    bot_data::Data,
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    roller::battle::{Battle, TrackerMessage},
+    types::*,
+};
+#[cfg(feature = "character-sheet")]
+use itertools::Itertools;
+use poise::{
+    CreateReply,
+    serenity_prelude::{
+        self as serenity, ButtonStyle, ChannelId, ComponentInteraction, CreateActionRow, CreateButton,
+        CreateInteractionResponse, CreateInteractionResponseMessage, EditMessage, MessageId,
+    },
+};
+use tokio::sync::RwLock;
+
+/// Custom ID prefix of tracker buttons; they are handled by the event handler, so they keep working after a restart.
+const BUTTON_PREFIX: &str = "battle:";
+
+/// Owner of the active character fighting as `name`; names shared by active characters of several players are left out.
+#[cfg(feature = "character-sheet")]
+fn combatant_owner(data: &Data, name: &str) -> Option<u64> {
+    // No Discord user has ID 0, so none of the owners is preferred.
+    data.active_character_owner(0, name, LocaleLang::default()).ok()
+}
+
+#[cfg(feature = "character-sheet")]
+fn combatant_character<'a>(data: &'a Data, name: &str) -> Option<&'a Character> {
+    data.users.get(&combatant_owner(data, name)?)?.characters.get(name)
+}
+
+/// Passes the turn; jammed weapons of the combatant taking it count down a round.
+pub fn next_turn(data: &mut Data) {
+    let Some(battle) = data.battle.as_mut() else {
+        return;
+    };
+    battle.next_round();
+    #[cfg(feature = "character-sheet")]
+    {
+        let Some(name) = battle.current_character_name().map(str::to_string) else {
+            return;
+        };
+        let Some(character) = combatant_owner(data, &name)
+            .and_then(|owner| data.users.get_mut(&owner))
+            .and_then(|user_data| user_data.characters.get_mut(&name))
+        else {
+            return;
+        };
+        for weapon in character.weapons.iter_mut().filter(|w| w.jammed.is_some()) {
+            weapon.work_on_jam().ok();
+        }
+    }
+}
+
+/// HP and conditions of combatants that are characters, and jammed weapons of the current one.
+#[cfg(feature = "character-sheet")]
+fn combatants_status(data: &Data, battle: &Battle) -> Option<String> {
+    let find = |name: &str| combatant_character(data, name);
+    let mut lines: Vec<String> = battle
+        .characters
+        .iter()
+        .filter_map(|combatant| {
+            find(&combatant.name).map(|character| {
+                format!(
+                    "**{}** {} {}",
+                    combatant.name,
+                    character.status_hp(),
+                    character.status_sanity()
+                )
+            })
+        })
+        .collect();
+    if let Some(character) = battle.current_character_name().and_then(find) {
+        lines.extend(
+            character
+                .weapons
+                .iter()
+                .filter_map(|w| w.jammed.map(|rounds| format!("🔧 **{}**: {}", w.name, rounds))),
+        );
+    }
+    (!lines.is_empty()).then(|| lines.iter().join("\n"))
+}
+
+#[cfg(not(feature = "character-sheet"))]
+fn combatants_status(_: &Data, _: &Battle) -> Option<String> {
+    None
+}
+
+fn tracker_content(lang: LocaleLang, data: &Data) -> MessageContent {
+    match &data.battle {
+        Some(battle) => MessageContent::from_battle(lang, battle, true, combatants_status(data, battle)),
+        None => MessageContent {
+            title: locale_text_by_tag_lang(lang, LocaleTag::FightEnd),
+            ..Default::default()
+        },
+    }
+}
+
+fn tracker_buttons(lang: LocaleLang, data: &Data) -> Vec<CreateActionRow> {
+    if data.battle.is_none() {
+        return vec![];
+    }
+    let button = |action: &str, emoji: &str, tag: LocaleTag, style: ButtonStyle| {
+        CreateButton::new(format!("{BUTTON_PREFIX}{action}"))
+            .label(format!("{emoji} {}", locale_text_by_tag_lang(lang, tag)))
+            .style(style)
+    };
+    vec![CreateActionRow::Buttons(vec![
+        button("previous", "⏮️", LocaleTag::PreviousTurn, ButtonStyle::Secondary),
+        button("next", "⏭️", LocaleTag::NextTurn, ButtonStyle::Primary),
+        button("remove", "💀", LocaleTag::RemoveCombatant, ButtonStyle::Secondary),
+        button("end", "🏁", LocaleTag::EndBattle, ButtonStyle::Danger),
+    ])]
+}
+
+/// Edits the battle tracker, or posts a new one when there is none or it was deleted.
+/// Returns a link to the tracker when it was edited, so the command can acknowledge quietly.
+pub async fn show_tracker(ctx: Context<'_>, tracker: Option<TrackerMessage>) -> Result<Option<String>, Error> {
+    let mc;
+    let buttons;
+    {
+        let data = ctx.data().data.read().await;
+        let lang = data
+            .users
+            .get(&ctx.author().id.get())
+            .map(|u| u.lang)
+            .unwrap_or_default();
+        mc = tracker_content(lang, &data);
+        buttons = tracker_buttons(lang, &data);
+    }
+
+    if let Some(tracker) = tracker {
+        let channel_id = ChannelId::new(tracker.channel_id);
+        let message_id = MessageId::new(tracker.message_id);
+        let edit = EditMessage::new().embed(mc.to_embed()).components(buttons.clone());
+        match channel_id.edit_message(ctx, message_id, edit).await {
+            Ok(_) => return Ok(Some(message_id.link(channel_id, ctx.guild_id()))),
+            Err(e) => eprintln!("Battle tracker update failed: {e}"),
+        }
+    }
+
+    let reply = ctx
+        .send(CreateReply::default().embed(mc.to_embed()).components(buttons))
+        .await?;
+    let message = reply.message().await?;
+    if let Some(battle) = &mut ctx.data().data.write().await.battle {
+        battle.tracker = Some(TrackerMessage {
+            channel_id: message.channel_id.get(),
+            message_id: message.id.get(),
+        });
+    }
+    Ok(None)
+}
+
+/// Replies to a battle command whose result is already visible on the edited tracker.
+pub async fn tracker_updated(ctx: Context<'_>, link: String, comment: Option<String>) -> Result<(), Error> {
+    let lang;
+    {
+        let data = ctx.data().data.read().await;
+        lang = data
+            .users
+            .get(&ctx.author().id.get())
+            .map(|u| u.lang)
+            .unwrap_or_default();
+    }
+    let mc = MessageContent {
+        description: [
+            comment,
+            Some(format!(
+                "{} {link}",
+                locale_text_by_tag_lang(lang, LocaleTag::BattleTrackerUpdated)
+            )),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("\n"),
+        ..Default::default()
+    };
+    ctx.send(CreateReply::default().embed(mc.to_embed()).ephemeral(true))
+        .await?;
+    Ok(())
+}
+
+/// The GM may always press tracker buttons, players only when their character has the turn.
+#[cfg_attr(not(feature = "character-sheet"), allow(unused_variables))]
+fn can_press(ctx: &serenity::Context, interaction: &ComponentInteraction, data: &Data, battle: &Battle) -> bool {
+    let is_gm = interaction.guild_id.is_some_and(|guild_id| {
+        let gm_role_name = data.settings(Some(guild_id.get())).gm_role_name;
+        let gm_role_id = guild_id
+            .to_guild_cached(ctx)
+            .and_then(|guild| guild.role_by_name(&gm_role_name).map(|role| role.id));
+        match (gm_role_id, &interaction.member) {
+            (Some(gm_role_id), Some(member)) => member.roles.contains(&gm_role_id),
+            _ => false,
+        }
+    });
+    #[cfg(feature = "character-sheet")]
+    let is_current_player = battle.current_character_name().is_some_and(|name| {
+        let user_id = interaction.user.id.get();
+        data.active_character_owner(user_id, name, LocaleLang::default())
+            .is_ok_and(|owner| owner == user_id)
+    });
+    #[cfg(not(feature = "character-sheet"))]
+    let is_current_player = false;
+    is_gm || is_current_player
+}
+
+/// Handles tracker buttons; presses on trackers of finished battles just remove the buttons.
+pub async fn handle_tracker_button(
+    ctx: &serenity::Context,
+    interaction: &ComponentInteraction,
+    data: &RwLock<Data>,
+) -> Result<(), Error> {
+    let Some(action) = interaction.data.custom_id.strip_prefix(BUTTON_PREFIX) else {
+        return Ok(());
+    };
+
+    let response;
+    {
+        let mut data = data.write().await;
+        let lang = data
+            .users
+            .get(&interaction.user.id.get())
+            .map(|u| u.lang)
+            .unwrap_or_default();
+        let current = data
+            .battle
+            .as_ref()
+            .filter(|battle| battle.tracker.map(|tracker| tracker.message_id) == Some(interaction.message.id.get()));
+
+        response = match current {
+            Some(battle) if !can_press(ctx, interaction, &data, battle) => CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .content(format!(
+                        "🚫 {}",
+                        locale_text_by_tag_lang(lang, LocaleTag::BattleButtonDenied)
+                    ))
+                    .ephemeral(true),
+            ),
+            Some(_) => {
+                match action {
+                    "previous" => data.battle.as_mut().map(Battle::previous_round),
+                    "next" => data.battle.is_some().then(|| next_turn(&mut data)),
+                    "remove" => data.battle.as_mut().map(|battle| {
+                        battle.remove_current_character();
+                    }),
+                    "end" => data.battle.take().map(|_| ()),
+                    _ => None,
+                };
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .embed(tracker_content(lang, &data).to_embed())
+                        .components(tracker_buttons(lang, &data)),
+                )
+            }
+            None => {
+                CreateInteractionResponse::UpdateMessage(CreateInteractionResponseMessage::new().components(vec![]))
+            }
+        };
+    }
+
+    interaction.create_response(ctx, response).await?;
+
+    data.read().await.save().await
+}
@@ -1,12 +1,12 @@
+use crate::roller::croll::croll_impl;
 use crate::{
     commands::{
         autocomplete::character::autocomplete_my_attributes,
-        basic::croll_impl,
         character::interaction::{add_push_roll_button, add_spend_luck_buttons, handle_interaction, ok_button},
     },
     locale::{LocaleTag, locale_tag_by_str, locale_text_by_tag_lang},
-    message::MessageContent,
-    roller::success_level::SuccessLevel,
+    message::{MessageContent, ToEmbed},
+    roller::{roll_log::RollRecord, success_level::SuccessLevel},
     types::*,
 };
 use poise::CreateReply;
@@ -72,8 +72,9 @@ async fn check_cmd(
     let croll_query;
     let mc;
     let user_lang;
-    let croll_result;
+    let mut croll_result;
     let character_name;
+    let mut luck_spent = 0;
     let mut buttons = vec![];
     {
         user_id = ctx.author().id.get();
@@ -90,12 +91,14 @@ async fn check_cmd(
             &character_name
         ))?;
 
+        let attribute = character.attributes.get(&name).unwrap();
         croll_query = format!(
-            "{}{}",
-            character.attributes.get(&name).unwrap().value,
-            modifier_dice.unwrap_or_default()
+            "{}{}{}",
+            attribute.value,
+            modifier_dice.unwrap_or_default(),
+            "+".repeat(character.talent_bonus_dice(&attribute.name) as usize)
         );
-        croll_result = croll_impl(&croll_query)?;
+        croll_result = croll_impl(&croll_query, character.ruleset())?;
 
         mc = MessageContent::from_croll_result(user_lang, &croll_result, false, true)
             .with_skill_name(&name)
@@ -130,7 +133,7 @@ async fn check_cmd(
     {
         match tag {
             LocaleTag::PushRoll => {
-                let croll_result = croll_impl(&croll_query)?;
+                croll_result = croll_impl(&croll_query, croll_result.ruleset)?;
                 let mut mc = MessageContent::from_croll_result(user_lang, &croll_result, false, true)
                     .with_skill_name(&name)
                     .with_character_name(&character_name);
@@ -157,7 +160,6 @@ async fn check_cmd(
                 let sl = SuccessLevel::from_tag(tag)?;
                 let luck = sl.delta(croll_result.result(), croll_result.threshold);
                 let remaining_luck;
-                let mut croll_result = croll_result;
                 croll_result.set_result(croll_result.result() - luck);
                 {
                     let mut data = ctx.data().data.write().await;
@@ -170,6 +172,7 @@ async fn check_cmd(
                     character.luck.modify(-luck);
                     remaining_luck = character.luck.current;
                 }
+                luck_spent = luck;
                 let mut mc = MessageContent::from_croll_result(user_lang, &croll_result, false, true)
                     .with_skill_name(&name)
                     .with_character_name(&character_name);
@@ -183,5 +186,12 @@ async fn check_cmd(
         }
     }
 
+    ctx.data().data.write().await.record_roll(
+        RollRecord::new(user_id, &croll_result)
+            .with_character(&character_name)
+            .with_skill(&name)
+            .with_luck_spent(luck_spent),
+    );
+
     ctx.data().data.write().await.save().await
 }
@@ -1,11 +1,16 @@
 use crate::{
-    character::{Attributes, Character},
-    commands::autocomplete::character::{
-        autocomplete_my_character, autocomplete_my_pulp_talents, autocomplete_pulp_archetypes,
-        autocomplete_pulp_talents,
+    character::{self, Attributes, Character},
+    commands::autocomplete::{
+        autocomplete_attributes,
+        character::{
+            autocomplete_my_character, autocomplete_my_pulp_talents, autocomplete_pulp_archetypes,
+            autocomplete_pulp_talents,
+        },
     },
-    locale::{LocaleTag, locale_entry_by_str, locale_text_by_tag_lang},
-    message::MessageContent,
+    commands::character::wizard::show_wizard,
+    locale::{LocaleTag, locale_entry_by_str, locale_entry_by_tag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    ruleset::RulesetKind,
     types::*,
 };
 use poise::CreateReply;
@@ -13,7 +18,7 @@ use poise::CreateReply;
 #[poise::command(
     prefix_command,
     slash_command,
-    subcommands("create", "select", "remove", "reset", "pulp_talent"),
+    subcommands("create", "wizard", "select", "remove", "reset", "backstory", "pulp_talent"),
     rename = "character",
     aliases("postać")
 )]
@@ -39,6 +44,9 @@ async fn create(
     #[autocomplete = "autocomplete_pulp_archetypes"]
     #[name_localized("pl", "pulpowy_archetyp")]
     pulp_archetype: Option<String>,
+    #[autocomplete = "autocomplete_attributes"]
+    #[name_localized("pl", "cecha_podstawowa")]
+    core_characteristic: Option<String>,
 ) -> Result<(), Error> {
     let mut mc = MessageContent::default();
     let mut ephemeral = false;
@@ -46,7 +54,9 @@ async fn create(
     {
         let user_id = ctx.author().id.get();
         let mut data = ctx.data().data.write().await;
-        let max = data.max_characters_per_user;
+        let settings = data.settings(ctx.guild_id().map(|guild_id| guild_id.get()));
+        let max = settings.max_characters_per_user;
+        let guild_ruleset = settings.ruleset;
         let user_data = data.users.entry(user_id).or_default();
 
         if user_data.characters.len() >= max {
@@ -56,10 +66,21 @@ async fn create(
             let attributes = Attributes::new(str, con, siz, dex, app, int, pow, edu)?;
             let pulp_archetype =
                 pulp_archetype.map(|pulp_archetype| locale_entry_by_str(&pulp_archetype).unwrap().clone());
-            user_data.characters.insert(
-                name.clone(),
-                Character::new(&name, &occupation, attributes, luck, pulp_archetype)?,
-            );
+            let core_characteristic = pulp_archetype
+                .as_ref()
+                .and_then(|archetype| archetype.to_enum())
+                .and_then(character::pulp_archetype)
+                .map(|archetype| archetype.core_characteristic(core_characteristic.as_deref()))
+                .transpose()
+                .map_err(|e| e.to_string(user_data.lang))?;
+            let ruleset = if pulp_archetype.is_some() {
+                RulesetKind::Pulp
+            } else {
+                guild_ruleset
+            };
+            let mut character = Character::new(&name, &occupation, attributes, luck, pulp_archetype, ruleset)?;
+            character.pulp_core_characteristic = core_characteristic.map(|tag| locale_entry_by_tag(tag).clone());
+            user_data.characters.insert(name.clone(), character);
             mc.title = format!("✅ `{name}`");
             user_data.active_character = Some(name);
         }
@@ -71,6 +92,12 @@ async fn create(
     ctx.data().data.write().await.save().await
 }
 
+/// Creates a character step by step; unfinished drafts are saved
+#[poise::command(slash_command, aliases("kreator"))]
+async fn wizard(ctx: Context<'_>) -> Result<(), Error> {
+    show_wizard(ctx).await
+}
+
 #[poise::command(prefix_command, slash_command, aliases("usuń"))]
 async fn remove(
     ctx: Context<'_>,
@@ -173,6 +200,48 @@ async fn reset(ctx: Context<'_>) -> Result<(), Error> {
     ctx.data().data.write().await.save().await
 }
 
+/// Sets the backstory of the active character; empty clears it
+#[poise::command(prefix_command, slash_command, aliases("historia"))]
+async fn backstory(
+    ctx: Context<'_>,
+    #[name_localized("pl", "historia")]
+    #[max_length = 2000]
+    text: Option<String>,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let active = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&active).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
+            &active
+        ))?;
+
+        character.backstory = text.filter(|text| !text.trim().is_empty());
+
+        mc = MessageContent {
+            title: format!(
+                "`{}`: {}",
+                &active,
+                locale_text_by_tag_lang(user_data.lang, LocaleTag::SheetBackstory)
+            ),
+            description: character.backstory.clone().unwrap_or("-".into()),
+            ..Default::default()
+        }
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed()).ephemeral(true))
+        .await?;
+
+    ctx.data().data.write().await.save().await
+}
+
 #[poise::command(
     prefix_command,
     slash_command,
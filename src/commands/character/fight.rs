@@ -1,18 +1,19 @@
+use crate::roller::croll::croll_impl;
 use std::sync::Arc;
 
 use crate::{
     character::WeaponOk,
     commands::{
         autocomplete::*,
-        basic::croll_impl,
         character::interaction::{add_spend_luck_buttons, handle_interaction, ok_button},
     },
     locale::{LocaleTag, locale_tag_by_str, locale_text_by_tag_lang},
-    message::MessageContent,
+    message::{MessageContent, ToEmbed},
     roller::{
         dice_rng::RealRng,
         modifier_dice::ModifierDiceType,
         roll::{get_roll_max, merge_roll_results, roll_die, roll_query},
+        roll_log::RollRecord,
         success_level::SuccessLevel,
     },
     types::*,
@@ -42,6 +43,9 @@ pub async fn fight_cmd(
     {
         user_id = ctx.author().id.get();
         let mut data = ctx.data().data.write().await;
+        let luck_in_combat = data
+            .settings(ctx.guild_id().map(|guild_id| guild_id.get()))
+            .luck_in_combat;
         let data_users = &mut data.users;
         data_users.entry(user_id).or_default();
         let user_data = data_users.get_mut(&user_id).unwrap();
@@ -67,6 +71,7 @@ pub async fn fight_cmd(
                 .find(|&w| w.name.to_ascii_lowercase().contains(&weapon_name.to_ascii_lowercase()))
                 .cloned()
                 .ok_or("No such weapon")?;
+            weapon.can_fire().map_err(|e| Error::from(e.to_string(user_lang)))?;
 
             attack = weapon
                 .attacks
@@ -108,7 +113,7 @@ pub async fn fight_cmd(
                 )
             })?;
             let croll_query = format!("{}{}", skill.value, modifier_dice);
-            let mut croll_result = croll_impl(&croll_query)?;
+            let mut croll_result = croll_impl(&croll_query, character.ruleset())?;
 
             let weapon_result;
             let weapon_malfunction;
@@ -136,7 +141,9 @@ pub async fn fight_cmd(
                 && croll_result.success_level != SuccessLevel::CriticalFailure
             {
                 buttons.push(ok_button());
-                buttons.extend(add_spend_luck_buttons(&croll_result, character, user_lang));
+                if luck_in_combat {
+                    buttons.extend(add_spend_luck_buttons(&croll_result, character, user_lang));
+                }
             }
 
             let mut mark_to_improve = false;
@@ -165,6 +172,13 @@ pub async fn fight_cmd(
                     let mut rng = RealRng::new();
                     jammed_rounds = roll_die(&mut rng, 6);
                 }
+                if let Some(weapon) = character
+                    .weapons
+                    .iter_mut()
+                    .find(|w| w.name.to_ascii_lowercase().contains(&weapon_name.to_ascii_lowercase()))
+                {
+                    weapon.jam(jammed_rounds);
+                }
                 mc.description = format!(
                     "{}\n**{}**\n{}: **{}**",
                     mc.description,
@@ -220,6 +234,8 @@ pub async fn fight_cmd(
                 let character_name = character_name.clone();
                 let mut mc = mc;
                 let mut mark_to_improve = mark_to_improve;
+                let mut croll_result = croll_result;
+                let mut luck_spent = 0;
                 let replies = replies.clone();
 
                 scope.spawn(async move {
@@ -256,7 +272,6 @@ pub async fn fight_cmd(
                                 let sl = SuccessLevel::from_tag(tag).unwrap();
                                 let luck = sl.delta(croll_result.result(), croll_result.threshold);
                                 let remaining_luck;
-                                let mut croll_result = croll_result;
                                 croll_result.set_result(croll_result.result() - luck);
                                 croll_result.success_level = sl;
                                 {
@@ -274,6 +289,7 @@ pub async fn fight_cmd(
                                     character.luck.modify(-luck);
                                     remaining_luck = character.luck.current;
                                 }
+                                luck_spent = luck;
                                 let mut mc = MessageContent::from_croll_result(user_lang, &croll_result, false, true)
                                     .with_skill_name(&weapon_skill_str)
                                     .with_character_name(&character_name);
@@ -288,6 +304,13 @@ pub async fn fight_cmd(
                         }
                     }
 
+                    data.write().await.record_roll(
+                        RollRecord::new(user_id, &croll_result)
+                            .with_character(&character_name)
+                            .with_skill(&weapon_skill_str)
+                            .with_luck_spent(luck_spent),
+                    );
+
                     mark_to_improve
                 });
             }
@@ -376,7 +399,7 @@ pub async fn damage_cmd(
                     let mut rng = RealRng::new();
                     Some(roll_query(&mut rng, &dmg_dice)?)
                 }
-                'e' => Some(if weapon.impaling {
+                'e' => Some(if weapon.is_impaling() {
                     let mut rng = RealRng::new();
                     merge_roll_results(&[get_roll_max(&dmg_dice)?, roll_query(&mut rng, &weapon_damage)?])?
                 } else {
@@ -0,0 +1,180 @@
+use crate::roller::croll::croll_impl;
+use crate::{
+    character::recovery_roll_impl,
+    commands::autocomplete::*,
+    locale::{LocaleTag, locale_dice, locale_entry_by_tag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    roller::{dice_rng::RealRng, roll::roll_query, success_level::SuccessLevel},
+    types::*,
+};
+use poise::CreateReply;
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "heal",
+    aliases("lecz"),
+    subcommands("first_aid_cmd", "medicine_cmd", "recovery_cmd")
+)]
+pub async fn heal_cmd(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, rename = "first_aid", aliases("pierwsza_pomoc"))]
+async fn first_aid_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "pacjent")]
+    patient: String,
+) -> Result<(), Error> {
+    treatment_impl(ctx, &patient, LocaleTag::FirstAid).await
+}
+
+#[poise::command(prefix_command, slash_command, rename = "medicine", aliases("medycyna"))]
+async fn medicine_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "pacjent")]
+    patient: String,
+) -> Result<(), Error> {
+    treatment_impl(ctx, &patient, LocaleTag::Medicine).await
+}
+
+/// Rolls healer's First Aid or Medicine skill and treats patient on success.
+async fn treatment_impl(ctx: Context<'_>, patient_name: &str, skill_tag: LocaleTag) -> Result<(), Error> {
+    let mut mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let lang = user_data.lang;
+        let healer_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoCharacterSelected))?;
+        let healer = user_data.characters.get(&healer_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+            &healer_name
+        ))?;
+        let ruleset = healer.ruleset();
+        let skill = healer
+            .get_skill(locale_entry_by_tag(skill_tag).en())
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoSuchSkill))?;
+
+        let patient_owner = data.active_character_owner(user_id, patient_name, lang)?;
+        let patient = data
+            .users
+            .get_mut(&patient_owner)
+            .and_then(|user_data| user_data.characters.get_mut(patient_name))
+            .ok_or(format!(
+                "{}: `{}`",
+                locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+                patient_name
+            ))?;
+
+        match skill_tag {
+            LocaleTag::FirstAid => patient.can_receive_first_aid(),
+            _ => patient.can_receive_medicine(),
+        }
+        .map_err(|e| Error::from(e.to_string(lang)))?;
+
+        let croll_result = croll_impl(&skill.value.to_string(), ruleset)?;
+        mc = MessageContent::from_croll_result(lang, &croll_result, false, true)
+            .with_skill_name(&skill.name.get(lang))
+            .with_character_name(&healer_name);
+
+        // A failed attempt still uses up the patient's treatment for today.
+        let success = croll_result.success_level >= SuccessLevel::Success;
+        let was_dying = patient.healing.dying;
+        let (healed, query) = match skill_tag {
+            LocaleTag::FirstAid => (patient.first_aid(success), "1".to_string()),
+            _ => {
+                let query = "1d3".to_string();
+                let amount;
+                {
+                    let mut rng = RealRng::new();
+                    amount = roll_query(&mut rng, &query)?.result();
+                }
+                (patient.medicine(success, amount), locale_dice(lang, &query))
+            }
+        };
+        let healed = healed.map_err(|e| Error::from(e.to_string(lang)))?;
+
+        if success {
+            mc.description = format!(
+                "{}\n\n`{}` ❤️ **{:+}** ({})\n{}",
+                mc.description,
+                patient.name,
+                healed,
+                query,
+                patient.status_hp()
+            );
+            if was_dying {
+                mc.description = format!(
+                    "{}\n**{}**",
+                    mc.description,
+                    locale_text_by_tag_lang(lang, LocaleTag::Stabilized)
+                );
+            }
+
+            let user_data = data.users.entry(user_id).or_default();
+            if let Some(healer) = user_data.characters.get_mut(&healer_name)
+                && let Some(skill) = healer.get_mut_skill(skill.name.en())
+                && skill.improvable
+                && !skill.to_improve
+            {
+                skill.to_improve = true;
+                mc.description = format!(
+                    "{}\n{}",
+                    mc.description,
+                    locale_text_by_tag_lang(lang, LocaleTag::SkillMarked)
+                );
+            }
+        }
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
+
+#[poise::command(prefix_command, slash_command, rename = "recovery", aliases("rekonwalescencja"))]
+async fn recovery_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let mut mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let lang = user_data.lang;
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        let (croll_result, query, healed) = recovery_roll_impl(character, lang)?;
+        mc = MessageContent::from_croll_result(lang, &croll_result, false, true)
+            .with_skill_name(&locale_text_by_tag_lang(lang, LocaleTag::RecoveryRoll))
+            .with_character_name(&character_name);
+
+        if croll_result.success_level >= SuccessLevel::Success {
+            mc.description = format!(
+                "{}\n\n❤️ **{:+}** ({})\n{}\n**{}**",
+                mc.description,
+                healed,
+                locale_dice(lang, query),
+                character.status_hp(),
+                locale_text_by_tag_lang(lang, LocaleTag::MajorWoundHealed)
+            );
+        }
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
@@ -0,0 +1,107 @@
+use crate::roller::croll::croll_impl;
+use crate::{
+    character::{BoutKind, InsanityKind},
+    commands::character::interaction::handle_interaction,
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    roller::{dice_rng::RealRng, success_level::SuccessLevel},
+    types::*,
+};
+use poise::{
+    CreateReply,
+    serenity_prelude::{self as serenity, CreateActionRow, CreateButton},
+};
+
+pub fn int_check_button(lang: LocaleLang) -> CreateButton {
+    serenity::CreateButton::new(locale_text_by_tag_lang(lang, LocaleTag::IntCheck))
+        .label(locale_text_by_tag_lang(lang, LocaleTag::IntCheck))
+        .style(serenity::ButtonStyle::Danger)
+}
+
+/// Sends Sanity loss message with INT check button; successful check starts temporary insanity
+/// for the character of `user_id`.
+pub async fn int_check_impl(
+    ctx: Context<'_>,
+    mut mc: MessageContent,
+    user_id: u64,
+    character_name: &str,
+    lang: LocaleLang,
+    bout_kind: BoutKind,
+) -> Result<(), Error> {
+    let action_row = CreateActionRow::Buttons(vec![int_check_button(lang)]);
+    let reply = ctx
+        .send(CreateReply::default().embed(mc.to_embed()).components(vec![action_row]))
+        .await?;
+
+    if handle_interaction(ctx.serenity_context().shard.clone(), &reply)
+        .await?
+        .is_some()
+    {
+        let mut data = ctx.data().data.write().await;
+        let character = data
+            .users
+            .get_mut(&user_id)
+            .and_then(|user_data| user_data.characters.get_mut(character_name))
+            .ok_or(format!(
+                "{}: `{}`",
+                locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+                character_name
+            ))?;
+
+        let int_result = croll_impl(&character.attributes.intelligence().to_string(), character.ruleset())?;
+        let int_mc = MessageContent::from_croll_result(lang, &int_result, false, true);
+        mc.description = format!(
+            "{}\n\n{} {}\n{}",
+            mc.description,
+            locale_text_by_tag_lang(lang, LocaleTag::IntCheck),
+            int_mc.title,
+            int_mc.description
+        );
+
+        if int_result.success_level >= SuccessLevel::Success {
+            let episode;
+            {
+                let mut rng = RealRng::new();
+                episode = character.start_insanity(&mut rng, InsanityKind::Temporary, None, Some(bout_kind));
+            }
+            mc.description = format!("{}\n\n{}", mc.description, episode.to_string(lang));
+        } else {
+            mc.description = format!(
+                "{}\n\n{}",
+                mc.description,
+                locale_text_by_tag_lang(lang, LocaleTag::MemoryRepressed)
+            );
+        }
+    }
+
+    reply
+        .edit(ctx, CreateReply::default().embed(mc.to_embed()).components(vec![]))
+        .await?;
+
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, rename = "insanity", aliases("szaleństwo"))]
+pub async fn insanity_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        mc = MessageContent::from_character_to_insanity(user_data.lang, character);
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed()).ephemeral(true))
+        .await?;
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::{
-    character::Character,
-    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
-    message::MessageContent,
+    character::{Character, TALENT_LUCK_COST},
+    locale::{LocaleEntry, LocaleLang, LocaleTag, locale_text_by_tag_lang},
+    message::from_embed,
     roller::{croll::CrollResult, success_level::SuccessLevel},
     types::Error,
 };
@@ -11,6 +11,9 @@ use poise::{
 };
 use std::time::Duration;
 
+/// Discord allows at most five buttons in an action row.
+pub const MAX_BUTTONS: usize = 5;
+
 pub fn ok_button() -> CreateButton {
     serenity::CreateButton::new("OK")
         .label("OK")
@@ -29,12 +32,39 @@ pub fn add_push_roll_button(croll_result: &CrollResult, lang: LocaleLang) -> Vec
     buttons
 }
 
+/// Pulp talents like Beady Eye let failed rolls be re-rolled for Luck.
+pub fn add_talent_reroll_button(
+    croll_result: &CrollResult,
+    character: &Character,
+    name: &LocaleEntry,
+    lang: LocaleLang,
+) -> Vec<CreateButton> {
+    let mut buttons: Vec<CreateButton> = vec![];
+    if croll_result.success_level < SuccessLevel::Success && character.can_talent_reroll(name) {
+        let mut button = serenity::CreateButton::new(locale_text_by_tag_lang(lang, LocaleTag::TalentReRoll))
+            .label(format!(
+                "🍀{}➡️{}",
+                TALENT_LUCK_COST,
+                locale_text_by_tag_lang(lang, LocaleTag::TalentReRoll)
+            ))
+            .style(serenity::ButtonStyle::Primary);
+        if character.luck.current < TALENT_LUCK_COST {
+            button = button.disabled(true);
+        }
+        buttons.push(button);
+    }
+    buttons
+}
+
 pub fn add_spend_luck_buttons(
     croll_result: &CrollResult,
     character: &Character,
     lang: LocaleLang,
 ) -> Vec<CreateButton> {
     let mut buttons: Vec<CreateButton> = vec![];
+    if !croll_result.ruleset.ruleset().luck_spending() {
+        return buttons;
+    }
     let mut previous_delta = 0;
     for success_level in croll_result.success_level {
         let luck_delta = success_level.delta(croll_result.result(), croll_result.threshold);
@@ -60,7 +90,7 @@ pub fn add_spend_luck_buttons(
 pub async fn handle_interaction(shard: ShardMessenger, reply: &ReplyHandle<'_>) -> Result<Option<String>, Error> {
     let orig_message = reply.message().await?.into_owned();
     let embed = orig_message.embeds[0].clone();
-    let mut mc = MessageContent::from(embed);
+    let mut mc = from_embed(embed);
     let mut ir = None;
 
     if let Some(interaction) = serenity::ComponentInteractionCollector::new(shard)
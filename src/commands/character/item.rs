@@ -1,8 +1,9 @@
 use crate::{
-    character::Item,
+    character::{Item, ItemEffect},
     commands::autocomplete::*,
     locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
-    message::MessageContent,
+    message::{MessageContent, ToEmbed},
+    roller::{dice_rng::RealRng, roll::roll_query},
     types::*,
 };
 use poise::CreateReply;
@@ -12,7 +13,7 @@ use poise::CreateReply;
     slash_command,
     rename = "item",
     aliases("przedmiot"),
-    subcommands("list_cmd", "add_cmd", "give_cmd", "remove_cmd")
+    subcommands("list_cmd", "add_cmd", "info_cmd", "use_cmd", "give_cmd", "remove_cmd")
 )]
 pub async fn item_cmd(_: Context<'_>) -> Result<(), Error> {
     Ok(())
@@ -34,7 +35,7 @@ async fn list_cmd(ctx: Context<'_>) -> Result<(), Error> {
             locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
             &character_name
         ))?;
-        mc = MessageContent::from_character_items(user_data.lang, &character.items);
+        mc = MessageContent::from_character_to_items(user_data.lang, character);
     }
 
     ctx.send(CreateReply::default().content(mc.to_content()).ephemeral(true))
@@ -44,6 +45,7 @@ async fn list_cmd(ctx: Context<'_>) -> Result<(), Error> {
 }
 
 #[poise::command(prefix_command, slash_command, rename = "add", aliases("dodaj"))]
+#[allow(clippy::too_many_arguments)]
 async fn add_cmd(
     ctx: Context<'_>,
     #[autocomplete = "autocomplete_my_items"]
@@ -51,6 +53,20 @@ async fn add_cmd(
     item_name: String,
     #[name_localized("pl", "ilość")] item_qty: Option<i32>,
     #[name_localized("pl", "jednostka")] item_unit: Option<String>,
+    #[name_localized("pl", "opis")] description: Option<String>,
+    #[description = "kg"]
+    #[name_localized("pl", "waga")]
+    weight: Option<f32>,
+    #[description = "tag1, tag2"]
+    #[name_localized("pl", "tagi")]
+    tags: Option<String>,
+    #[autocomplete = "autocomplete_my_items"]
+    #[name_localized("pl", "pojemnik")]
+    container: Option<String>,
+    #[name_localized("pl", "ładunki")] charges: Option<i32>,
+    #[description = "heal:1d3, bonus, light"]
+    #[name_localized("pl", "efekt")]
+    effect: Option<String>,
 ) -> Result<(), Error> {
     let message;
 
@@ -69,18 +85,58 @@ async fn add_cmd(
         ))?;
 
         let item_qty = item_qty.unwrap_or(1);
+        let effect = match &effect {
+            Some(effect) => Some(effect.parse::<ItemEffect>().map_err(|_| {
+                format!(
+                    "{}: `{}`",
+                    locale_text_by_tag_lang(user_data.lang, LocaleTag::InvalidItemEffect),
+                    effect
+                )
+            })?),
+            None => None,
+        };
 
-        if let Some(position) = character
+        // Attributes given for an item already carried replace the old ones.
+        let position = character
             .items
             .iter()
-            .position(|n| n.name.to_ascii_lowercase().contains(&item_name.to_ascii_lowercase()))
-        {
-            let item = character.items.get_mut(position).unwrap();
-            item.add(item_qty);
-        } else {
-            let item = Item::new(&item_name, item_qty, item_unit.as_deref());
-            character.items.push(item);
+            .position(|n| n.name.to_ascii_lowercase().contains(&item_name.to_ascii_lowercase()));
+        let mut item = match position {
+            Some(position) => character.items[position].clone(),
+            None => Item::new(&item_name, 0, None),
         };
+        item.add(item_qty);
+        if let Some(charges) = charges {
+            item = item
+                .with_charges(charges)
+                .map_err(|e| Error::from(e.to_string(user_data.lang)))?;
+        }
+        if item_unit.is_some() {
+            item.unit = item_unit;
+        }
+        if description.is_some() {
+            item.description = description;
+        }
+        if let Some(weight) = weight {
+            item.weight = weight.max(0.0);
+        }
+        if let Some(tags) = tags {
+            item.tags = tags
+                .split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect();
+        }
+        if container.is_some() {
+            item.container = container;
+        }
+        if effect.is_some() {
+            item.effect = effect;
+        }
+        match position {
+            Some(position) => character.items[position] = item,
+            None => character.items.push(item),
+        }
 
         message = MessageContent {
             title: format!("`{}`", character.name),
@@ -99,6 +155,186 @@ async fn add_cmd(
     ctx.data().data.write().await.save().await
 }
 
+#[poise::command(prefix_command, slash_command, rename = "info", aliases("opis"))]
+async fn info_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_my_items"]
+    #[name_localized("pl", "nazwa_przedmiotu")]
+    item_name: String,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        let item = character
+            .items
+            .iter()
+            .find(|n| n.name.to_ascii_lowercase().contains(&item_name.to_ascii_lowercase()))
+            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoSuchItem))?;
+
+        let mut description = item.to_details(user_data.lang);
+        let contents = character
+            .items
+            .iter()
+            .filter(|i| i.container.as_deref() == Some(item.name.as_str()))
+            .map(|i| format!("  ↳ {}", i.to_string(user_data.lang)))
+            .collect::<Vec<_>>();
+        if !contents.is_empty() {
+            description = format!("{}\n{}", description, contents.join("\n"));
+        }
+
+        mc = MessageContent {
+            title: format!("`{}`", character.name),
+            description,
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed()).ephemeral(true))
+        .await?;
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, rename = "use", aliases("użyj"))]
+async fn use_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_my_items"]
+    #[name_localized("pl", "nazwa_przedmiotu")]
+    item_name: String,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "cel")]
+    target: Option<String>,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let lang = user_data.lang;
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        let position = character
+            .items
+            .iter()
+            .position(|n| n.name.to_ascii_lowercase().contains(&item_name.to_ascii_lowercase()))
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoSuchItem))?;
+        // The charge is spent on a copy first, so a bad target or roll leaves the item untouched.
+        let mut used_item = character.items[position].clone();
+        let effect = used_item.use_charge().map_err(|e| Error::from(e.to_string(lang)))?;
+
+        let mut heal = None;
+        if let ItemEffect::Heal(query) = &effect {
+            let target = target.unwrap_or(character_name.clone());
+            let amount;
+            {
+                let mut rng = RealRng::new();
+                amount = roll_query(&mut rng, query)?.result();
+            }
+            let target_owner = data.active_character_owner(user_id, &target, lang)?;
+            if !data
+                .users
+                .get(&target_owner)
+                .is_some_and(|user_data| user_data.characters.contains_key(&target))
+            {
+                return Err(format!(
+                    "{}: `{}`",
+                    locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+                    &target
+                )
+                .into());
+            }
+            heal = Some((target_owner, target, amount));
+        }
+
+        let user_data = data.users.entry(user_id).or_default();
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+        if used_item.quantity == 0 {
+            character.items.remove(position);
+        } else {
+            character.items[position] = used_item.clone();
+        }
+
+        let mut description = format!(
+            "{}: **{}**",
+            locale_text_by_tag_lang(lang, LocaleTag::ItemUsed),
+            used_item.name
+        );
+        match effect {
+            ItemEffect::Heal(_) => {
+                let (target_owner, target, amount) = heal.unwrap();
+                let patient = data
+                    .users
+                    .get_mut(&target_owner)
+                    .and_then(|user_data| user_data.characters.get_mut(&target))
+                    .ok_or(format!(
+                        "{}: `{}`",
+                        locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+                        &target
+                    ))?;
+                if !patient.dead {
+                    patient.hp.modify(amount);
+                }
+                description = format!(
+                    "{}\n\n`{}` ❤️ **{:+}**\n{}",
+                    description,
+                    patient.name,
+                    amount,
+                    patient.status_hp()
+                );
+            }
+            ItemEffect::BonusDie => {
+                character.bonus_dice += 1;
+                description = format!(
+                    "{}\n🎲 {}",
+                    description,
+                    locale_text_by_tag_lang(lang, LocaleTag::BonusDieReady)
+                );
+            }
+            ItemEffect::Light => {
+                let tag = if used_item.lit {
+                    LocaleTag::LightOn
+                } else {
+                    LocaleTag::LightOff
+                };
+                description = format!("{}\n🔦 {}", description, locale_text_by_tag_lang(lang, tag));
+            }
+        }
+
+        mc = MessageContent {
+            title: format!("`{character_name}`"),
+            description,
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
+
 #[poise::command(prefix_command, slash_command, rename = "remove", aliases("usuń"))]
 async fn remove_cmd(
     ctx: Context<'_>,
@@ -188,11 +424,7 @@ async fn give_cmd(
                 .iter()
                 .any(|(_, user_data)| user_data.characters.iter().any(|(name, _)| **name == character_name))
             {
-                let e = match lang {
-                    LocaleLang::Polski => Err("Nieistniejący odbiorca!".into()),
-                    LocaleLang::English => Err("No receiver!".into()),
-                };
-                return e;
+                return Err(locale_text_by_tag_lang(lang, LocaleTag::NoReceiver).into());
             }
         }
 
@@ -1,19 +1,28 @@
 pub mod attribute;
 pub mod character_cmd;
 pub mod fight;
+pub mod heal;
+pub mod insanity;
 pub mod interaction;
 pub mod item;
+pub mod pulp;
+pub mod roll_stats;
+pub mod sheet;
 pub mod skill;
 pub mod skills;
+pub mod spell;
 pub mod stats;
+pub mod tome;
 pub mod weapon;
+pub mod wizard;
 
 use crate::{
     locale::{LocaleTag, locale_text_by_tag_lang},
-    message::MessageContent,
+    message::{MessageContent, ToEmbed},
     types::*,
 };
 use poise::CreateReply;
+use sheet::{SheetTab, show_sheet};
 
 #[poise::command(prefix_command, slash_command, rename = "status", aliases("status"))]
 pub async fn status_cmd(ctx: Context<'_>) -> Result<(), Error> {
@@ -45,29 +54,7 @@ pub async fn status_cmd(ctx: Context<'_>) -> Result<(), Error> {
 
 #[poise::command(prefix_command, slash_command, rename = "sheet", aliases("karta"))]
 pub async fn sheet_cmd(ctx: Context<'_>) -> Result<(), Error> {
-    let mcs;
-    {
-        let user_id = ctx.author().id.get();
-        let data = ctx.data().data.read().await;
-        let user_data = data.users.get(&user_id).ok_or("No characters.")?;
-        let active = user_data
-            .active_character
-            .clone()
-            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
-        let character = user_data
-            .characters
-            .get(&active)
-            .ok_or(format!("Character not found: `{}`", &active))?;
-
-        mcs = MessageContent::from_character_to_sheet(user_data.lang, character);
-    }
-
-    for mc in mcs {
-        ctx.send(CreateReply::default().content(mc.to_content()).ephemeral(true))
-            .await?;
-    }
-
-    Ok(())
+    show_sheet(ctx, SheetTab::Characteristics).await
 }
 
 #[poise::command(prefix_command, slash_command, rename = "sleep", aliases("śpij"))]
@@ -0,0 +1,148 @@
+use crate::{
+    commands::autocomplete::character::autocomplete_my_skills,
+    locale::{LocaleTag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    types::*,
+};
+use poise::CreateReply;
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "pulp",
+    subcommands("archetype_skill_cmd", "shrug_off_cmd", "avoid_death_cmd")
+)]
+pub async fn pulp_cmd(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "archetype_skill",
+    aliases("umiejętność_archetypu")
+)]
+async fn archetype_skill_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_my_skills"]
+    #[name_localized("pl", "umiejętność")]
+    skill: String,
+    #[name_localized("pl", "punkty")] points: i32,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        let value = character
+            .spend_archetype_points(&skill, points.max(0))
+            .map_err(|e| e.to_string(user_data.lang))?;
+
+        mc = MessageContent {
+            title: format!("`{}`", character.name),
+            description: format!(
+                "{} **{:+}** = **{}**\n{}: **{}**",
+                skill,
+                points.max(0),
+                value,
+                locale_text_by_tag_lang(user_data.lang, LocaleTag::ArchetypePoints),
+                character.archetype_points
+            ),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
+
+#[poise::command(prefix_command, slash_command, rename = "shrug_off", aliases("zignoruj_obrażenia"))]
+async fn shrug_off_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        let healed = character.shrug_off().map_err(|e| e.to_string(user_data.lang))?;
+
+        mc = MessageContent {
+            title: format!(
+                "`{}` {}",
+                character.name,
+                locale_text_by_tag_lang(user_data.lang, LocaleTag::DamageShruggedOff)
+            ),
+            description: format!(
+                "❤️ **{:+}**\n{}\n{}",
+                healed,
+                character.status_hp(),
+                character.status_luck()
+            ),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
+
+#[poise::command(prefix_command, slash_command, rename = "avoid_death", aliases("uniknij_śmierci"))]
+async fn avoid_death_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        let spent = character.avoid_death().map_err(|e| e.to_string(user_data.lang))?;
+
+        mc = MessageContent {
+            title: format!(
+                "`{}` {}",
+                character.name,
+                locale_text_by_tag_lang(user_data.lang, LocaleTag::DeathAvoided)
+            ),
+            description: format!(
+                "🍀 **-{}**\n{}\n{}",
+                spent,
+                character.status_hp(),
+                character.status_luck()
+            ),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
@@ -0,0 +1,105 @@
+use crate::{
+    bot_data::Data,
+    commands::autocomplete::character::autocomplete_any_character,
+    locale::{LocaleTag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    roller::{roll_log::RollRecord, roll_stats::RollStats},
+    types::*,
+};
+use poise::{CreateReply, serenity_prelude::User};
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "stats",
+    aliases("statystyki"),
+    subcommands("character_cmd", "user_cmd", "party_cmd")
+)]
+pub async fn roll_stats_cmd(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Hidden rolls stay out of statistics, so they can't reveal a secret result.
+fn stats_message(ctx: Context<'_>, title: String, filter: impl Fn(&RollRecord) -> bool, data: &Data) -> MessageContent {
+    let lang = data
+        .users
+        .get(&ctx.author().id.get())
+        .map(|u| u.lang)
+        .unwrap_or_default();
+    let stats = RollStats::new(data.rolls.iter().filter(|record| !record.hidden && filter(record)));
+    MessageContent {
+        title: format!(
+            "{}: {}",
+            locale_text_by_tag_lang(lang, LocaleTag::RollStatistics),
+            title
+        ),
+        description: stats.to_string(lang),
+        ..Default::default()
+    }
+}
+
+#[poise::command(prefix_command, slash_command, rename = "character", aliases("postać"))]
+async fn character_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_character"]
+    #[name_localized("pl", "postać")]
+    character: Option<String>,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let data = ctx.data().data.read().await;
+        let character_name = match character {
+            Some(character) => character,
+            None => {
+                let user_data = data.users.get(&user_id).ok_or("No characters.")?;
+                user_data
+                    .active_character
+                    .clone()
+                    .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?
+            }
+        };
+        mc = stats_message(
+            ctx,
+            format!("`{character_name}`"),
+            |record| record.character.as_ref() == Some(&character_name),
+            &data,
+        );
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, rename = "user", aliases("gracz"))]
+async fn user_cmd(ctx: Context<'_>, #[name_localized("pl", "gracz")] user: Option<User>) -> Result<(), Error> {
+    let mc;
+    {
+        let user = user.as_ref().unwrap_or(ctx.author());
+        let data = ctx.data().data.read().await;
+        mc = stats_message(ctx, user.name.clone(), |record| record.user_id == user.id.get(), &data);
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+    Ok(())
+}
+
+/// Rolls of every character, without plain rolls that belong to no character.
+#[poise::command(prefix_command, slash_command, rename = "party", aliases("drużyna"))]
+async fn party_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let data = ctx.data().data.read().await;
+        let lang = data.users.get(&user_id).map(|u| u.lang).unwrap_or_default();
+        mc = stats_message(
+            ctx,
+            locale_text_by_tag_lang(lang, LocaleTag::Party),
+            |record| record.character.is_some(),
+            &data,
+        );
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+    Ok(())
+}
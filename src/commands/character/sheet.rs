@@ -0,0 +1,404 @@
+use crate::{
+    character::Character,
+    commands::character::skill::skill_impl::skill_impl_str,
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    types::*,
+};
+use itertools::Itertools;
+use poise::{
+    CreateReply,
+    serenity_prelude::{
+        ButtonStyle, ComponentInteractionCollector, ComponentInteractionDataKind, CreateActionRow, CreateButton,
+        CreateInteractionResponse, CreateInteractionResponseMessage, CreateSelectMenu, CreateSelectMenuKind,
+        CreateSelectMenuOption,
+    },
+};
+use std::time::Duration;
+
+/// With the tab menu and page buttons, three rows of five roll buttons still fit in a message.
+const SKILLS_PER_PAGE: usize = 15;
+const SKILL_BUTTONS_PER_ROW: usize = 5;
+const SHEET_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SheetTab {
+    Characteristics,
+    Skills,
+    Combat,
+    Inventory,
+    Backstory,
+    Pulp,
+}
+
+impl SheetTab {
+    const ALL: [SheetTab; 6] = [
+        SheetTab::Characteristics,
+        SheetTab::Skills,
+        SheetTab::Combat,
+        SheetTab::Inventory,
+        SheetTab::Backstory,
+        SheetTab::Pulp,
+    ];
+
+    fn id(self) -> &'static str {
+        match self {
+            SheetTab::Characteristics => "characteristics",
+            SheetTab::Skills => "skills",
+            SheetTab::Combat => "combat",
+            SheetTab::Inventory => "inventory",
+            SheetTab::Backstory => "backstory",
+            SheetTab::Pulp => "pulp",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|tab| tab.id() == id)
+    }
+
+    fn to_locale_tag(self) -> LocaleTag {
+        match self {
+            SheetTab::Characteristics => LocaleTag::SheetCharacteristics,
+            SheetTab::Skills => LocaleTag::SheetSkills,
+            SheetTab::Combat => LocaleTag::SheetCombat,
+            SheetTab::Inventory => LocaleTag::SheetInventory,
+            SheetTab::Backstory => LocaleTag::SheetBackstory,
+            SheetTab::Pulp => LocaleTag::SheetPulp,
+        }
+    }
+
+    /// The Pulp tab is only offered to Pulp heroes.
+    fn tabs(character: &Character) -> Vec<SheetTab> {
+        Self::ALL
+            .into_iter()
+            .filter(|tab| *tab != SheetTab::Pulp || character.pulp_archetype.is_some())
+            .collect()
+    }
+}
+
+/// Skill names in the reader's language, in the order of the sheet.
+fn sorted_skill_names(lang: LocaleLang, character: &Character) -> Vec<String> {
+    character
+        .skills
+        .values()
+        .map(|skill| skill.name.get(lang))
+        .sorted()
+        .collect()
+}
+
+fn page_count(character: &Character) -> usize {
+    character.skills.len().div_ceil(SKILLS_PER_PAGE).max(1)
+}
+
+fn skills_page(lang: LocaleLang, character: &Character, page: usize) -> Vec<String> {
+    sorted_skill_names(lang, character)
+        .into_iter()
+        .skip(page * SKILLS_PER_PAGE)
+        .take(SKILLS_PER_PAGE)
+        .collect()
+}
+
+fn skills_content(lang: LocaleLang, character: &Character, page: usize) -> String {
+    let names = skills_page(lang, character, page);
+    let skills: SkillMap = character
+        .skills
+        .iter()
+        .filter(|(_, skill)| names.contains(&skill.name.get(lang)))
+        .map(|(key, skill)| (key.clone(), skill.clone()))
+        .collect();
+    MessageContent::from_character_skills(lang, &skills)
+        .into_iter()
+        .map(|mc| mc.description)
+        .join("\n")
+}
+
+fn combat_content(lang: LocaleLang, character: &Character) -> String {
+    format!(
+        "{}\n{}: **{}** {}: **{}** {}: **{}**\n\n{}",
+        character.status_hp(),
+        locale_text_by_tag_lang(lang, LocaleTag::Db),
+        character.damage_modifier(lang),
+        locale_text_by_tag_lang(lang, LocaleTag::Build),
+        character.build,
+        locale_text_by_tag_lang(lang, LocaleTag::Move),
+        character.move_rate,
+        MessageContent::from_character_to_weapons(lang, character).description
+    )
+}
+
+fn inventory_content(lang: LocaleLang, character: &Character) -> String {
+    let mut out = MessageContent::from_character_to_items(lang, character).description;
+    if !character.tomes.is_empty() || !character.spells.is_empty() {
+        out = format!(
+            "{}\n\n{}",
+            out,
+            MessageContent::from_character_to_tomes(lang, character).description
+        );
+    }
+    out
+}
+
+fn backstory_content(lang: LocaleLang, character: &Character) -> String {
+    let mut lines = vec![];
+    if let Some(occupation) = &character.occupation {
+        lines.push(format!(
+            "{}: **{}**",
+            locale_text_by_tag_lang(lang, LocaleTag::Occupation),
+            occupation
+        ));
+    }
+    lines.push(character.backstory.clone().unwrap_or("-".into()));
+    if !character.insanity_episodes.is_empty() {
+        lines.push(MessageContent::from_character_to_insanity(lang, character).description);
+    }
+    lines.join("\n\n")
+}
+
+fn pulp_content(lang: LocaleLang, character: &Character) -> String {
+    let mut lines = vec![];
+    if let Some(archetype) = &character.pulp_archetype {
+        lines.push(format!(
+            "{}: **{}**",
+            locale_text_by_tag_lang(lang, LocaleTag::PulpArchetype),
+            archetype.get(lang)
+        ));
+    }
+    if let Some(core_characteristic) = &character.pulp_core_characteristic {
+        lines.push(format!(
+            "{}: **{}**",
+            locale_text_by_tag_lang(lang, LocaleTag::CoreCharacteristic),
+            core_characteristic.get(lang)
+        ));
+    }
+    lines.push(format!(
+        "{}: **{}**",
+        locale_text_by_tag_lang(lang, LocaleTag::ArchetypePoints),
+        character.archetype_points
+    ));
+    lines.push(format!(
+        "{}: {}",
+        locale_text_by_tag_lang(lang, LocaleTag::PulpTalents),
+        character
+            .pulp_talents
+            .iter()
+            .map(|t| format!("**{}**", t.get(lang)))
+            .join(", ")
+    ));
+    lines.join("\n")
+}
+
+fn sheet_content(lang: LocaleLang, character: &Character, tab: SheetTab, page: usize) -> MessageContent {
+    let description = match tab {
+        SheetTab::Characteristics => MessageContent::from_character_to_attributes(lang, character).description,
+        SheetTab::Skills => skills_content(lang, character, page),
+        SheetTab::Combat => combat_content(lang, character),
+        SheetTab::Inventory => inventory_content(lang, character),
+        SheetTab::Backstory => backstory_content(lang, character),
+        SheetTab::Pulp => pulp_content(lang, character),
+    };
+    MessageContent {
+        title: format!(
+            "`{}`: {}",
+            character.name,
+            locale_text_by_tag_lang(lang, tab.to_locale_tag())
+        ),
+        description,
+        ..Default::default()
+    }
+}
+
+fn sheet_components(
+    id: &str,
+    lang: LocaleLang,
+    character: &Character,
+    tab: SheetTab,
+    page: usize,
+) -> Vec<CreateActionRow> {
+    let options = SheetTab::tabs(character)
+        .into_iter()
+        .map(|t| {
+            CreateSelectMenuOption::new(locale_text_by_tag_lang(lang, t.to_locale_tag()), t.id())
+                .default_selection(t == tab)
+        })
+        .collect();
+    let mut rows = vec![CreateActionRow::SelectMenu(CreateSelectMenu::new(
+        format!("{id}:tab"),
+        CreateSelectMenuKind::String { options },
+    ))];
+    if tab != SheetTab::Skills {
+        return rows;
+    }
+
+    let pages = page_count(character);
+    if pages > 1 {
+        rows.push(CreateActionRow::Buttons(vec![
+            CreateButton::new(format!("{id}:previous"))
+                .label("◀️")
+                .style(ButtonStyle::Secondary)
+                .disabled(page == 0),
+            CreateButton::new(format!("{id}:page"))
+                .label(format!("{}/{}", page + 1, pages))
+                .style(ButtonStyle::Secondary)
+                .disabled(true),
+            CreateButton::new(format!("{id}:next"))
+                .label("▶️")
+                .style(ButtonStyle::Secondary)
+                .disabled(page + 1 >= pages),
+        ]));
+    }
+    let roll_buttons: Vec<_> = skills_page(lang, character, page)
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| {
+            CreateButton::new(format!("{id}:roll:{i}"))
+                .label(format!("🎲 {}", name.chars().take(75).collect::<String>()))
+                .style(ButtonStyle::Primary)
+        })
+        .collect();
+    rows.extend(
+        roll_buttons
+            .chunks(SKILL_BUTTONS_PER_ROW)
+            .map(|row| CreateActionRow::Buttons(row.to_vec())),
+    );
+    rows
+}
+
+struct SheetView {
+    mc: MessageContent,
+    components: Vec<CreateActionRow>,
+    skills: Vec<String>,
+    page: usize,
+}
+
+async fn sheet_view(ctx: Context<'_>, id: &str, tab: SheetTab, page: usize) -> Result<SheetView, Error> {
+    let data = ctx.data().data.read().await;
+    let user_data = data.users.get(&ctx.author().id.get()).ok_or("No characters.")?;
+    let active = user_data
+        .active_character
+        .clone()
+        .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
+    let character = user_data.characters.get(&active).ok_or(format!(
+        "{}: `{}`",
+        locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
+        &active
+    ))?;
+
+    let lang = user_data.lang;
+    let page = page.min(page_count(character) - 1);
+    Ok(SheetView {
+        mc: sheet_content(lang, character, tab, page),
+        components: sheet_components(id, lang, character, tab, page),
+        skills: skills_page(lang, character, page),
+        page,
+    })
+}
+
+/// Interactive sheet of the active character: a tab menu, skill pages and a roll button for every skill.
+pub async fn show_sheet(ctx: Context<'_>, mut tab: SheetTab) -> Result<(), Error> {
+    let id = ctx.id().to_string();
+    let mut view = sheet_view(ctx, &id, tab, 0).await?;
+    let reply = ctx
+        .send(
+            CreateReply::default()
+                .embed(view.mc.to_embed())
+                .components(view.components.clone())
+                .ephemeral(true),
+        )
+        .await?;
+
+    while let Some(press) = ComponentInteractionCollector::new(ctx)
+        .filter({
+            let prefix = format!("{id}:");
+            move |press| press.data.custom_id.starts_with(&prefix)
+        })
+        .timeout(SHEET_TIMEOUT)
+        .await
+    {
+        let action = &press.data.custom_id[id.len() + 1..];
+        let mut page = view.page;
+        match action {
+            "tab" => {
+                if let ComponentInteractionDataKind::StringSelect { values } = &press.data.kind {
+                    tab = values.first().and_then(|value| SheetTab::from_id(value)).unwrap_or(tab);
+                    page = 0;
+                }
+            }
+            "previous" => page = page.saturating_sub(1),
+            "next" => page += 1,
+            _ => {
+                if let Some(skill) = action
+                    .strip_prefix("roll:")
+                    .and_then(|i| i.parse::<usize>().ok())
+                    .and_then(|i| view.skills.get(i).cloned())
+                {
+                    press
+                        .create_response(ctx, CreateInteractionResponse::Acknowledge)
+                        .await?;
+                    skill_impl_str(ctx, &skill, &None).await?;
+                    continue;
+                }
+            }
+        }
+
+        view = sheet_view(ctx, &id, tab, page).await?;
+        press
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .embed(view.mc.to_embed())
+                        .components(view.components.clone()),
+                ),
+            )
+            .await?;
+    }
+
+    reply
+        .edit(ctx, CreateReply::default().embed(view.mc.to_embed()).components(vec![]))
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{character::Attributes, ruleset::RulesetKind};
+
+    fn character(pulp: bool) -> Character {
+        let archetype = pulp.then(|| crate::locale::LocaleEntry::new_single_lang("Adventurer"));
+        Character::new(
+            "Harvey",
+            &None,
+            Attributes::new(50, 50, 50, 50, 50, 50, 50, 50).unwrap(),
+            50,
+            archetype,
+            RulesetKind::Classic,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn skill_pages_cover_every_skill() {
+        let lang = LocaleLang::default();
+        let character = character(false);
+        let names: Vec<_> = (0..page_count(&character))
+            .flat_map(|page| skills_page(lang, &character, page))
+            .collect();
+        assert_eq!(names, sorted_skill_names(lang, &character));
+        assert!((0..page_count(&character)).all(|page| skills_page(lang, &character, page).len() <= SKILLS_PER_PAGE));
+    }
+
+    #[test]
+    fn pulp_tab_only_for_pulp_heroes() {
+        assert!(!SheetTab::tabs(&character(false)).contains(&SheetTab::Pulp));
+        assert!(SheetTab::tabs(&character(true)).contains(&SheetTab::Pulp));
+    }
+
+    #[test]
+    fn components_fit_in_a_message() {
+        let character = character(false);
+        for page in 0..page_count(&character) {
+            assert!(sheet_components("1", LocaleLang::default(), &character, SheetTab::Skills, page).len() <= 5);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     commands::autocomplete::*,
     locale::{LocaleTag, locale_text_by_tag_lang},
-    message::MessageContent,
+    message::{MessageContent, ToEmbed},
     types::*,
 };
 use poise::CreateReply;
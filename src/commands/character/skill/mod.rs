@@ -2,9 +2,15 @@ pub mod add;
 pub mod skill_impl;
 use crate::{
     character::Skill,
-    commands::{autocomplete::character::*, character::skill::skill_impl::skill_impl_str},
-    locale::{LocaleLang, LocaleTag, locale_tag_by_str, locale_text_by_tag_lang},
-    message::MessageContent,
+    commands::{
+        autocomplete::character::*,
+        character::{
+            sheet::{SheetTab, show_sheet},
+            skill::skill_impl::skill_impl_str,
+        },
+    },
+    locale::{LocaleTag, locale_dice, locale_tag_by_str, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
     roller::{dice_rng::RealRng, improve_roll::improve_skill, roll::roll_query, success_level::SuccessLevel},
     types::{Context, Error},
 };
@@ -34,30 +40,7 @@ pub async fn skill_cmd(_: Context<'_>) -> Result<(), Error> {
 
 #[poise::command(prefix_command, slash_command, rename = "list", aliases("lista"))]
 async fn list_cmd(ctx: Context<'_>) -> Result<(), Error> {
-    let mcs;
-    {
-        let user_id = ctx.author().id.get();
-        let mut data = ctx.data().data.write().await;
-        let user_data = data.users.entry(user_id).or_default();
-        let character_name = user_data
-            .active_character
-            .clone()
-            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
-        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
-            "{}: `{}`",
-            locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
-            &character_name
-        ))?;
-
-        mcs = MessageContent::from_character_skills(user_data.lang, &character.skills);
-    }
-
-    for mc in mcs {
-        ctx.send(CreateReply::default().content(mc.to_content()).ephemeral(true))
-            .await?;
-    }
-
-    Ok(())
+    show_sheet(ctx, SheetTab::Skills).await
 }
 
 #[poise::command(prefix_command, slash_command, rename = "check", aliases("test"))]
@@ -143,9 +126,7 @@ async fn change_cmd(
             &character_name
         ))?;
 
-        if user_data.lang == LocaleLang::Polski {
-            query = query.replace('d', "k");
-        }
+        query = locale_dice(user_data.lang, &query);
 
         let roll_result;
         {
@@ -243,6 +224,7 @@ async fn improve_cmd(
     {
         let user_id = ctx.author().id.get();
         let mut data = ctx.data().data.write().await;
+        let settings = data.settings(ctx.guild_id().map(|guild_id| guild_id.get()));
         let user_data = data.users.entry(user_id).or_default();
         let character_name = user_data
             .active_character
@@ -254,6 +236,8 @@ async fn improve_cmd(
             &character_name
         ))?;
 
+        let rules = character.ruleset().ruleset();
+        let default_improve_dice = settings.improve_dice(character.ruleset()).to_string();
         let skills: Vec<(&String, &mut Skill)> = character
             .skills
             .iter_mut()
@@ -270,15 +254,20 @@ async fn improve_cmd(
         } else {
             for (name, skill) in skills {
                 if skill.improvable && skill.to_improve {
-                    let improve_result = improve_skill(skill.value);
-                    let mut mc = MessageContent::from_improve(user_data.lang, &improve_result);
+                    let mut mc = MessageContent::default();
+                    let mut improved = true;
+                    if rules.improvement_check() {
+                        let improve_result = improve_skill(skill.value);
+                        mc = MessageContent::from_improve(user_data.lang, &improve_result);
+                        improved = improve_result.success_level == SuccessLevel::Success;
+                    }
                     skill.to_improve = false;
 
-                    let mut improve_dice = improve_dice.clone().unwrap_or("d6".into()); //TODO Home Rules; rule-wise: d10
-                    if user_data.lang == LocaleLang::Polski {
-                        improve_dice = improve_dice.replace('d', "k");
-                    }
-                    if improve_result.success_level == SuccessLevel::Success {
+                    let improve_dice = locale_dice(
+                        user_data.lang,
+                        &improve_dice.clone().unwrap_or(default_improve_dice.clone()),
+                    );
+                    if improved {
                         let res;
                         {
                             let mut rng = RealRng::new();
@@ -1,13 +1,15 @@
+use crate::roller::croll::croll_impl;
 use poise::{CreateReply, serenity_prelude::CreateActionRow};
 
 use crate::{
-    commands::{
-        basic::croll_impl,
-        character::interaction::{add_push_roll_button, add_spend_luck_buttons, handle_interaction, ok_button},
+    character::{TALENT_LUCK_COST, marks_skill},
+    commands::character::interaction::{
+        MAX_BUTTONS, add_push_roll_button, add_spend_luck_buttons, add_talent_reroll_button, handle_interaction,
+        ok_button,
     },
     locale::{LocaleLang, LocaleTag, locale_tag_by_str, locale_text_by_tag_lang},
-    message::MessageContent,
-    roller::{modifier_dice::ModifierDiceType, success_level::SuccessLevel},
+    message::{MessageContent, ToEmbed},
+    roller::{roll_log::RollRecord, success_level::SuccessLevel},
     types::{Context, Error},
 };
 
@@ -20,6 +22,7 @@ pub async fn skill_impl_str(ctx: Context<'_>, skill_name: &str, modifier_dice: &
     let skill_improvable;
     let skill_already_marked;
     let character_name;
+    let mut luck_spent = 0;
     let mut buttons = vec![];
     {
         user_id = ctx.author().id.get();
@@ -35,7 +38,7 @@ pub async fn skill_impl_str(ctx: Context<'_>, skill_name: &str, modifier_dice: &
             locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
             &character_name
         ))?;
-        let skill = character.get_mut_skill(skill_name).ok_or_else(|| {
+        let skill = character.get_skill(skill_name).ok_or_else(|| {
             format!(
                 "{}: {}",
                 locale_text_by_tag_lang(user_data.lang, LocaleTag::NoSuchSkill),
@@ -44,28 +47,33 @@ pub async fn skill_impl_str(ctx: Context<'_>, skill_name: &str, modifier_dice: &
         })?;
         skill_improvable = skill.improvable;
         skill_already_marked = skill.to_improve;
-        croll_query = format!("{}{}", skill.value, modifier_dice.unwrap_or_default());
-        croll_result = croll_impl(&croll_query)?;
+        let pending_bonus_dice =
+            "+".repeat((character.bonus_dice.max(0) + character.talent_bonus_dice(&skill.name)) as usize);
+        croll_query = format!(
+            "{}{}{}",
+            skill.value,
+            modifier_dice.unwrap_or_default(),
+            pending_bonus_dice
+        );
+        croll_result = croll_impl(&croll_query, character.ruleset())?;
+        character.bonus_dice = 0;
         if croll_result.success_level < SuccessLevel::ExtremeSuccess
             && croll_result.success_level != SuccessLevel::CriticalFailure
         {
             buttons.push(ok_button());
             buttons.extend(add_push_roll_button(&croll_result, user_lang));
+            buttons.extend(add_talent_reroll_button(
+                &croll_result,
+                character,
+                &skill.name,
+                user_lang,
+            ));
             buttons.extend(add_spend_luck_buttons(&croll_result, character, user_lang));
+            buttons.truncate(MAX_BUTTONS);
         }
     }
 
-    let mut mark_to_improve = false;
-    if skill_improvable && croll_result.success_level >= SuccessLevel::Success {
-        match &croll_result.modifier_dice {
-            None => mark_to_improve = true,
-            Some(modifier_dice) => {
-                if modifier_dice.dice_type == ModifierDiceType::Penalty {
-                    mark_to_improve = true
-                }
-            }
-        }
-    }
+    let mut mark_to_improve = skill_improvable && marks_skill(&croll_result);
 
     mc = MessageContent::from_croll_result(user_lang, &croll_result, false, true)
         .with_skill_name(skill_name)
@@ -110,21 +118,11 @@ pub async fn skill_impl_str(ctx: Context<'_>, skill_name: &str, modifier_dice: &
     {
         match tag {
             LocaleTag::PushRoll => {
-                croll_result = croll_impl(&croll_query)?;
+                croll_result = croll_impl(&croll_query, croll_result.ruleset)?;
                 mc = MessageContent::from_croll_result(user_lang, &croll_result, false, true)
                     .with_skill_name(skill_name)
                     .with_character_name(&character_name);
-                mark_to_improve = false;
-                if skill_improvable && croll_result.success_level >= SuccessLevel::Success {
-                    match &croll_result.modifier_dice {
-                        None => mark_to_improve = true,
-                        Some(modifier_dice) => {
-                            if modifier_dice.dice_type == ModifierDiceType::Penalty {
-                                mark_to_improve = true
-                            }
-                        }
-                    }
-                }
+                mark_to_improve = skill_improvable && marks_skill(&croll_result);
                 if croll_result.success_level < SuccessLevel::Success {
                     mc.description = format!(
                         "{}\n\n{}: ***{}***",
@@ -151,12 +149,42 @@ pub async fn skill_impl_str(ctx: Context<'_>, skill_name: &str, modifier_dice: &
                     .edit(ctx, CreateReply::default().embed(mc.to_embed()).components(vec![]))
                     .await?;
             }
+            LocaleTag::TalentReRoll => {
+                mark_to_improve = false;
+                let remaining_luck;
+                {
+                    let mut data = ctx.data().data.write().await;
+                    let user_data = data.users.entry(user_id).or_default();
+                    let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+                        "{}: `{}`",
+                        locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
+                        &character_name
+                    ))?;
+                    character.spend_talent_luck().map_err(|e| e.to_string(user_data.lang))?;
+                    remaining_luck = character.luck.current;
+                }
+                luck_spent = TALENT_LUCK_COST;
+                croll_result = croll_impl(&croll_query, croll_result.ruleset)?;
+                mc = MessageContent::from_croll_result(user_lang, &croll_result, false, true)
+                    .with_skill_name(skill_name)
+                    .with_character_name(&character_name);
+                mc.title = format!("{} (🔁)", mc.title);
+                mc.description = format!(
+                    "{}\n\n{}: 🍀-{} ({})",
+                    mc.description,
+                    locale_text_by_tag_lang(user_lang, LocaleTag::TalentReRoll),
+                    TALENT_LUCK_COST,
+                    remaining_luck
+                );
+                reply
+                    .edit(ctx, CreateReply::default().embed(mc.to_embed()).components(vec![]))
+                    .await?;
+            }
             LocaleTag::Success | LocaleTag::HardSuccess | LocaleTag::ExtremeSuccess => {
                 mark_to_improve = false;
                 let sl = SuccessLevel::from_tag(tag)?;
                 let luck = sl.delta(croll_result.result(), croll_result.threshold);
                 let remaining_luck;
-                let mut croll_result = croll_result;
                 croll_result.set_result(croll_result.result() - luck);
                 croll_result.success_level = sl;
                 {
@@ -170,6 +198,7 @@ pub async fn skill_impl_str(ctx: Context<'_>, skill_name: &str, modifier_dice: &
                     character.luck.modify(-luck);
                     remaining_luck = character.luck.current;
                 }
+                luck_spent = luck;
                 let mut mc = MessageContent::from_croll_result(user_lang, &croll_result, false, true)
                     .with_skill_name(skill_name)
                     .with_character_name(&character_name);
@@ -202,6 +231,13 @@ pub async fn skill_impl_str(ctx: Context<'_>, skill_name: &str, modifier_dice: &
         skill.to_improve |= mark_to_improve;
     }
 
+    ctx.data().data.write().await.record_roll(
+        RollRecord::new(user_id, &croll_result)
+            .with_character(&character_name)
+            .with_skill(skill_name)
+            .with_luck_spent(luck_spent),
+    );
+
     ctx.data().data.write().await.save().await
 }
 
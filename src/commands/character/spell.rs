@@ -0,0 +1,141 @@
+use crate::{
+    commands::autocomplete::*,
+    locale::{LocaleTag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    types::*,
+    utils::parse_duration_hours,
+};
+use poise::CreateReply;
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "spell",
+    aliases("zaklęcie"),
+    subcommands("list_cmd", "learn_cmd", "cast_cmd")
+)]
+pub async fn spell_cmd(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, rename = "list", aliases("lista"))]
+async fn list_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+        mc = MessageContent::from_character_to_tomes(user_data.lang, character);
+    }
+
+    ctx.send(CreateReply::default().content(mc.to_content()).ephemeral(true))
+        .await?;
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, rename = "learn", aliases("naucz"))]
+async fn learn_cmd(ctx: Context<'_>, #[name_localized("pl", "nazwa")] name: String) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        if !character.spells.iter().any(|s| s.eq_ignore_ascii_case(&name)) {
+            character.spells.push(name.clone());
+        }
+
+        mc = MessageContent {
+            title: format!("`{}`", character.name),
+            description: format!(
+                "{}: **{}**",
+                locale_text_by_tag_lang(user_data.lang, LocaleTag::SpellsLearned),
+                name
+            ),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
+
+#[poise::command(prefix_command, slash_command, rename = "cast", aliases("rzuć"))]
+async fn cast_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_my_spells"]
+    #[name_localized("pl", "nazwa")]
+    name: String,
+    #[name_localized("pl", "koszt_pm")] cost: i32,
+    #[name_localized("pl", "czas_trwania")] duration: Option<String>,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let lang = user_data.lang;
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        let duration_hours = match &duration {
+            Some(duration) => Some(parse_duration_hours(duration).ok_or(format!(
+                "{}: `{}`",
+                locale_text_by_tag_lang(lang, LocaleTag::InvalidTime),
+                duration
+            ))?),
+            None => None,
+        };
+
+        let spell = character
+            .cast_spell(&name, cost, duration_hours)
+            .map_err(|e| Error::from(e.to_string(lang)))?;
+
+        let mut description = format!(
+            "{}: ✨ **{}**\n🪄 **{:+}**\n{}",
+            locale_text_by_tag_lang(lang, LocaleTag::SpellCast),
+            spell,
+            -cost,
+            character.status_magic()
+        );
+        if let Some(hours) = duration_hours {
+            description = format!("{description}\n⏳ {hours}h");
+        }
+
+        mc = MessageContent {
+            title: format!("`{}`", character.name),
+            description,
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
@@ -1,81 +1,17 @@
+use crate::roller::croll::croll_impl;
 use crate::{
-    bot_data::*,
-    character::Character,
-    commands::basic::croll_impl,
+    character::{hp_impl, luck_impl, san_impl},
+    commands::character::insanity::int_check_impl,
     locale::*,
-    message::MessageContent,
-    roller::{dice_rng::RealRng, improve_roll::improve_skill, roll::roll_query, success_level::SuccessLevel},
+    message::{MessageContent, ToEmbed},
+    roller::{
+        dice_rng::RealRng, improve_roll::improve_skill, roll::roll_query, roll_log::RollRecord,
+        success_level::SuccessLevel,
+    },
     types::*,
 };
 use poise::CreateReply;
 
-pub fn hp_impl(character: &mut Character, delta: &str, lang: LocaleLang) -> Result<MessageContent, Error> {
-    let roll_result;
-    {
-        let mut rng = RealRng::new();
-        roll_result = roll_query(&mut rng, delta)?;
-    }
-    let delta_res = roll_result.result_real();
-
-    character.hp.modify(delta_res);
-
-    let mut additional_desc = String::new();
-    if delta_res < -(character.hp.max) {
-        character.dead = true;
-        character.major_wound = false;
-        additional_desc = format!("\n\n**{}**", locale_text_by_tag_lang(lang, LocaleTag::DeathInevitable));
-    } else {
-        if delta_res <= -(character.hp.max / 2) {
-            character.major_wound = true;
-            additional_desc = format!("\n\n**{}**", locale_text_by_tag_lang(lang, LocaleTag::MajorWound));
-            if character.hp.current > 0 {
-                additional_desc = format!(
-                    "{}\n{}\n{}",
-                    additional_desc,
-                    locale_text_by_tag_lang(lang, LocaleTag::YouFell),
-                    locale_text_by_tag_lang(lang, LocaleTag::RollConCheckBlackOut)
-                )
-                .to_string();
-            }
-        }
-
-        if character.hp.current == 0 {
-            if character.major_wound {
-                additional_desc = format!(
-                    "{}\n**{}**\n{}\n{}\n{}",
-                    additional_desc,
-                    locale_text_by_tag_lang(lang, LocaleTag::Agony),
-                    locale_text_by_tag_lang(lang, LocaleTag::YouFell),
-                    locale_text_by_tag_lang(lang, LocaleTag::YouBlackOut),
-                    locale_text_by_tag_lang(lang, LocaleTag::RollConCheckDie)
-                );
-            } else {
-                additional_desc = format!(
-                    "{}\n**{}**\n{}\n{}",
-                    additional_desc,
-                    locale_text_by_tag_lang(lang, LocaleTag::KnockOut),
-                    locale_text_by_tag_lang(lang, LocaleTag::YouFell),
-                    locale_text_by_tag_lang(lang, LocaleTag::YouBlackOut),
-                );
-            }
-        }
-    }
-
-    let mc = MessageContent {
-        title: format!("`{}`", character.name),
-        description: format!(
-            "❤️ **{:+}** ({:+})\n{}{}",
-            delta_res,
-            delta,
-            character.status_hp(),
-            additional_desc
-        ),
-        ..Default::default()
-    };
-
-    Ok(mc)
-}
-
 #[poise::command(prefix_command, slash_command, rename = "hp", aliases("pw"))]
 pub async fn hp_cmd(ctx: Context<'_>, #[name_localized("pl", "zmiana")] delta: String) -> Result<(), Error> {
     let mc;
@@ -101,62 +37,24 @@ pub async fn hp_cmd(ctx: Context<'_>, #[name_localized("pl", "zmiana")] delta: S
     ctx.data().data.write().await.save().await
 }
 
-pub fn san_impl(character: &mut Character, delta: &str, lang: LocaleLang) -> Result<MessageContent, Error> {
-    let roll_result;
-    {
-        let mut rng = RealRng::new();
-        roll_result = roll_query(&mut rng, delta)?;
-    }
-    let delta_res = roll_result.result_real();
-
-    character.sanity.modify(delta_res);
-
-    let mut additional_desc = String::new();
-    if character.sanity.current == 0 {
-        character.insane = true;
-        additional_desc = format!("\n\n**{}**", locale_text_by_tag_lang(lang, LocaleTag::MindShattered));
-    } else if character.fragile_mind && delta_res < 0 {
-        additional_desc = format!("\n\n**{}**", locale_text_by_tag_lang(lang, LocaleTag::TempInsanity));
-    } else if delta_res <= -5 {
-        additional_desc = format!(
-            "\n\n**{}**\n{}",
-            locale_text_by_tag_lang(lang, LocaleTag::TempInsanityThreat),
-            locale_text_by_tag_lang(lang, LocaleTag::RollIntCheck)
-        );
-    }
-
-    if (character.sanity.current as f32 / character.sanity.initial as f32) < 0.8 {
-        character.fragile_mind = true;
-        additional_desc = format!("\n\n**{}**", locale_text_by_tag_lang(lang, LocaleTag::IndefInsanity));
-    }
-
-    let mc = MessageContent {
-        title: format!("`{}`", character.name),
-        description: format!(
-            "🧠 **{:+}** ({:+})\n{}{}",
-            delta_res,
-            delta,
-            character.status_sanity(),
-            additional_desc
-        ),
-        ..Default::default()
-    };
-
-    Ok(mc)
-}
-
 #[poise::command(prefix_command, slash_command, rename = "sanity", aliases("poczytalność"))]
 pub async fn sanity_cmd(
     ctx: Context<'_>,
     #[name_localized("pl", "zmiana")] delta: Option<String>,
 ) -> Result<(), Error> {
     let mc;
+    let lang;
+    let character_name;
+    let mut int_check = false;
+    let bout_kind;
 
+    let user_id = ctx.author().id.get();
     {
-        let user_id = ctx.author().id.get();
         let mut data = ctx.data().data.write().await;
+        bout_kind = data.bout_kind();
         let user_data = data.users.entry(user_id).or_default();
-        let character_name = user_data
+        lang = user_data.lang;
+        character_name = user_data
             .active_character
             .clone()
             .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
@@ -166,18 +64,31 @@ pub async fn sanity_cmd(
             &character_name
         ))?;
 
+        let mut roll_record = None;
         if let Some(delta) = &delta {
-            mc = san_impl(character, delta, user_data.lang)?;
+            (mc, int_check) = san_impl(character, delta, user_data.lang, bout_kind)?;
         } else {
             let sanity_str = locale_text_by_tag_lang(user_data.lang, LocaleTag::Sanity);
-            let sanity_result = croll_impl(&character.sanity.current.to_string())?;
+            let sanity_result = croll_impl(&character.sanity.current.to_string(), character.ruleset())?;
             mc = MessageContent::from_croll_result(user_data.lang, &sanity_result, false, true)
                 .with_skill_name(&sanity_str)
                 .with_character_name(&character_name);
+            roll_record = Some(
+                RollRecord::new(user_id, &sanity_result)
+                    .with_character(&character_name)
+                    .with_skill(&sanity_str),
+            );
+        }
+        if let Some(roll_record) = roll_record {
+            data.record_roll(roll_record);
         }
     }
 
-    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+    if int_check {
+        int_check_impl(ctx, mc, user_id, &character_name, lang, bout_kind).await?;
+    } else {
+        ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+    }
 
     ctx.data().data.write().await.save().await
 }
@@ -188,11 +99,12 @@ pub async fn luck_cmd(
     #[name_localized("pl", "dodatkowe_kości")] modifier_dice: Option<String>,
     #[name_localized("pl", "zmiana")] delta: Option<String>,
 ) -> Result<(), Error> {
-    let mut mc = MessageContent::default();
+    let mut mc;
 
     {
         let user_id = ctx.author().id.get();
         let mut data = ctx.data().data.write().await;
+        let mut roll_record = None;
         let user_data = data.users.entry(user_id).or_default();
         let character_name = user_data
             .active_character
@@ -205,21 +117,7 @@ pub async fn luck_cmd(
         ))?;
 
         if let Some(delta) = &delta {
-            let roll_result;
-            {
-                let mut rng = RealRng::new();
-                roll_result = roll_query(&mut rng, delta)?;
-            }
-            let delta_res = roll_result.result_real();
-
-            if delta_res < -character.luck.current {
-                return Err(locale_text_by_tag_lang(user_data.lang, LocaleTag::CantSpendLuck).into());
-            }
-
-            character.luck.modify(delta_res);
-
-            mc.title = format!("`{}`", character.name);
-            mc.description = format!("🍀 **{:+}** ({:+})\n{}", delta_res, delta, character.status_luck());
+            mc = luck_impl(character, delta, user_data.lang)?;
         } else {
             let luck_str = locale_text_by_tag_lang(user_data.lang, LocaleTag::Luck);
             let luck_query = format!(
@@ -227,17 +125,19 @@ pub async fn luck_cmd(
                 &character.luck.current.to_string(),
                 modifier_dice.unwrap_or_default()
             );
-            let luck_result = croll_impl(&luck_query)?;
+            let luck_result = croll_impl(&luck_query, character.ruleset())?;
 
             mc = MessageContent::from_croll_result(user_data.lang, &luck_result, false, true)
                 .with_skill_name(&luck_str)
                 .with_character_name(&character_name);
+            roll_record = Some(
+                RollRecord::new(user_id, &luck_result)
+                    .with_character(&character_name)
+                    .with_skill(&luck_str),
+            );
 
             if luck_result.success_level == SuccessLevel::CriticalSuccess {
-                let mut improve_dice = "d10".to_string();
-                if user_data.lang == LocaleLang::Polski {
-                    improve_dice = improve_dice.replace('d', "k");
-                }
+                let improve_dice = locale_dice(user_data.lang, "d10");
 
                 let res;
                 {
@@ -262,6 +162,9 @@ pub async fn luck_cmd(
                 character.set_attribute("power", new_power);
             }
         }
+        if let Some(roll_record) = roll_record {
+            data.record_roll(roll_record);
+        }
     }
 
     ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
@@ -290,17 +193,16 @@ pub async fn improve_luck_cmd(ctx: poise::Context<'_, ContextData, Error>) -> Re
         let improve_result = improve_skill(character.luck.current);
         mc = MessageContent::from_improve(user_data.lang, &improve_result);
 
-        let improve_query = match (
-            character.pulp_archetype.is_some(),
-            (improve_result.success_level >= SuccessLevel::Success),
-        ) {
-            (true, true) => Some(String::from("2d10+10")),
-            (true, false) => Some(String::from("1d10+5")),
-            (false, true) => Some(String::from("1d10")),
-            _ => None,
-        };
+        let improve_query = character
+            .ruleset()
+            .ruleset()
+            .luck_recovery(improve_result.success_level >= SuccessLevel::Success)
+            .map(|iq| match character.talent_luck_recovery() {
+                Some(extra) => format!("{iq}+{extra}"),
+                None => iq.to_string(),
+            });
 
-        if let Some(mut iq) = improve_query {
+        if let Some(iq) = improve_query {
             let luck_delta;
             {
                 let mut rng = RealRng::new();
@@ -308,9 +210,7 @@ pub async fn improve_luck_cmd(ctx: poise::Context<'_, ContextData, Error>) -> Re
                 character.luck.modify(luck_delta.result());
             }
 
-            if user_data.lang == LocaleLang::Polski {
-                iq = iq.replace('d', "k");
-            }
+            let iq = locale_dice(user_data.lang, &iq);
 
             mc.description = format!(
                 "{}\n🍀 **{:+}** ({})\n{}",
@@ -0,0 +1,289 @@
+use crate::character::san_impl;
+use crate::{
+    character::{BoutKind, Character, ReadingPhase, Tome, TomeError},
+    commands::{autocomplete::*, character::insanity::int_check_impl},
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    types::*,
+    utils::parse_duration_hours,
+};
+use itertools::Itertools;
+use poise::CreateReply;
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "tome",
+    aliases("księga"),
+    subcommands("list_cmd", "add_cmd", "read_cmd", "remove_cmd")
+)]
+pub async fn tome_cmd(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, rename = "list", aliases("lista"))]
+async fn list_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+        mc = MessageContent::from_character_to_tomes(user_data.lang, character);
+    }
+
+    ctx.send(CreateReply::default().content(mc.to_content()).ephemeral(true))
+        .await?;
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+#[poise::command(prefix_command, slash_command, rename = "add", aliases("dodaj"))]
+async fn add_cmd(
+    ctx: Context<'_>,
+    #[name_localized("pl", "nazwa")] name: String,
+    #[name_localized("pl", "język")] language: String,
+    #[name_localized("pl", "wstępna_lektura")] initial_reading: String,
+    #[name_localized("pl", "pełne_studium")] full_study: String,
+    #[name_localized("pl", "mity_wstępnie")] mythos_initial: i32,
+    #[name_localized("pl", "mity_w_pełni")] mythos_full: i32,
+    #[name_localized("pl", "utrata_poczytalności")] sanity_loss: String,
+    #[name_localized("pl", "poziom_mitów")] mythos_rating: i32,
+    #[name_localized("pl", "zaklęcia")] spells: Option<String>,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let lang = user_data.lang;
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        if character.get_tome(&name).is_some() {
+            return Err(TomeError::AlreadyExists(name).to_string(lang).into());
+        }
+
+        let invalid_time = |time: &str| -> Error {
+            format!("{}: `{}`", locale_text_by_tag_lang(lang, LocaleTag::InvalidTime), time).into()
+        };
+        let initial_reading_hours =
+            parse_duration_hours(&initial_reading).ok_or_else(|| invalid_time(&initial_reading))?;
+        let full_reading_hours = parse_duration_hours(&full_study).ok_or_else(|| invalid_time(&full_study))?;
+
+        let spells = spells
+            .unwrap_or_default()
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        let tome = Tome::new(
+            &name,
+            &language,
+            initial_reading_hours,
+            full_reading_hours,
+            mythos_initial,
+            mythos_full,
+            sanity_loss.trim_start_matches('-'),
+            mythos_rating,
+            spells,
+        );
+        tome.validate().map_err(|e| e.to_string(lang))?;
+        character.tomes.push(tome);
+
+        mc = MessageContent {
+            title: format!("`{}`", character.name),
+            description: format!("➕ 📖 `{name}`"),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
+
+/// Applies effects of finished reading phases; returned flag tells whether INT check should be offered.
+pub fn apply_reading_phases(
+    character: &mut Character,
+    tome: &Tome,
+    finished: &[ReadingPhase],
+    lang: LocaleLang,
+    bout_kind: BoutKind,
+) -> Result<(String, bool), Error> {
+    let mut description = String::new();
+    let mut int_check = false;
+
+    for phase in finished {
+        let learned = character
+            .learn_from_tome(tome, *phase)
+            .map_err(|e| Error::from(e.to_string(lang)))?;
+        let phase_tag = match phase {
+            ReadingPhase::Full => LocaleTag::FullStudy,
+            _ => LocaleTag::InitialReading,
+        };
+        description = format!(
+            "{}\n\n**{}** ✔️\n{} **{:+}**",
+            description,
+            locale_text_by_tag_lang(lang, phase_tag),
+            locale_text_by_tag_lang(lang, LocaleTag::CthulhuMythos),
+            tome.mythos_gain(*phase)
+        );
+
+        if !tome.sanity_loss.is_empty() && tome.sanity_loss != "0" {
+            let (san_mc, san_int_check) = san_impl(character, &format!("-{}", tome.sanity_loss), lang, bout_kind)?;
+            int_check |= san_int_check;
+            description = format!("{}\n{}", description, san_mc.description);
+        }
+
+        if !learned.is_empty() {
+            description = format!(
+                "{}\n{}: {}",
+                description,
+                locale_text_by_tag_lang(lang, LocaleTag::SpellsLearned),
+                learned.iter().map(|s| format!("**{s}**")).join(", ")
+            );
+        }
+    }
+
+    Ok((description, int_check))
+}
+
+#[poise::command(prefix_command, slash_command, rename = "read", aliases("czytaj"))]
+async fn read_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_my_tomes"]
+    #[name_localized("pl", "nazwa")]
+    name: String,
+    #[name_localized("pl", "czas")] time: String,
+    #[description = "Keep reading while /time advance passes"]
+    #[name_localized("pl", "czytaj_dalej")]
+    keep_reading: Option<bool>,
+) -> Result<(), Error> {
+    let mc;
+    let lang;
+    let character_name;
+    let bout_kind;
+    let int_check;
+    let user_id = ctx.author().id.get();
+    {
+        let mut data = ctx.data().data.write().await;
+        bout_kind = data.bout_kind();
+        let user_data = data.users.entry(user_id).or_default();
+        lang = user_data.lang;
+        character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        let hours = parse_duration_hours(&time).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::InvalidTime),
+            time
+        ))?;
+
+        let keep_reading = keep_reading.unwrap_or_default();
+        let tome = character
+            .get_mut_tome(&name)
+            .ok_or(TomeError::NoSuchTome(name.clone()).to_string(lang))?;
+        let finished = tome.read(hours).map_err(|e| Error::from(e.to_string(lang)))?;
+        tome.reading = keep_reading && tome.phase != ReadingPhase::Full;
+        let tome = tome.clone();
+        // Only one tome is read as time passes.
+        if tome.reading {
+            for other in character.tomes.iter_mut().filter(|t| t.name != tome.name) {
+                other.reading = false;
+            }
+        }
+
+        let mut description = format!(
+            "📖 `{}` **+{}h**\n{}: {}",
+            tome.name,
+            hours,
+            locale_text_by_tag_lang(lang, LocaleTag::ReadingProgress),
+            tome.progress()
+        );
+
+        let (phases_description, phases_int_check) =
+            apply_reading_phases(character, &tome, &finished, lang, bout_kind)?;
+        description.push_str(&phases_description);
+        int_check = phases_int_check;
+
+        mc = MessageContent {
+            title: format!("`{}`", character.name),
+            description,
+            ..Default::default()
+        };
+    }
+
+    if int_check {
+        int_check_impl(ctx, mc, user_id, &character_name, lang, bout_kind).await?;
+    } else {
+        ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+    }
+
+    ctx.data().data.write().await.save().await
+}
+
+#[poise::command(prefix_command, slash_command, rename = "remove", aliases("usuń"))]
+async fn remove_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_my_tomes"]
+    #[name_localized("pl", "nazwa")]
+    name: String,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let lang = user_data.lang;
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        let position = character
+            .tomes
+            .iter()
+            .position(|t| t.name.eq_ignore_ascii_case(&name))
+            .ok_or(TomeError::NoSuchTome(name.clone()).to_string(lang))?;
+        let tome = character.tomes.remove(position);
+
+        mc = MessageContent {
+            title: format!("`{}`", character.name),
+            description: format!("➖ 📖 `{}`", tome.name),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
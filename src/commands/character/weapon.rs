@@ -1,8 +1,10 @@
+use crate::roller::croll::croll_impl;
 use crate::{
-    character::{Ammo, RangeDamage, Weapon},
+    character::{AmmoType, CatalogWeapon, WeaponError, find_catalog_weapon},
     commands::autocomplete::*,
-    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
-    message::MessageContent,
+    locale::{LocaleLang, LocaleTag, locale_entry_by_tag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    roller::success_level::SuccessLevel,
     types::*,
 };
 use poise::CreateReply;
@@ -15,10 +17,13 @@ use poise::CreateReply;
     subcommands(
         "list_cmd",
         "add_cmd",
+        "add_catalog_cmd",
         "give_cmd",
         "remove_cmd",
         "reload_cmd",
+        "unjam_cmd",
         "add_ammo_cmd",
+        "add_ammo_type_cmd",
         "remove_ammo_cmd"
     )
 )]
@@ -68,6 +73,87 @@ async fn add_cmd(
     #[autocomplete = "autocomplete_my_fight_skills"]
     skill: String,
 ) -> Result<(), Error> {
+    let entry = CatalogWeapon {
+        name,
+        skill,
+        damage,
+        range,
+        attacks,
+        malfunction,
+        clip_size: clip_capacity,
+        impaling,
+        apply_damage_modifier,
+        half_damage_modifier: half_damage_modifier.unwrap_or_default(),
+        ..Default::default()
+    };
+    add_weapon_impl(ctx, entry, start_ammunition).await
+}
+
+// Slash only: prefix argument parsing of this many optional parameters blows up compile time.
+#[allow(clippy::too_many_arguments)]
+#[poise::command(slash_command, rename = "add_catalog")]
+async fn add_catalog_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_weapon_catalog"]
+    #[name_localized("pl", "katalog")]
+    catalog: String,
+    #[name_localized("pl", "nazwa")] name: Option<String>,
+    #[name_localized("pl", "obrażenia")] damage: Option<String>,
+    #[name_localized("pl", "ataki")] attacks: Option<String>,
+    #[name_localized("pl", "zasięg")] range: Option<String>,
+    #[name_localized("pl", "zawodność")] malfunction: Option<i32>,
+    #[name_localized("pl", "czy_modyfikator_obrażeń")] apply_damage_modifier: Option<bool>,
+    #[name_localized("pl", "czy_połowa_modyfikatora_obrażeń")] half_damage_modifier: Option<bool>,
+    #[name_localized("pl", "czy_ostra")] impaling: Option<bool>,
+    #[name_localized("pl", "magazynek")] clip_capacity: Option<i32>,
+    #[name_localized("pl", "amunicja_startowa")] start_ammunition: Option<i32>,
+    #[name_localized("pl", "umiejętność")]
+    #[autocomplete = "autocomplete_my_fight_skills"]
+    skill: Option<String>,
+) -> Result<(), Error> {
+    let mut entry;
+    {
+        let user_id = ctx.author().id.get();
+        let data = ctx.data().data.read().await;
+        let lang = data.users.get(&user_id).map(|u| u.lang).unwrap_or_default();
+        entry = find_catalog_weapon(&catalog)
+            .map_err(|e| Error::from(e.to_string(lang)))?
+            .clone();
+    }
+    if let Some(name) = name {
+        entry.name = name;
+    }
+    if let Some(damage) = damage {
+        entry.damage = damage;
+    }
+    if let Some(attacks) = attacks {
+        entry.attacks = attacks;
+    }
+    if range.is_some() {
+        entry.range = range;
+    }
+    if malfunction.is_some() {
+        entry.malfunction = malfunction;
+    }
+    if let Some(apply_damage_modifier) = apply_damage_modifier {
+        entry.apply_damage_modifier = apply_damage_modifier;
+    }
+    if let Some(half_damage_modifier) = half_damage_modifier {
+        entry.half_damage_modifier = half_damage_modifier;
+    }
+    if let Some(impaling) = impaling {
+        entry.impaling = impaling;
+    }
+    if clip_capacity.is_some() {
+        entry.clip_size = clip_capacity;
+    }
+    if let Some(skill) = skill {
+        entry.skill = skill;
+    }
+    add_weapon_impl(ctx, entry, start_ammunition).await
+}
+
+async fn add_weapon_impl(ctx: Context<'_>, entry: CatalogWeapon, start_ammunition: Option<i32>) -> Result<(), Error> {
     let message;
     {
         let user_id = ctx.author().id.get();
@@ -83,42 +169,12 @@ async fn add_cmd(
             &character_name
         ))?;
 
-        let range_dmgs = RangeDamage::from(
-            range
-                .unwrap_or("-".into())
-                .replace(' ', "")
-                .replace('k', "d")
-                .split("/")
-                .map(str::to_string)
-                .collect(),
-            damage.replace(' ', "").split("/").map(str::to_string).collect(),
-        )
-        .map_err(|e| Error::from(e.to_string(user_data.lang)))?;
-
-        let ammo = if let (Some(clip_size), Some(ammunition)) = (clip_capacity, start_ammunition) {
-            Some(Ammo::new(clip_size, ammunition))
-        } else {
-            None
-        };
-
-        let mut new_weapon = Weapon::new(
-            &name,
-            range_dmgs,
-            &attacks,
-            apply_damage_modifier,
-            impaling,
-            &skill,
-            malfunction,
-            ammo,
-        );
-        if half_damage_modifier.unwrap_or_default() {
-            new_weapon.apply_half_dm();
-        }
+        let new_weapon = entry.to_weapon(user_data.lang, start_ammunition)?;
         character.weapons.push(new_weapon);
 
         message = MessageContent {
             title: format!("`{}`", character.name),
-            description: format!("➕ **{}**", name),
+            description: format!("➕ **{}**", entry.name),
             ..Default::default()
         };
     }
@@ -196,11 +252,7 @@ async fn give_cmd(
                 .iter()
                 .any(|(_, user_data)| user_data.characters.iter().any(|(name, _)| **name == character_name))
             {
-                let e = match lang {
-                    LocaleLang::Polski => Err("Nieistniejący odbiorca!".into()),
-                    LocaleLang::English => Err("No receiver!".into()),
-                };
-                return e;
+                return Err(locale_text_by_tag_lang(lang, LocaleTag::NoReceiver).into());
             }
         }
 
@@ -254,6 +306,9 @@ pub async fn reload_cmd(
     #[autocomplete = "autocomplete_my_weapons"]
     #[name_localized("pl", "nazwa_broni")]
     weapon_name: String,
+    #[autocomplete = "autocomplete_my_weapon_ammo_types"]
+    #[name_localized("pl", "rodzaj_amunicji")]
+    ammo_type: Option<String>,
 ) -> Result<(), Error> {
     {
         let user_id = ctx.author().id.get();
@@ -275,9 +330,12 @@ pub async fn reload_cmd(
             .find(|w| w.name.to_ascii_lowercase().contains(&weapon_name.to_ascii_lowercase()))
             .ok_or("No such weapon")?;
 
-        let desc = match weapon.reload() {
-            Ok(o) => o.to_string(user_data.lang),
-            Err(e) => e.to_string(user_data.lang),
+        let desc = match weapon.ammo.as_mut() {
+            Some(ammo) => match ammo.reload(&mut character.items, ammo_type.as_deref()) {
+                Ok(o) => o.to_string(user_data.lang),
+                Err(e) => e.to_string(user_data.lang),
+            },
+            None => WeaponError::DoesntUseAmmo.to_string(user_data.lang),
         };
 
         let message = MessageContent {
@@ -292,6 +350,85 @@ pub async fn reload_cmd(
     ctx.data().data.write().await.save().await
 }
 
+// Spends a round working on a jammed weapon, or tries to clear it at once with Mechanical Repair or
+// weapon's skill roll, whichever is higher.
+#[poise::command(prefix_command, slash_command, rename = "unjam", aliases("odblokuj"))]
+pub async fn unjam_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_my_weapons"]
+    #[name_localized("pl", "nazwa_broni")]
+    weapon_name: String,
+    #[name_localized("pl", "test")] roll: Option<bool>,
+) -> Result<(), Error> {
+    let mut mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let lang = user_data.lang;
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        let weapon = character
+            .weapons
+            .iter()
+            .find(|w| w.name.to_ascii_lowercase().contains(&weapon_name.to_ascii_lowercase()))
+            .cloned()
+            .ok_or("No such weapon")?;
+        if weapon.jammed.is_none() {
+            return Err(WeaponError::NotJammed.to_string(lang).into());
+        }
+
+        let mut cleared = false;
+        mc = MessageContent {
+            title: format!("**{}** (`{}`)", weapon.name, &character_name),
+            ..Default::default()
+        };
+        if roll.unwrap_or_default() {
+            let skill = [
+                character.get_skill(locale_entry_by_tag(LocaleTag::MechanicalRepair).en()),
+                character.get_skill(&weapon.skill),
+            ]
+            .into_iter()
+            .flatten()
+            .max_by_key(|s| s.value)
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoSuchSkill))?;
+            let croll_result = croll_impl(&skill.value.to_string(), character.ruleset())?;
+            cleared = croll_result.success_level >= SuccessLevel::Success;
+            mc = MessageContent::from_croll_result(lang, &croll_result, false, true)
+                .with_skill_name(&skill.name.get(lang))
+                .with_character_name(&character_name);
+        }
+
+        let weapon = character
+            .weapons
+            .iter_mut()
+            .find(|w| w.name == weapon.name)
+            .ok_or("No such weapon")?;
+        let result = if cleared {
+            weapon.clear_jam()
+        } else {
+            weapon.work_on_jam()
+        }
+        .map_err(|e| Error::from(e.to_string(lang)))?;
+
+        mc.description = format!("{}\n\n🔧 **{}**", mc.description, result.to_string(lang))
+            .trim()
+            .to_string();
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
+
 #[poise::command(prefix_command, slash_command, rename = "add_ammo", aliases("dodaj_amunicję"))]
 pub async fn add_ammo_cmd(
     ctx: Context<'_>,
@@ -299,6 +436,9 @@ pub async fn add_ammo_cmd(
     #[name_localized("pl", "nazwa_broni")]
     weapon_name: String,
     #[name_localized("pl", "amunicja")] rounds: i32,
+    #[autocomplete = "autocomplete_my_weapon_ammo_types"]
+    #[name_localized("pl", "rodzaj_amunicji")]
+    ammo_type: Option<String>,
 ) -> Result<(), Error> {
     {
         let user_id = ctx.author().id.get();
@@ -320,7 +460,11 @@ pub async fn add_ammo_cmd(
             .find(|w| w.name.to_ascii_lowercase().contains(&weapon_name.to_ascii_lowercase()))
             .ok_or("No such weapon")?;
 
-        let desc = match weapon.add_ammo(rounds) {
+        let result = match (&ammo_type, weapon.ammo.as_mut()) {
+            (Some(ammo_type), Some(ammo)) => ammo.add_typed_rounds(&mut character.items, ammo_type, rounds),
+            _ => weapon.add_ammo(rounds),
+        };
+        let desc = match result {
             Ok(o) => o.to_string(user_data.lang),
             Err(e) => e.to_string(user_data.lang),
         };
@@ -337,6 +481,70 @@ pub async fn add_ammo_cmd(
     ctx.data().data.write().await.save().await
 }
 
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "add_ammo_type",
+    aliases("dodaj_rodzaj_amunicji")
+)]
+pub async fn add_ammo_type_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_my_weapons"]
+    #[name_localized("pl", "nazwa_broni")]
+    weapon_name: String,
+    #[name_localized("pl", "nazwa")] name: String,
+    #[name_localized("pl", "amunicja")] rounds: Option<i32>,
+    #[name_localized("pl", "obrażenia")] damage: Option<String>,
+    #[name_localized("pl", "czy_ostra")] impaling: Option<bool>,
+    #[autocomplete = "autocomplete_my_items"]
+    #[name_localized("pl", "wspólny_zapas")]
+    pool: Option<String>,
+) -> Result<(), Error> {
+    let message;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let user_data = data.users.entry(user_id).or_default();
+        let character_name = user_data
+            .active_character
+            .clone()
+            .ok_or(locale_text_by_tag_lang(user_data.lang, LocaleTag::NoCharacterSelected))?;
+        let character = user_data.characters.get_mut(&character_name).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(user_data.lang, LocaleTag::CharacterNotFound),
+            &character_name
+        ))?;
+
+        let weapon = character
+            .weapons
+            .iter_mut()
+            .find(|w| w.name.to_ascii_lowercase().contains(&weapon_name.to_ascii_lowercase()))
+            .ok_or("No such weapon")?;
+        let ammo = weapon
+            .ammo
+            .as_mut()
+            .ok_or(WeaponError::DoesntUseAmmo.to_string(user_data.lang))?;
+        ammo.add_type(AmmoType {
+            name: name.trim().to_string(),
+            damage: damage.map(|d| d.replace(' ', "").replace('k', "d")),
+            impaling,
+            rounds: rounds.unwrap_or_default(),
+            pool,
+        })
+        .map_err(|e| Error::from(e.to_string(user_data.lang)))?;
+
+        message = MessageContent {
+            title: format!("**{}** (`{}`)", weapon.name, &character_name),
+            description: format!("➕ **{}**\n{}", name, ammo.to_string(&character.items)),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(message.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
+
 #[poise::command(prefix_command, slash_command, rename = "remove_ammo", aliases("usuń_amunicję"))]
 pub async fn remove_ammo_cmd(
     ctx: Context<'_>,
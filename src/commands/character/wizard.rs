@@ -0,0 +1,421 @@
+use crate::{
+    character::{CHARACTERISTIC_ABBREVIATIONS, CharacterDraft, DraftError, parse_lines, parse_numbers, parse_skills},
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    roller::{dice_rng::RealRng, roll::roll_attributes},
+    ruleset::RulesetKind,
+    types::*,
+};
+use itertools::Itertools;
+use poise::{
+    CreateReply,
+    serenity_prelude::{
+        ActionRowComponent, ButtonStyle, ComponentInteraction, ComponentInteractionCollector, CreateActionRow,
+        CreateButton, CreateInputText, CreateInteractionResponse, CreateInteractionResponseMessage, CreateModal,
+        InputTextStyle, ModalInteraction, ModalInteractionCollector,
+    },
+};
+use std::time::Duration;
+
+const WIZARD_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+const MODAL_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+/// Same limit as the reroll loop of `/roll_attributes`.
+const MAX_REROLLS: usize = 1_000_000;
+
+fn wizard_content(lang: LocaleLang, draft: &CharacterDraft) -> MessageContent {
+    MessageContent {
+        title: locale_text_by_tag_lang(lang, LocaleTag::CharacterWizard),
+        description: draft.summary(lang),
+        ..Default::default()
+    }
+}
+
+fn wizard_components(id: &str, lang: LocaleLang, draft: &CharacterDraft) -> Vec<CreateActionRow> {
+    let button = |action: &str, emoji: &str, tag: LocaleTag, style: ButtonStyle| {
+        CreateButton::new(format!("{id}:{action}"))
+            .label(format!("{emoji} {}", locale_text_by_tag_lang(lang, tag)))
+            .style(style)
+    };
+    vec![
+        CreateActionRow::Buttons(vec![
+            button("roll", "🎲", LocaleTag::RollCharacteristics, ButtonStyle::Primary),
+            button("enter", "✏️", LocaleTag::EnterCharacteristics, ButtonStyle::Secondary),
+            button("age", "⏳", LocaleTag::Age, ButtonStyle::Secondary).disabled(draft.characteristics.is_none()),
+            button("name", "📝", LocaleTag::Name, ButtonStyle::Secondary),
+        ]),
+        CreateActionRow::Buttons(vec![
+            button("skills", "📚", LocaleTag::SheetSkills, ButtonStyle::Secondary).disabled(draft.age.is_none()),
+            button("weapons", "🔫", LocaleTag::Weapons, ButtonStyle::Secondary),
+            button("items", "🎒", LocaleTag::Items, ButtonStyle::Secondary),
+        ]),
+        CreateActionRow::Buttons(vec![
+            button("create", "✅", LocaleTag::CreateCharacter, ButtonStyle::Success).disabled(!draft.is_complete()),
+            button("discard", "🗑️", LocaleTag::DiscardDraft, ButtonStyle::Danger),
+        ]),
+    ]
+}
+
+fn wizard_response(id: &str, lang: LocaleLang, draft: &CharacterDraft) -> CreateInteractionResponse {
+    CreateInteractionResponse::UpdateMessage(
+        CreateInteractionResponseMessage::new()
+            .embed(wizard_content(lang, draft).to_embed())
+            .components(wizard_components(id, lang, draft)),
+    )
+}
+
+fn error_response(message: String) -> CreateInteractionResponse {
+    CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(format!("🚫 {message}"))
+            .ephemeral(true),
+    )
+}
+
+fn input(style: InputTextStyle, label: String, value: String) -> CreateInputText {
+    let input = CreateInputText::new(style, label.chars().take(45).collect::<String>(), "value").required(false);
+    if value.is_empty() { input } else { input.value(value) }
+}
+
+/// Shows a modal in response to a button and waits for its values, in the order of `inputs`.
+async fn ask(
+    ctx: Context<'_>,
+    press: &ComponentInteraction,
+    title: String,
+    inputs: Vec<CreateInputText>,
+) -> Result<Option<(ModalInteraction, Vec<String>)>, Error> {
+    let custom_id = press.id.to_string();
+    let modal =
+        CreateModal::new(&custom_id, title).components(inputs.into_iter().map(CreateActionRow::InputText).collect());
+    press
+        .create_response(ctx, CreateInteractionResponse::Modal(modal))
+        .await?;
+
+    let Some(submit) = ModalInteractionCollector::new(ctx)
+        .filter(move |submit| submit.data.custom_id == custom_id)
+        .timeout(MODAL_TIMEOUT)
+        .await
+    else {
+        return Ok(None);
+    };
+    let values = submit
+        .data
+        .components
+        .iter()
+        .flat_map(|row| &row.components)
+        .filter_map(|component| match component {
+            ActionRowComponent::InputText(text) => Some(text.value.clone().unwrap_or_default()),
+            _ => None,
+        })
+        .collect();
+    Ok(Some((submit, values)))
+}
+
+fn characteristics_inputs(lang: LocaleLang, draft: &CharacterDraft) -> Vec<CreateInputText> {
+    let abbreviations = |range: std::ops::Range<usize>| {
+        CHARACTERISTIC_ABBREVIATIONS[range]
+            .iter()
+            .map(|tag| locale_text_by_tag_lang(lang, *tag))
+            .join(" ")
+    };
+    let values = |range: std::ops::Range<usize>| {
+        draft
+            .characteristics
+            .map(|values| values[range].iter().join(" "))
+            .unwrap_or_default()
+    };
+    vec![
+        input(InputTextStyle::Short, abbreviations(0..4), values(0..4)),
+        input(InputTextStyle::Short, abbreviations(4..8), values(4..8)),
+        input(
+            InputTextStyle::Short,
+            locale_text_by_tag_lang(lang, LocaleTag::Luck),
+            draft.luck.map(|luck| luck.to_string()).unwrap_or_default(),
+        ),
+    ]
+}
+
+fn enter_characteristics(draft: &mut CharacterDraft, values: &[String]) -> Result<(), DraftError> {
+    let numbers = parse_numbers(&values[..values.len().min(3)].join(" "))?;
+    let characteristics: [i32; 8] = numbers
+        .get(..8)
+        .and_then(|numbers| numbers.try_into().ok())
+        .ok_or(DraftError::InvalidCharacteristics)?;
+    match numbers[8..] {
+        [luck] => draft.set_characteristics(characteristics, luck),
+        _ => Err(DraftError::InvalidCharacteristics),
+    }
+}
+
+/// Step that asks for values in a modal, then changes the draft with them.
+struct Step {
+    title: LocaleTag,
+    inputs: Vec<CreateInputText>,
+    apply: fn(&mut CharacterDraft, &[String], RulesetKind) -> Result<(), DraftError>,
+}
+
+fn step(action: &str, lang: LocaleLang, draft: &CharacterDraft) -> Option<Step> {
+    let text = |tag| locale_text_by_tag_lang(lang, tag);
+    let lines = |values: &[String]| values.iter().join("\n");
+    Some(match action {
+        "enter" => Step {
+            title: LocaleTag::EnterCharacteristics,
+            inputs: characteristics_inputs(lang, draft),
+            apply: |draft, values, _| enter_characteristics(draft, values),
+        },
+        "age" => Step {
+            title: LocaleTag::Age,
+            inputs: vec![input(
+                InputTextStyle::Short,
+                text(LocaleTag::Age),
+                draft.age.as_ref().map(|age| age.years.to_string()).unwrap_or_default(),
+            )],
+            apply: |draft, values, _| {
+                let years = values[0].trim().parse().map_err(|_| DraftError::InvalidAge)?;
+                draft.set_age(&mut RealRng::new(), years)
+            },
+        },
+        "name" => Step {
+            title: LocaleTag::Name,
+            inputs: vec![
+                input(
+                    InputTextStyle::Short,
+                    text(LocaleTag::Name),
+                    draft.name.clone().unwrap_or_default(),
+                )
+                .required(true)
+                .max_length(100),
+                input(
+                    InputTextStyle::Short,
+                    text(LocaleTag::Occupation),
+                    draft.occupation.clone().unwrap_or_default(),
+                )
+                .max_length(100),
+            ],
+            apply: |draft, values, _| {
+                let [name, occupation] = values else {
+                    return Err(DraftError::Incomplete);
+                };
+                draft.name = Some(name.trim().to_string()).filter(|name| !name.is_empty());
+                draft.occupation = Some(occupation.trim().to_string()).filter(|occupation| !occupation.is_empty());
+                Ok(())
+            },
+        },
+        "skills" => Step {
+            title: LocaleTag::SheetSkills,
+            inputs: vec![
+                input(
+                    InputTextStyle::Paragraph,
+                    format!(
+                        "{}: {}",
+                        text(LocaleTag::SkillPoints),
+                        draft.skill_points().unwrap_or_default()
+                    ),
+                    draft
+                        .skills
+                        .iter()
+                        .map(|(name, points)| format!("{name}: {points}"))
+                        .join("\n"),
+                )
+                .placeholder("Spot Hidden: 40"),
+            ],
+            apply: |draft, values, ruleset| draft.set_skills(parse_skills(&values[0])?, ruleset),
+        },
+        "weapons" => Step {
+            title: LocaleTag::Weapons,
+            inputs: vec![
+                input(
+                    InputTextStyle::Paragraph,
+                    text(LocaleTag::Weapons),
+                    lines(&draft.weapons),
+                )
+                .placeholder("Knife, medium"),
+            ],
+            apply: |draft, values, _| draft.set_weapons(parse_lines(&values[0])),
+        },
+        "items" => Step {
+            title: LocaleTag::Items,
+            inputs: vec![input(
+                InputTextStyle::Paragraph,
+                text(LocaleTag::Items),
+                lines(&draft.items),
+            )],
+            apply: |draft, values, _| {
+                draft.items = parse_lines(&values[0]);
+                Ok(())
+            },
+        },
+        _ => return None,
+    })
+}
+
+/// Saved draft of the author with their language and the ruleset of the guild.
+async fn load_draft(ctx: Context<'_>) -> (LocaleLang, CharacterDraft, RulesetKind) {
+    let data = ctx.data().data.read().await;
+    let ruleset = data.ruleset(ctx.guild_id().map(|guild_id| guild_id.get()));
+    let user_data = data.users.get(&ctx.author().id.get());
+    (
+        user_data.map(|u| u.lang).unwrap_or_default(),
+        user_data.and_then(|u| u.draft.clone()).unwrap_or_default(),
+        ruleset,
+    )
+}
+
+async fn save_draft(ctx: Context<'_>, draft: Option<CharacterDraft>) -> Result<(), Error> {
+    let mut data = ctx.data().data.write().await;
+    data.users.entry(ctx.author().id.get()).or_default().draft = draft;
+    data.save().await
+}
+
+/// Creates the character the same way as `/character create`; returns the reason when it can't be created.
+async fn create_character(ctx: Context<'_>, draft: &CharacterDraft) -> Result<Result<String, String>, Error> {
+    let mut data = ctx.data().data.write().await;
+    let settings = data.settings(ctx.guild_id().map(|guild_id| guild_id.get()));
+    let max = settings.max_characters_per_user;
+    let ruleset = settings.ruleset;
+    let user_data = data.users.entry(ctx.author().id.get()).or_default();
+    let lang = user_data.lang;
+
+    if user_data.characters.len() >= max {
+        return Ok(Err(locale_text_by_tag_lang(lang, LocaleTag::SorryTooManyCharacters)));
+    }
+    let character = match draft.build(lang, ruleset) {
+        Ok(character) => character,
+        Err(e) => return Ok(Err(e.to_string())),
+    };
+    let name = character.name.clone();
+    user_data.characters.insert(name.clone(), character);
+    user_data.active_character = Some(name.clone());
+    user_data.draft = None;
+    data.save().await?;
+    Ok(Ok(name))
+}
+
+/// Step-by-step character creation; every step is saved, so the wizard can be left and continued later.
+pub async fn show_wizard(ctx: Context<'_>) -> Result<(), Error> {
+    let id = ctx.id().to_string();
+    let (lang, mut draft, ruleset) = load_draft(ctx).await;
+    let reply = ctx
+        .send(
+            CreateReply::default()
+                .embed(wizard_content(lang, &draft).to_embed())
+                .components(wizard_components(&id, lang, &draft))
+                .ephemeral(true),
+        )
+        .await?;
+
+    let mut finished = None;
+    while let Some(press) = ComponentInteractionCollector::new(ctx)
+        .filter({
+            let prefix = format!("{id}:");
+            move |press| press.data.custom_id.starts_with(&prefix)
+        })
+        .timeout(WIZARD_TIMEOUT)
+        .await
+    {
+        let action = &press.data.custom_id[id.len() + 1..];
+        match action {
+            "roll" => {
+                let min_attribute_value = ctx
+                    .data()
+                    .data
+                    .read()
+                    .await
+                    .settings(ctx.guild_id().map(|guild_id| guild_id.get()))
+                    .min_attribute_value;
+                let roll = (0..MAX_REROLLS)
+                    .map(|_| roll_attributes(None))
+                    .find(|roll| roll.lowest_attribute_value() >= min_attribute_value)
+                    .ok_or("🚫🎲")?;
+                draft.roll(&mut RealRng::new(), &roll);
+                save_draft(ctx, Some(draft.clone())).await?;
+                press.create_response(ctx, wizard_response(&id, lang, &draft)).await?;
+            }
+            "create" => match create_character(ctx, &draft).await? {
+                Ok(name) => {
+                    finished = Some(format!("✅ `{name}`"));
+                    press
+                        .create_response(ctx, CreateInteractionResponse::Acknowledge)
+                        .await?;
+                    break;
+                }
+                Err(e) => press.create_response(ctx, error_response(e)).await?,
+            },
+            "discard" => {
+                save_draft(ctx, None).await?;
+                finished = Some(locale_text_by_tag_lang(lang, LocaleTag::DraftDiscarded));
+                press
+                    .create_response(ctx, CreateInteractionResponse::Acknowledge)
+                    .await?;
+                break;
+            }
+            _ => {
+                let Some(step) = step(action, lang, &draft) else {
+                    continue;
+                };
+                let Some((submit, values)) =
+                    ask(ctx, &press, locale_text_by_tag_lang(lang, step.title), step.inputs).await?
+                else {
+                    continue;
+                };
+                let mut changed = draft.clone();
+                match (step.apply)(&mut changed, &values, ruleset) {
+                    Ok(()) => {
+                        draft = changed;
+                        save_draft(ctx, Some(draft.clone())).await?;
+                        submit.create_response(ctx, wizard_response(&id, lang, &draft)).await?;
+                    }
+                    Err(e) => submit.create_response(ctx, error_response(e.to_string(lang))).await?,
+                }
+            }
+        }
+    }
+
+    let mc = match finished {
+        Some(title) => MessageContent {
+            title,
+            ..Default::default()
+        },
+        None => MessageContent {
+            footer: locale_text_by_tag_lang(lang, LocaleTag::DraftSaved),
+            ..wizard_content(lang, &draft)
+        },
+    };
+    reply
+        .edit(ctx, CreateReply::default().embed(mc.to_embed()).components(vec![]))
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn characteristics_from_modal() {
+        let mut draft = CharacterDraft::default();
+        assert!(enter_characteristics(&mut draft, &strings(&["50 60 70 40", "55,80,65,70", "45"])).is_ok());
+        assert_eq!(draft.characteristics, Some([50, 60, 70, 40, 55, 80, 65, 70]));
+        assert_eq!(draft.luck, Some(45));
+        assert!(enter_characteristics(&mut draft, &strings(&["50 60 70", "55 80 65 70", "45"])).is_err());
+        assert!(enter_characteristics(&mut draft, &strings(&["50 60 70 40", "55 80 65 70", ""])).is_err());
+    }
+
+    #[test]
+    fn buttons_follow_progress() {
+        let lang = LocaleLang::default();
+        let mut draft = CharacterDraft::default();
+        assert_eq!(wizard_components("1", lang, &draft).len(), 3);
+        assert!(step("skills", lang, &draft).is_some());
+        assert!(step("unknown", lang, &draft).is_none());
+        assert!(
+            (step("name", lang, &draft).unwrap().apply)(&mut draft, &strings(&["  ", "Writer"]), RulesetKind::Classic)
+                .is_ok()
+        );
+        assert_eq!(draft.name, None);
+        assert_eq!(draft.occupation.as_deref(), Some("Writer"));
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{
+    character::Character,
+    commands::{
+        autocomplete::character::{autocomplete_any_active_character, autocomplete_character_attributes},
+        gm::{edit_any_character, is_user_gm},
+    },
+    locale::{LocaleLang, LocaleTag, locale_dice, locale_text_by_tag_lang},
+    roller::{dice_rng::RealRng, roll::roll_query},
+    types::*,
+};
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "gmattribute",
+    aliases("gmcharacteristic", "gmatrybut", "gmcecha"),
+    subcommands("set_cmd", "change_cmd", "luck_cmd", "magic_cmd", "occupation_cmd")
+)]
+pub async fn gmattribute_cmd(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+fn attribute_value(lang: LocaleLang, character: &Character, name: &str) -> Result<i32, Error> {
+    character
+        .attributes
+        .get(name)
+        .map(|attribute| attribute.value)
+        .ok_or_else(|| {
+            format!(
+                "{}: {}",
+                locale_text_by_tag_lang(lang, LocaleTag::NoSuchAttribute),
+                name
+            )
+            .into()
+        })
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "set",
+    aliases("ustaw"),
+    check = "is_user_gm"
+)]
+async fn set_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "imię")]
+    character_name: String,
+    #[autocomplete = "autocomplete_character_attributes"]
+    #[name_localized("pl", "nazwa")]
+    name: String,
+    #[name_localized("pl", "wartość")] value: i32,
+) -> Result<(), Error> {
+    edit_any_character(ctx, &character_name, |lang, character| {
+        attribute_value(lang, character, &name)?;
+        character.set_attribute(&name, value);
+        Ok(format!("`{name}` ⬅️ `{value}`"))
+    })
+    .await
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "change",
+    aliases("zmiana"),
+    check = "is_user_gm"
+)]
+async fn change_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "imię")]
+    character_name: String,
+    #[autocomplete = "autocomplete_character_attributes"]
+    #[name_localized("pl", "nazwa")]
+    name: String,
+    #[name_localized("pl", "wartość")] query: String,
+) -> Result<(), Error> {
+    edit_any_character(ctx, &character_name, |lang, character| {
+        let value = attribute_value(lang, character, &name)?;
+        let query = locale_dice(lang, &query);
+        let roll_result;
+        {
+            let mut rng = RealRng::new();
+            roll_result = roll_query(&mut rng, &query)?;
+        }
+
+        let new_value = (value + roll_result.result_real()).max(0);
+        character.set_attribute(&name, new_value);
+        Ok(format!(
+            "**{name}**\n{} **{:+}** = **{}** ({})",
+            value,
+            roll_result.result_real(),
+            new_value,
+            query,
+        ))
+    })
+    .await
+}
+
+/// Sets current Luck, up to 99
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "luck",
+    aliases("szczęście"),
+    check = "is_user_gm"
+)]
+async fn luck_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "imię")]
+    character_name: String,
+    #[name_localized("pl", "wartość")] value: i32,
+) -> Result<(), Error> {
+    edit_any_character(ctx, &character_name, |lang, character| {
+        character.luck.current = value.clamp(0, character.luck.max);
+        Ok(format!(
+            "{} ⬅️ `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::Luck),
+            character.status_luck_raw()
+        ))
+    })
+    .await
+}
+
+/// Sets current Magic Points, up to the maximum from POW
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "magic",
+    aliases("magia"),
+    check = "is_user_gm"
+)]
+async fn magic_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "imię")]
+    character_name: String,
+    #[name_localized("pl", "wartość")] value: i32,
+) -> Result<(), Error> {
+    edit_any_character(ctx, &character_name, |lang, character| {
+        character.magic.current = value.clamp(0, character.magic.max);
+        Ok(format!(
+            "{} ⬅️ `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::MagicPoints),
+            character.status_magic_raw()
+        ))
+    })
+    .await
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "occupation",
+    aliases("zawód"),
+    check = "is_user_gm"
+)]
+async fn occupation_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "imię")]
+    character_name: String,
+    #[name_localized("pl", "zawód")] occupation: Option<String>,
+) -> Result<(), Error> {
+    edit_any_character(ctx, &character_name, |lang, character| {
+        character.occupation = occupation.filter(|occupation| !occupation.trim().is_empty());
+        Ok(format!(
+            "{} ⬅️ `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::Occupation),
+            character.occupation.as_deref().unwrap_or("-")
+        ))
+    })
+    .await
+}
@@ -1,6 +1,15 @@
-use crate::commands::character::stats::{hp_impl, san_impl};
+use crate::character::InsanityKind;
+use crate::character::{hp_impl, san_impl};
+use crate::commands::character::insanity::int_check_impl;
 use crate::commands::gm::character::character::autocomplete_any_active_character;
-use crate::{character::Character, commands::autocomplete::*, locale::*, message::MessageContent, types::*};
+use crate::roller::dice_rng::RealRng;
+use crate::{
+    character::Character,
+    commands::autocomplete::*,
+    locale::*,
+    message::{MessageContent, ToEmbed},
+    types::*,
+};
 use poise::CreateReply;
 
 pub async fn is_user_gm(ctx: Context<'_>) -> Result<bool, Error> {
@@ -8,13 +17,14 @@ pub async fn is_user_gm(ctx: Context<'_>) -> Result<bool, Error> {
         let gm_role_id;
         {
             let data = ctx.data().data.read().await;
+            let gm_role_name = data.settings(Some(guild_id.get())).gm_role_name;
             let guild = guild_id
                 .to_guild_cached(ctx.serenity_context())
                 .ok_or("Guild not in cache")?;
 
             gm_role_id = guild
-                .role_by_name(&data.gm_role_name)
-                .ok_or(format!("No such role `{}`", &data.gm_role_name))?
+                .role_by_name(&gm_role_name)
+                .ok_or(format!("No such role `{gm_role_name}`"))?
                 .id
                 .get();
         }
@@ -127,6 +137,8 @@ pub async fn gminsane_cmd(
     #[autocomplete = "autocomplete_any_active_character"]
     #[name_localized("pl", "imię")]
     name: String,
+    #[name_localized("pl", "trwałe")] indefinite: Option<bool>,
+    #[name_localized("pl", "przyczyna")] cause: Option<String>,
 ) -> Result<(), Error> {
     let gm_lang;
     {
@@ -141,11 +153,21 @@ pub async fn gminsane_cmd(
 
     {
         let mut data = ctx.data().data.write().await;
+        let bout_kind = data.bout_kind();
+        let kind = if indefinite.unwrap_or_default() {
+            InsanityKind::Indefinite
+        } else {
+            InsanityKind::Temporary
+        };
 
         for user_data in data.users.values_mut() {
             if let Some(character) = user_data.characters.get_mut(&name) {
                 character_name = Some(character.name.clone());
-                character.fragile_mind = true;
+                let episode;
+                {
+                    let mut rng = RealRng::new();
+                    episode = character.start_insanity(&mut rng, kind, cause.clone(), Some(bout_kind));
+                }
 
                 mc = Some(MessageContent {
                     title: format!(
@@ -153,6 +175,7 @@ pub async fn gminsane_cmd(
                         character.name,
                         locale_text_by_tag_lang(gm_lang, LocaleTag::GoneMad)
                     ),
+                    description: episode.to_string(gm_lang),
                     ..Default::default()
                 });
                 break;
@@ -204,7 +227,7 @@ pub async fn gmsane_cmd(
         for user_data in data.users.values_mut() {
             if let Some(character) = user_data.characters.get_mut(&name) {
                 character_name = Some(character.name.clone());
-                character.fragile_mind = false;
+                character.end_insanity();
 
                 mc = Some(MessageContent {
                     title: format!(
@@ -523,21 +546,29 @@ pub async fn gmsan_cmd(
 
     let mut mc = None;
     let mut character_name = None;
+    let mut owner_id = None;
+    let bout_kind;
 
     {
         let mut data = ctx.data().data.write().await;
+        bout_kind = data.bout_kind();
 
-        for user_data in data.users.values_mut() {
+        for (user_id, user_data) in data.users.iter_mut() {
             if let Some(character) = user_data.characters.get_mut(&name) {
                 character_name = Some(character.name.clone());
-                mc = Some(san_impl(character, &delta, gm_lang)?);
+                owner_id = Some(*user_id);
+                mc = Some(san_impl(character, &delta, gm_lang, bout_kind)?);
                 break;
             }
         }
     }
 
-    if let Some(mc) = mc {
-        ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+    if let (Some((mc, int_check)), Some(owner_id)) = (mc, owner_id) {
+        if int_check {
+            int_check_impl(ctx, mc, owner_id, &name, gm_lang, bout_kind).await?;
+        } else {
+            ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+        }
     } else {
         return Err(format!(
             "{}: `{}`",
@@ -1,7 +1,7 @@
 use crate::{
     commands::{autocomplete::*, gm::is_user_gm},
     locale::{LocaleTag, locale_text_by_tag_lang},
-    message::MessageContent,
+    message::{MessageContent, ToEmbed},
     types::*,
 };
 use poise::CreateReply;
@@ -0,0 +1,148 @@
+use crate::{
+    bot_data::Data,
+    commands::gm::is_user_gm,
+    dashboard::Dashboard,
+    locale::{LocaleTag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    types::*,
+};
+use poise::{
+    CreateReply,
+    serenity_prelude::{self as serenity, ChannelId, CreateMessage, EditMessage, Http, MessageId},
+};
+use tokio::sync::RwLock;
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "dashboard",
+    aliases("tablica"),
+    subcommands("start_cmd", "stop_cmd")
+)]
+pub async fn dashboard_cmd(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Edits every dashboard whose party status changed since its last edit.
+/// Dashboards with a deleted message are forgotten.
+pub async fn refresh_dashboards(http: &Http, data: &RwLock<Data>) -> Result<(), Error> {
+    let updates: Vec<(u64, Dashboard)>;
+    {
+        let mut data = data.write().await;
+        let Data { users, dashboards, .. } = &mut *data;
+        updates = dashboards
+            .iter_mut()
+            .filter_map(|(guild_id, dashboard)| dashboard.update(users).map(|_| (*guild_id, dashboard.clone())))
+            .collect();
+    }
+    if updates.is_empty() {
+        return Ok(());
+    }
+
+    for (guild_id, dashboard) in updates {
+        let result = ChannelId::new(dashboard.channel_id)
+            .edit_message(
+                http,
+                MessageId::new(dashboard.message_id),
+                EditMessage::new().embed(dashboard.to_message_content().to_embed()),
+            )
+            .await;
+        if let Err(e) = result {
+            eprintln!("Dashboard update failed: {e}");
+            let mut data = data.write().await;
+            if let serenity::Error::Http(http_error) = &e
+                && http_error.status_code().map(|status| status.as_u16()) == Some(404)
+            {
+                data.dashboards.remove(&guild_id);
+            } else if let Some(dashboard) = data.dashboards.get_mut(&guild_id) {
+                dashboard.invalidate();
+            }
+        }
+    }
+
+    data.read().await.save().await
+}
+
+/// Runs after every command, so any change of HP, SAN, Luck, MP, wounds or insanity reaches the dashboards.
+pub async fn post_command(ctx: Context<'_>) {
+    if let Err(e) = refresh_dashboards(ctx.http(), &ctx.data().data).await {
+        eprintln!("{e}");
+    }
+}
+
+/// Posts and pins a live party status in this channel
+#[poise::command(prefix_command, slash_command, rename = "start", guild_only, check = "is_user_gm")]
+async fn start_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("Guild only")?.get();
+    let mut dashboard;
+    {
+        let data = ctx.data().data.read().await;
+        let lang = data
+            .users
+            .get(&ctx.author().id.get())
+            .map(|u| u.lang)
+            .unwrap_or_default();
+        dashboard = Dashboard::new(ctx.channel_id().get(), 0, lang, &data.users);
+    }
+
+    let message = ctx
+        .channel_id()
+        .send_message(
+            ctx,
+            CreateMessage::new().embed(dashboard.to_message_content().to_embed()),
+        )
+        .await?;
+    if let Err(e) = message.pin(ctx).await {
+        eprintln!("Dashboard pin failed: {e}");
+    }
+    dashboard.message_id = message.id.get();
+
+    let lang = dashboard.lang;
+    let previous = ctx.data().data.write().await.dashboards.insert(guild_id, dashboard);
+    if let Some(previous) = previous {
+        let _ = ChannelId::new(previous.channel_id)
+            .delete_message(ctx, MessageId::new(previous.message_id))
+            .await;
+    }
+
+    let mc = MessageContent {
+        description: locale_text_by_tag_lang(lang, LocaleTag::DashboardStarted),
+        ..Default::default()
+    };
+    ctx.send(CreateReply::default().embed(mc.to_embed()).ephemeral(true))
+        .await?;
+
+    ctx.data().data.write().await.save().await
+}
+
+#[poise::command(prefix_command, slash_command, rename = "stop", guild_only, check = "is_user_gm")]
+async fn stop_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("Guild only")?.get();
+    let lang;
+    let dashboard;
+    {
+        let mut data = ctx.data().data.write().await;
+        lang = data
+            .users
+            .get(&ctx.author().id.get())
+            .map(|u| u.lang)
+            .unwrap_or_default();
+        dashboard = data
+            .dashboards
+            .remove(&guild_id)
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoDashboard))?;
+    }
+
+    let _ = ChannelId::new(dashboard.channel_id)
+        .delete_message(ctx, MessageId::new(dashboard.message_id))
+        .await;
+
+    let mc = MessageContent {
+        description: locale_text_by_tag_lang(lang, LocaleTag::DashboardStopped),
+        ..Default::default()
+    };
+    ctx.send(CreateReply::default().embed(mc.to_embed()).ephemeral(true))
+        .await?;
+
+    ctx.data().data.write().await.save().await
+}
@@ -1,8 +1,8 @@
 use crate::{
     bot_data::*,
     commands::gm::is_user_gm,
-    message::MessageContent,
-    types::{Context, Error},
+    message::{MessageContent, ToEmbed},
+    types::{Context, ContextData, Error},
 };
 use poise::{
     CreateReply,
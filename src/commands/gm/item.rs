@@ -2,7 +2,7 @@ use crate::{
     character::Item,
     commands::autocomplete::*,
     locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
-    message::MessageContent,
+    message::{MessageContent, ToEmbed},
     types::*,
 };
 use poise::CreateReply;
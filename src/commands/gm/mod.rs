@@ -1,31 +1,59 @@
+pub mod attribute;
 pub mod character;
 pub mod characters;
+pub mod dashboard;
 pub mod db;
 pub mod item;
+pub mod roll;
+pub mod ruleset;
+pub mod session;
+pub mod settings;
+pub mod skill;
+pub mod time;
 pub mod weapon;
 
-use crate::types::{Context, Error};
+pub use super::is_user_gm;
+use crate::{
+    character::Character,
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    types::{Context, Error},
+};
+use poise::CreateReply;
 
-pub async fn is_user_gm(ctx: Context<'_>) -> Result<bool, Error> {
-    if let Some(guild_id) = ctx.guild_id() {
-        let gm_role_id;
-        {
-            let data = ctx.data().data.read().await;
-            let guild = guild_id
-                .to_guild_cached(ctx.serenity_context())
-                .ok_or("Guild not in cache")?;
+/// Applies `edit` to a character of any player and posts the returned description.
+/// The closure gets the Keeper's language.
+pub async fn edit_any_character(
+    ctx: Context<'_>,
+    character_name: &str,
+    edit: impl FnOnce(LocaleLang, &mut Character) -> Result<String, Error>,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let mut data = ctx.data().data.write().await;
+        let lang = data
+            .users
+            .get(&ctx.author().id.get())
+            .map(|u| u.lang)
+            .unwrap_or_default();
+        let character = data
+            .users
+            .values_mut()
+            .find_map(|user_data| user_data.characters.get_mut(character_name))
+            .ok_or(format!(
+                "{}: `{}`",
+                locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+                character_name
+            ))?;
 
-            gm_role_id = guild
-                .role_by_name(&data.gm_role_name)
-                .ok_or(format!("No such role `{}`", &data.gm_role_name))?
-                .id
-                .get();
-        }
-        if let Ok(member) = guild_id.member(ctx.serenity_context(), ctx.author().id).await {
-            let has_role = member.roles.iter().any(|r| r.get() == gm_role_id);
-            return Ok(has_role);
-        }
+        mc = MessageContent {
+            title: format!("`{}`", character.name),
+            description: edit(lang, character)?,
+            ..Default::default()
+        };
     }
 
-    Ok(false)
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
 }
@@ -0,0 +1,150 @@
+use crate::roller::croll::croll_impl;
+use crate::{
+    commands::{
+        autocomplete::character::{autocomplete_any_active_character, autocomplete_character_skills},
+        gm::is_user_gm,
+    },
+    locale::{LocaleTag, locale_text_by_tag_lang, locale_text_fmt},
+    message::{MessageContent, ToEmbed},
+    roller::{dice_rng::RealRng, roll::roll_query, roll_log::RollRecord},
+    types::*,
+};
+use poise::{
+    CreateReply,
+    serenity_prelude::{CreateMessage, UserId},
+};
+
+/// Keeper from the house rules, otherwise the server owner.
+async fn keeper_id(ctx: Context<'_>) -> Result<UserId, Error> {
+    let guild_id = ctx.guild_id().ok_or("Guild only")?;
+    let keeper_id = ctx.data().data.read().await.settings(Some(guild_id.get())).keeper_id;
+    match keeper_id {
+        Some(keeper_id) => Ok(UserId::new(keeper_id)),
+        None => Ok(guild_id
+            .to_guild_cached(ctx.serenity_context())
+            .ok_or("Guild not in cache")?
+            .owner_id),
+    }
+}
+
+/// Sends the full result to the Keeper and only a notice to the channel.
+async fn whisper(ctx: Context<'_>, mut mc: MessageContent) -> Result<(), Error> {
+    let keeper_id = keeper_id(ctx).await?;
+    let lang;
+    {
+        let data = ctx.data().data.read().await;
+        lang = data
+            .users
+            .get(&ctx.author().id.get())
+            .map(|u| u.lang)
+            .unwrap_or_default();
+    }
+
+    mc.title = format!("{} ({})", mc.title, ctx.author().name);
+    keeper_id
+        .direct_message(ctx, CreateMessage::new().embed(mc.to_embed()))
+        .await?;
+
+    let notice = MessageContent {
+        description: format!(
+            "🤫 {}",
+            locale_text_fmt(lang, LocaleTag::WhisperedRoll, &[&ctx.author().name])
+        ),
+        ..Default::default()
+    };
+    ctx.send(CreateReply::default().embed(notice.to_embed())).await?;
+    Ok(())
+}
+
+/// Percentile roll whispered to the Keeper
+#[poise::command(prefix_command, slash_command, rename = "wcroll", guild_only)]
+pub async fn wcroll_cmd(ctx: Context<'_>, #[name_localized("pl", "próg")] threshold: String) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let lang = data.users.get(&user_id).map(|u| u.lang).unwrap_or_default();
+        let threshold = data.resolve_threshold(user_id, &threshold)?;
+        let croll_result = croll_impl(
+            &threshold,
+            data.roll_ruleset(user_id, ctx.guild_id().map(|guild_id| guild_id.get())),
+        )?;
+        data.record_roll(RollRecord::new(user_id, &croll_result).hidden());
+        mc = MessageContent::from_croll_result(lang, &croll_result, false, false);
+    }
+
+    whisper(ctx, mc).await?;
+
+    ctx.data().data.write().await.save().await
+}
+
+/// Dice roll whispered to the Keeper
+#[poise::command(prefix_command, slash_command, rename = "wroll", guild_only)]
+pub async fn wroll_cmd(ctx: Context<'_>, #[name_localized("pl", "kości")] dice: String) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let data = ctx.data().data.read().await;
+        let lang = data.users.get(&user_id).map(|u| u.lang).unwrap_or_default();
+        let dice = data.resolve_roll(user_id, &dice)?;
+        let roll_result;
+        {
+            let mut rng = RealRng::new();
+            roll_result = roll_query(&mut rng, &dice)?
+        }
+        mc = MessageContent::from_dice_result(lang, roll_result, false);
+    }
+
+    whisper(ctx, mc).await
+}
+
+/// Secret skill check of a player's character, shown only to the Keeper
+#[poise::command(prefix_command, slash_command, rename = "gmroll", check = "is_user_gm")]
+pub async fn gmroll_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "imię")]
+    character_name: String,
+    #[autocomplete = "autocomplete_character_skills"]
+    #[name_localized("pl", "umiejętność")]
+    skill: String,
+    #[name_localized("pl", "dodatkowe_kości")] modifier_dice: Option<String>,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let gm_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let lang = data.users.get(&gm_id).map(|u| u.lang).unwrap_or_default();
+        let user_id = data.active_character_owner(gm_id, &character_name, lang)?;
+        let character = data
+            .users
+            .get(&user_id)
+            .and_then(|user_data| user_data.characters.get(&character_name))
+            .ok_or(format!(
+                "{}: `{}`",
+                locale_text_by_tag_lang(lang, LocaleTag::CharacterNotFound),
+                &character_name
+            ))?;
+        let skill = character
+            .get_skill(&skill)
+            .ok_or_else(|| format!("{}: {}", locale_text_by_tag_lang(lang, LocaleTag::NoSuchSkill), skill))?;
+        let skill_name = skill.name.get(lang);
+        let croll_query = format!("{}{}", skill.value, modifier_dice.unwrap_or_default());
+        let croll_result = croll_impl(&croll_query, character.ruleset())?;
+
+        mc = MessageContent::from_croll_result(lang, &croll_result, false, false)
+            .with_skill_name(&skill_name)
+            .with_character_name(&character_name);
+        data.record_roll(
+            RollRecord::new(user_id, &croll_result)
+                .with_character(&character_name)
+                .with_skill(&skill_name)
+                .hidden(),
+        );
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed()).ephemeral(true))
+        .await?;
+
+    ctx.data().data.write().await.save().await
+}
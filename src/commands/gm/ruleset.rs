@@ -0,0 +1,87 @@
+use crate::{
+    commands::gm::is_user_gm,
+    locale::{LocaleTag, locale_text_by_tag_lang, locale_text_fmt},
+    message::{MessageContent, ToEmbed},
+    ruleset::RulesetKind,
+    types::*,
+};
+use poise::CreateReply;
+
+pub async fn autocomplete_ruleset<'a>(ctx: Context<'a>, partial: &'a str) -> Vec<String> {
+    let user_id = ctx.author().id.get();
+    let data = ctx.data().data.read().await;
+    let lang = data.users.get(&user_id).map(|u| u.lang).unwrap_or_default();
+
+    RulesetKind::ALL
+        .iter()
+        .map(|ruleset| locale_text_by_tag_lang(lang, ruleset.to_locale_tag()))
+        .filter(|name| name.to_lowercase().contains(&partial.to_lowercase()))
+        .collect()
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "ruleset",
+    aliases("zasady"),
+    guild_only,
+    subcommands("show_cmd", "set_cmd")
+)]
+pub async fn ruleset_cmd(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, rename = "show", aliases("pokaż"), guild_only)]
+async fn show_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let data = ctx.data().data.read().await;
+        let lang = data.users.get(&user_id).map(|u| u.lang).unwrap_or_default();
+        let ruleset = data.ruleset(ctx.guild_id().map(|guild_id| guild_id.get()));
+        mc = MessageContent {
+            title: locale_text_by_tag_lang(lang, LocaleTag::Ruleset),
+            description: locale_text_by_tag_lang(lang, ruleset.to_locale_tag()),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+    Ok(())
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "set",
+    aliases("ustaw"),
+    guild_only,
+    check = "is_user_gm"
+)]
+async fn set_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_ruleset"]
+    #[name_localized("pl", "zasady")]
+    ruleset: String,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let lang = data.users.get(&user_id).map(|u| u.lang).unwrap_or_default();
+        let guild_id = ctx.guild_id().ok_or("Guild only")?.get();
+        let kind = RulesetKind::from_text(&ruleset)
+            .ok_or_else(|| locale_text_fmt(lang, LocaleTag::UnknownRuleset, &[&ruleset]))?;
+        data.settings_mut(guild_id).ruleset = kind;
+
+        mc = MessageContent {
+            title: locale_text_by_tag_lang(lang, LocaleTag::Ruleset),
+            description: format!("✅ {}", locale_text_by_tag_lang(lang, kind.to_locale_tag())),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
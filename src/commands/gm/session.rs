@@ -0,0 +1,114 @@
+use crate::{
+    commands::gm::is_user_gm,
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    session::{Session, SessionRecap},
+    types::*,
+};
+use poise::{CreateReply, serenity_prelude::CreateAttachment};
+
+/// Discord embed descriptions are limited to 4096 characters; the exported file has the full recap.
+const MAX_EMBED_DESCRIPTION: usize = 4000;
+
+fn recap_reply(recap: &SessionRecap, lang: LocaleLang) -> CreateReply {
+    let mut description = recap.to_markdown_body(lang);
+    if description.chars().count() > MAX_EMBED_DESCRIPTION {
+        description = format!(
+            "{}…",
+            description.chars().take(MAX_EMBED_DESCRIPTION).collect::<String>()
+        );
+    }
+    let mc = MessageContent {
+        title: recap.title(lang),
+        description,
+        ..Default::default()
+    };
+    CreateReply::default()
+        .embed(mc.to_embed())
+        .attachment(CreateAttachment::bytes(recap.to_markdown(lang), recap.file_name()))
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "session",
+    aliases("sesja"),
+    subcommands("start_cmd", "end_cmd", "recap_cmd")
+)]
+pub async fn session_cmd(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, rename = "start", check = "is_user_gm")]
+async fn start_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let lang = data.users.entry(user_id).or_default().lang;
+        if data.session.is_some() {
+            return Err(locale_text_by_tag_lang(lang, LocaleTag::SessionAlreadyStarted).into());
+        }
+        let session = Session::start(&data.users);
+
+        mc = MessageContent {
+            title: locale_text_by_tag_lang(lang, LocaleTag::SessionStarted),
+            description: session
+                .snapshots
+                .iter()
+                .map(|snapshot| format!("`{}`", snapshot.name))
+                .collect::<Vec<_>>()
+                .join(", "),
+            ..Default::default()
+        };
+        data.session = Some(session);
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "end",
+    aliases("koniec"),
+    check = "is_user_gm"
+)]
+async fn end_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let reply;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let lang = data.users.entry(user_id).or_default().lang;
+        let session = data
+            .session
+            .take()
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoActiveSession))?;
+        reply = recap_reply(&session.recap(&data.users, &data.rolls, lang), lang);
+    }
+
+    ctx.send(reply).await?;
+
+    ctx.data().data.write().await.save().await
+}
+
+/// Recap of the session so far, without ending it.
+#[poise::command(prefix_command, slash_command, rename = "recap", aliases("podsumowanie"))]
+async fn recap_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let reply;
+    {
+        let user_id = ctx.author().id.get();
+        let data = ctx.data().data.read().await;
+        let lang = data.users.get(&user_id).map(|u| u.lang).unwrap_or_default();
+        let session = data
+            .session
+            .as_ref()
+            .ok_or(locale_text_by_tag_lang(lang, LocaleTag::NoActiveSession))?;
+        reply = recap_reply(&session.recap(&data.users, &data.rolls, lang), lang);
+    }
+
+    ctx.send(reply).await?;
+    Ok(())
+}
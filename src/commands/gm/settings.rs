@@ -0,0 +1,85 @@
+use crate::{
+    commands::gm::is_user_gm,
+    locale::{LocaleTag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    settings::{SettingKey, SettingsError},
+    types::*,
+};
+use poise::CreateReply;
+
+async fn autocomplete_setting<'a>(_ctx: Context<'a>, partial: &'a str) -> Vec<String> {
+    SettingKey::ALL
+        .iter()
+        .map(|key| key.name().to_string())
+        .filter(|name| name.contains(&partial.to_lowercase()))
+        .collect()
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "settings",
+    aliases("ustawienia"),
+    guild_only,
+    subcommands("show_cmd", "set_cmd")
+)]
+pub async fn settings_cmd(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, rename = "show", aliases("pokaż"), guild_only)]
+async fn show_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let data = ctx.data().data.read().await;
+        let lang = data.users.get(&user_id).map(|u| u.lang).unwrap_or_default();
+        let settings = data.settings(ctx.guild_id().map(|guild_id| guild_id.get()));
+        mc = MessageContent {
+            title: locale_text_by_tag_lang(lang, LocaleTag::Settings),
+            description: settings.to_string(lang),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+    Ok(())
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "set",
+    aliases("ustaw"),
+    guild_only,
+    check = "is_user_gm"
+)]
+async fn set_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_setting"]
+    #[name_localized("pl", "ustawienie")]
+    setting: String,
+    #[name_localized("pl", "wartość")] value: String,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let lang = data.users.get(&user_id).map(|u| u.lang).unwrap_or_default();
+        let guild_id = ctx.guild_id().ok_or("Guild only")?.get();
+        let key = SettingKey::from_name(&setting)
+            .ok_or_else(|| SettingsError::UnknownSetting(setting.clone()).to_string(lang))?;
+        let settings = data.settings_mut(guild_id);
+        settings.set(key, &value).map_err(|e| e.to_string(lang))?;
+
+        mc = MessageContent {
+            title: locale_text_by_tag_lang(lang, LocaleTag::Settings),
+            description: format!("✅ {}", settings.to_string(lang)),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
@@ -0,0 +1,226 @@
+use crate::{
+    character::Character,
+    commands::{
+        autocomplete::character::{autocomplete_any_active_character, autocomplete_character_skills},
+        gm::{edit_any_character, is_user_gm},
+    },
+    locale::{LocaleLang, LocaleTag, locale_dice, locale_tag_by_str, locale_text_by_tag_lang},
+    roller::{dice_rng::RealRng, roll::roll_query},
+    types::*,
+};
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "gmskill",
+    aliases("gmumiejętność"),
+    subcommands("add_cmd", "set_cmd", "change_cmd", "remove_cmd", "mark_cmd", "unmark_cmd")
+)]
+pub async fn gmskill_cmd(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Cthulhu Mythos lowers maximum Sanity, so the Keeper sees the new limit right away.
+fn with_max_sanity(lang: LocaleLang, character: &Character, name: &str, description: String) -> String {
+    if locale_tag_by_str(name) == Some(LocaleTag::CthulhuMythos) {
+        format!(
+            "{}\n\n{} **{}**",
+            description,
+            locale_text_by_tag_lang(lang, LocaleTag::MaxSanitySet),
+            character.sanity.max
+        )
+    } else {
+        description
+    }
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "add",
+    aliases("dodaj"),
+    check = "is_user_gm"
+)]
+async fn add_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "imię")]
+    character_name: String,
+    #[name_localized("pl", "nazwa")] name: String,
+    #[name_localized("pl", "wartość")] value: i32,
+) -> Result<(), Error> {
+    edit_any_character(ctx, &character_name, |lang, character| {
+        character
+            .add_skill(&name, value)
+            .map_err(|e| Error::from(e.to_string(lang)))?;
+        Ok(format!("➕ `{name}` `{value}`"))
+    })
+    .await
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "set",
+    aliases("ustaw"),
+    check = "is_user_gm"
+)]
+async fn set_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "imię")]
+    character_name: String,
+    #[autocomplete = "autocomplete_character_skills"]
+    #[name_localized("pl", "nazwa")]
+    name: String,
+    #[name_localized("pl", "wartość")] value: i32,
+) -> Result<(), Error> {
+    edit_any_character(ctx, &character_name, |lang, character| {
+        character
+            .set_skill(&name, value)
+            .map_err(|e| Error::from(e.to_string(lang)))?;
+        Ok(with_max_sanity(
+            lang,
+            character,
+            &name,
+            format!("`{name}` ⬅️ `{value}`"),
+        ))
+    })
+    .await
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "change",
+    aliases("zmiana"),
+    check = "is_user_gm"
+)]
+async fn change_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "imię")]
+    character_name: String,
+    #[autocomplete = "autocomplete_character_skills"]
+    #[name_localized("pl", "nazwa")]
+    name: String,
+    #[name_localized("pl", "wartość")] query: String,
+) -> Result<(), Error> {
+    edit_any_character(ctx, &character_name, |lang, character| {
+        let query = locale_dice(lang, &query);
+        let roll_result;
+        {
+            let mut rng = RealRng::new();
+            roll_result = roll_query(&mut rng, &query)?;
+        }
+
+        character
+            .modify_skill(&name, roll_result.result_real())
+            .map_err(|e| Error::from(e.to_string(lang)))?;
+        let skill = character
+            .get_skill(&name)
+            .ok_or_else(|| format!("{}: {}", locale_text_by_tag_lang(lang, LocaleTag::NoSuchSkill), name))?;
+
+        Ok(with_max_sanity(
+            lang,
+            character,
+            &name,
+            format!(
+                "**{name}**\n{} **{:+}** = **{}** ({})",
+                skill.value - roll_result.result_real(),
+                roll_result.result_real(),
+                skill.value,
+                query,
+            ),
+        ))
+    })
+    .await
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "remove",
+    aliases("usuń"),
+    check = "is_user_gm"
+)]
+async fn remove_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "imię")]
+    character_name: String,
+    #[autocomplete = "autocomplete_character_skills"]
+    #[name_localized("pl", "nazwa")]
+    name: String,
+) -> Result<(), Error> {
+    edit_any_character(ctx, &character_name, |lang, character| {
+        character
+            .remove_skill(&name)
+            .map_err(|e| Error::from(e.to_string(lang)))?;
+        Ok(format!("❌ `{name}`"))
+    })
+    .await
+}
+
+fn set_marked(lang: LocaleLang, character: &mut Character, name: &str, marked: bool) -> Result<String, Error> {
+    let skill = character
+        .get_mut_skill(name)
+        .ok_or_else(|| format!("{}: {}", locale_text_by_tag_lang(lang, LocaleTag::NoSuchSkill), name))?;
+    skill.to_improve = marked;
+
+    Ok(format!(
+        "**{name}**\n{}",
+        locale_text_by_tag_lang(
+            lang,
+            if marked {
+                LocaleTag::SkillMarked
+            } else {
+                LocaleTag::SkillUnmarked
+            }
+        )
+    ))
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "mark",
+    aliases("zaznacz"),
+    check = "is_user_gm"
+)]
+async fn mark_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "imię")]
+    character_name: String,
+    #[autocomplete = "autocomplete_character_skills"]
+    #[name_localized("pl", "nazwa")]
+    name: String,
+) -> Result<(), Error> {
+    edit_any_character(ctx, &character_name, |lang, character| {
+        set_marked(lang, character, &name, true)
+    })
+    .await
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "unmark",
+    aliases("odznacz"),
+    check = "is_user_gm"
+)]
+async fn unmark_cmd(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_any_active_character"]
+    #[name_localized("pl", "imię")]
+    character_name: String,
+    #[autocomplete = "autocomplete_character_skills"]
+    #[name_localized("pl", "nazwa")]
+    name: String,
+) -> Result<(), Error> {
+    edit_any_character(ctx, &character_name, |lang, character| {
+        set_marked(lang, character, &name, false)
+    })
+    .await
+}
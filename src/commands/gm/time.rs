@@ -0,0 +1,256 @@
+use crate::{
+    calendar::Era,
+    character::{BoutKind, Character, ReadingPhase, recovery_roll_impl},
+    commands::{character::tome::apply_reading_phases, gm::is_user_gm},
+    locale::{LocaleLang, LocaleTag, locale_text_by_tag_lang, locale_text_fmt},
+    message::{MessageContent, ToEmbed},
+    roller::success_level::SuccessLevel,
+    types::*,
+    utils::parse_duration_hours,
+};
+use chrono::{NaiveDate, NaiveTime};
+use itertools::Itertools;
+use poise::CreateReply;
+
+pub async fn autocomplete_era<'a>(ctx: Context<'a>, partial: &'a str) -> Vec<String> {
+    let user_id = ctx.author().id.get();
+    let data = ctx.data().data.read().await;
+    let lang = data.users.get(&user_id).map(|u| u.lang).unwrap_or_default();
+
+    Era::ALL
+        .iter()
+        .map(|era| locale_text_by_tag_lang(lang, era.to_locale_tag()))
+        .filter(|name| name.to_lowercase().contains(&partial.to_lowercase()))
+        .collect()
+}
+
+fn era_from_text(text: &str) -> Option<Era> {
+    Era::from_name(text).or_else(|| {
+        Era::ALL.into_iter().find(|era| {
+            LocaleLang::all()
+                .any(|lang| locale_text_by_tag_lang(lang, era.to_locale_tag()).eq_ignore_ascii_case(text.trim()))
+        })
+    })
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "time",
+    aliases("czas"),
+    subcommands("show_cmd", "set_cmd", "advance_cmd")
+)]
+pub async fn time_cmd(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, rename = "show", aliases("pokaż"))]
+async fn show_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let lang = data.users.entry(user_id).or_default().lang;
+        mc = MessageContent {
+            description: data.calendar.to_string(lang),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+    Ok(())
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "set",
+    aliases("ustaw"),
+    check = "is_user_gm"
+)]
+async fn set_cmd(
+    ctx: Context<'_>,
+    #[description = "YYYY-MM-DD"]
+    #[name_localized("pl", "data")]
+    date: Option<String>,
+    #[description = "HH:MM"]
+    #[name_localized("pl", "godzina")]
+    time: Option<String>,
+    #[autocomplete = "autocomplete_era"]
+    #[name_localized("pl", "epoka")]
+    era: Option<String>,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let lang = data.users.entry(user_id).or_default().lang;
+        let invalid_time = |text: &str| -> Error {
+            format!("{}: `{}`", locale_text_by_tag_lang(lang, LocaleTag::InvalidTime), text).into()
+        };
+
+        let mut now = data.calendar.now;
+        if let Some(date) = &date {
+            let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").map_err(|_| invalid_time(date))?;
+            now = date.and_time(now.time());
+        }
+        if let Some(time) = &time {
+            let time = NaiveTime::parse_from_str(time.trim(), "%H:%M").map_err(|_| invalid_time(time))?;
+            now = now.date().and_time(time);
+        }
+        if let Some(era) = &era {
+            data.calendar.era = era_from_text(era).ok_or(format!("`{era}`?"))?;
+        }
+        data.calendar.now = now;
+
+        mc = MessageContent {
+            description: data.calendar.to_string(lang),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
+
+/// Longest single advance; every passed day is applied to every character one by one.
+const MAX_ADVANCE_DAYS: i32 = 366;
+
+/// Applies daily rules for each passed day and hourly durations to a single character.
+fn pass_time(character: &mut Character, hours: i32, days: i32, lang: LocaleLang) -> Result<Vec<String>, Error> {
+    let mut lines = vec![];
+    if character.dead {
+        return Ok(lines);
+    }
+
+    for _ in 0..days {
+        character.sanity.update_initial();
+        let healed = character.new_day();
+        if healed > 0 {
+            lines.push(format!(
+                "{}: ❤️ **{:+}**",
+                locale_text_by_tag_lang(lang, LocaleTag::NaturalHealing),
+                healed
+            ));
+        }
+
+        if character.can_roll_recovery().is_ok() {
+            let (croll_result, _, healed) = recovery_roll_impl(character, lang)?;
+            let success = croll_result.success_level >= SuccessLevel::Success;
+            let mut line = format!(
+                "{}: {}",
+                locale_text_by_tag_lang(lang, LocaleTag::RecoveryRoll),
+                croll_result.success_level.to_string_lang(lang)
+            );
+            if success {
+                line = format!(
+                    "{} ❤️ **{:+}**\n**{}**",
+                    line,
+                    healed,
+                    locale_text_by_tag_lang(lang, LocaleTag::MajorWoundHealed)
+                );
+            }
+            lines.push(line);
+        }
+    }
+
+    if let Some(tome) = character.tomes.iter_mut().find(|t| t.reading) {
+        let finished = tome.read(hours).map_err(|e| Error::from(e.to_string(lang)))?;
+        tome.reading = tome.phase != ReadingPhase::Full;
+        let tome = tome.clone();
+        lines.push(format!(
+            "📖 `{}` {}: {}",
+            tome.name,
+            locale_text_by_tag_lang(lang, LocaleTag::ReadingProgress),
+            tome.progress()
+        ));
+        let (description, _) = apply_reading_phases(character, &tome, &finished, lang, BoutKind::Summary)?;
+        if !description.is_empty() {
+            lines.push(description.trim().to_string());
+        }
+    }
+
+    for episode in character.pass_insanity_time(hours) {
+        lines.push(format!(
+            "{}: {}",
+            locale_text_by_tag_lang(lang, LocaleTag::InsanityEnded),
+            episode.to_string(lang)
+        ));
+    }
+
+    for spell in character.pass_spell_time(hours) {
+        lines.push(format!(
+            "{}: ✨ **{}**",
+            locale_text_by_tag_lang(lang, LocaleTag::SpellEnded),
+            spell.name
+        ));
+    }
+
+    Ok(lines)
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "advance",
+    aliases("przesuń"),
+    check = "is_user_gm"
+)]
+async fn advance_cmd(
+    ctx: Context<'_>,
+    #[description = "e.g. 3h, 2d, 1w"]
+    #[name_localized("pl", "czas")]
+    time: String,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let user_id = ctx.author().id.get();
+        let mut data = ctx.data().data.write().await;
+        let lang = data.users.entry(user_id).or_default().lang;
+        let hours = parse_duration_hours(&time).ok_or(format!(
+            "{}: `{}`",
+            locale_text_by_tag_lang(lang, LocaleTag::InvalidTime),
+            time
+        ))?;
+        if hours > MAX_ADVANCE_DAYS * 24 {
+            return Err(locale_text_fmt(lang, LocaleTag::TimeAdvanceTooLong, &[&MAX_ADVANCE_DAYS]).into());
+        }
+
+        // Work on copies so a failure part way through leaves the calendar and characters untouched.
+        let mut calendar = data.calendar.clone();
+        let mut users = data.users.clone();
+        let days = calendar.advance(hours);
+        let mut description = format!("{} **+{}h**", calendar.to_string(lang), hours);
+        if days > 0 {
+            description = format!(
+                "{}\n{} (×{})",
+                description,
+                locale_text_by_tag_lang(lang, LocaleTag::NewDay),
+                days
+            );
+        }
+
+        for character in users
+            .values_mut()
+            .flat_map(|user_data| user_data.characters.values_mut())
+        {
+            let lines = pass_time(character, hours, days, lang)?;
+            if !lines.is_empty() {
+                description = format!("{}\n\n`{}`\n{}", description, character.name, lines.iter().join("\n"));
+            }
+        }
+        data.calendar = calendar;
+        data.users = users;
+
+        mc = MessageContent {
+            title: locale_text_by_tag_lang(lang, LocaleTag::TimeAdvanced),
+            description,
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
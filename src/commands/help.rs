@@ -0,0 +1,101 @@
+#[cfg(any(feature = "character-sheet", feature = "inline-rolls"))]
+use crate::commands::is_user_gm;
+use crate::{
+    command_list::{CommandCategory, CommandMeta},
+    commands::autocomplete::autocomplete_help,
+    types::{Context, ContextData, Error},
+};
+use poise::CreateReply;
+
+#[poise::command(prefix_command, slash_command, aliases("pomoc"))]
+pub async fn help(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_help"] command: Option<String>,
+) -> Result<(), Error> {
+    let all_commands = &ctx.framework().options().commands;
+
+    if let Some(command) = command {
+        if let Some(cmd) = all_commands
+            .iter()
+            .find(|el| el.name.to_ascii_lowercase().eq(command.to_ascii_lowercase().as_str()))
+        {
+            let mut short_desc: Option<&'static str> = None;
+            let mut long_desc: Option<&'static str> = None;
+            if let Some(meta) = cmd.custom_data.downcast_ref::<CommandMeta>() {
+                short_desc = Some(meta.short_desc);
+                long_desc = Some(meta.long_desc);
+            }
+            ctx.send(
+                CreateReply::default()
+                    .content(format!(
+                        "`/{}`\n\n{}\n\n{}",
+                        cmd.name,
+                        short_desc.unwrap_or_default(),
+                        long_desc.unwrap_or_default()
+                    ))
+                    .ephemeral(true),
+            )
+            .await?;
+        } else {
+            return Err(format!("No such command: `{command}`").into());
+        }
+    } else {
+        let commands = vec![
+            format_help(all_commands, CommandCategory::Basic),
+            #[cfg(feature = "character-sheet")]
+            format_help(all_commands, CommandCategory::Character),
+        ];
+
+        #[cfg(any(feature = "character-sheet", feature = "inline-rolls"))]
+        let commands = if is_user_gm(ctx).await? {
+            commands
+                .into_iter()
+                .chain(vec![format_help(all_commands, CommandCategory::GM)])
+                .collect()
+        } else {
+            commands
+        };
+
+        for command in commands {
+            ctx.send(CreateReply::default().content(command).ephemeral(true))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+fn format_help(commands: &[poise::Command<ContextData, Error>], category: CommandCategory) -> String {
+    let mut out = format!("**{category:?} Commands**\n");
+
+    let mut table = tabled::builder::Builder::new();
+
+    for cmd in commands.iter().filter(|cmd| {
+        if let Some(meta) = &cmd.custom_data.downcast_ref::<CommandMeta>() {
+            meta.category == category
+        } else {
+            false
+        }
+    }) {
+        table.push_record([
+            ["/".to_owned(), cmd.name.clone()].join(""),
+            cmd.custom_data
+                .downcast_ref::<CommandMeta>()
+                .unwrap()
+                .short_desc
+                .to_string(),
+        ]);
+    }
+
+    out.push_str("```text\n");
+    out.push_str(
+        table
+            .build()
+            .with(tabled::settings::Style::empty())
+            .to_string()
+            .as_str(),
+    );
+    out.push_str("```");
+
+    out
+}
@@ -0,0 +1,197 @@
+use crate::roller::croll::croll_impl;
+use crate::{
+    bot_data::Data,
+    commands::is_user_gm,
+    locale::{LocaleTag, locale_text_by_tag_lang},
+    message::{MessageContent, ToEmbed},
+    roller::{dice_rng::RealRng, roll::roll_query, roll_log::RollRecord},
+    types::*,
+};
+use lazy_static::lazy_static;
+use poise::{
+    CreateReply,
+    serenity_prelude::{self as serenity, AutoArchiveDuration, CreateMessage, CreateThread, Message},
+};
+use regex::Regex;
+use tokio::sync::RwLock;
+
+/// A message holds at most 10 embeds, one per roll.
+const MAX_INLINE_ROLLS: usize = 10;
+const MAX_THREAD_NAME: usize = 100;
+
+lazy_static! {
+    static ref INLINE_ROLL: Regex = Regex::new(r"\[\[\s*([^\[\]\s][^\[\]]*?)\s*\]\]").unwrap();
+    static ref CROLL_PREFIX: Regex = Regex::new(r"(?i)^croll\s+").unwrap();
+}
+
+#[derive(Debug, PartialEq)]
+enum InlineRoll {
+    Roll(String),
+    Croll(String),
+}
+
+/// `[[1d8+1d4]]` is a dice roll, `[[croll 45+]]` a skill check.
+fn find_inline_rolls(text: &str) -> Vec<InlineRoll> {
+    INLINE_ROLL
+        .captures_iter(text)
+        .map(|captures| {
+            let query = &captures[1];
+            match CROLL_PREFIX.find(query) {
+                Some(prefix) => InlineRoll::Croll(query[prefix.end()..].to_string()),
+                None => InlineRoll::Roll(query.to_string()),
+            }
+        })
+        .take(MAX_INLINE_ROLLS)
+        .collect()
+}
+
+/// Turns inline `[[1d6]]` and `[[croll 45]]` rolls in this channel on or off
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "inline_rolls",
+    aliases("rzuty_w_tekście"),
+    guild_only,
+    check = "is_user_gm"
+)]
+pub async fn inline_rolls_cmd(
+    ctx: Context<'_>,
+    #[name_localized("pl", "włączone")] enabled: bool,
+) -> Result<(), Error> {
+    let mc;
+    {
+        let mut data = ctx.data().data.write().await;
+        let lang = data
+            .users
+            .get(&ctx.author().id.get())
+            .map(|u| u.lang)
+            .unwrap_or_default();
+        let guild_id = ctx.guild_id().ok_or("Guild only")?.get();
+        data.settings_mut(guild_id)
+            .set_inline_rolls(ctx.channel_id().get(), enabled);
+
+        mc = MessageContent {
+            description: locale_text_by_tag_lang(
+                lang,
+                if enabled {
+                    LocaleTag::InlineRollsEnabled
+                } else {
+                    LocaleTag::InlineRollsDisabled
+                },
+            ),
+            ..Default::default()
+        };
+    }
+
+    ctx.send(CreateReply::default().embed(mc.to_embed())).await?;
+
+    ctx.data().data.write().await.save().await
+}
+
+/// Rolls inline markers of a message in an enabled channel and answers in a thread started from the message.
+pub async fn handle_inline_rolls(ctx: &serenity::Context, message: &Message, data: &RwLock<Data>) -> Result<(), Error> {
+    let Some(guild_id) = message.guild_id else {
+        return Ok(());
+    };
+    if message.author.bot {
+        return Ok(());
+    }
+    let rolls = find_inline_rolls(&message.content);
+    if rolls.is_empty() {
+        return Ok(());
+    }
+
+    let embeds;
+    {
+        let mut data = data.write().await;
+        let settings = data.settings(Some(guild_id.get()));
+        if !settings.inline_rolls(message.channel_id.get()) {
+            return Ok(());
+        }
+        let user_id = message.author.id.get();
+        let lang = data.users.get(&user_id).map(|u| u.lang).unwrap_or_default();
+        let ruleset = data.roll_ruleset(user_id, Some(guild_id.get()));
+
+        let mut contents = vec![];
+        for roll in &rolls {
+            let mc = match roll {
+                InlineRoll::Roll(query) => data
+                    .resolve_roll(user_id, query)
+                    .and_then(|query| roll_query(&mut RealRng::new(), &query))
+                    .map(|roll_result| MessageContent::from_dice_result(lang, roll_result, false)),
+                InlineRoll::Croll(query) => data
+                    .resolve_threshold(user_id, query)
+                    .and_then(|threshold| croll_impl(&threshold, ruleset))
+                    .map(|croll_result| {
+                        data.record_roll(RollRecord::new(user_id, &croll_result));
+                        MessageContent {
+                            footer: format!("croll {query}"),
+                            ..MessageContent::from_croll_result(lang, &croll_result, false, false)
+                        }
+                    }),
+            };
+            contents.push(mc.unwrap_or_else(|e| MessageContent {
+                title: "🚫".into(),
+                description: e.to_string(),
+                ..Default::default()
+            }));
+        }
+        embeds = contents.iter().map(MessageContent::to_embed).collect::<Vec<_>>();
+    }
+
+    let name = format!("🎲 {}", message.author.display_name())
+        .chars()
+        .take(MAX_THREAD_NAME)
+        .collect::<String>();
+    let thread = message
+        .channel_id
+        .create_thread_from_message(
+            ctx,
+            message.id,
+            CreateThread::new(name).auto_archive_duration(AutoArchiveDuration::OneHour),
+        )
+        .await;
+    match thread {
+        Ok(thread) => {
+            thread.send_message(ctx, CreateMessage::new().embeds(embeds)).await?;
+        }
+        // Messages inside threads can't start another thread.
+        Err(e) => {
+            eprintln!("Inline roll thread failed: {e}");
+            message
+                .channel_id
+                .send_message(ctx, CreateMessage::new().embeds(embeds).reference_message(message))
+                .await?;
+        }
+    }
+
+    data.read().await.save().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("I swing the crowbar [[1d8+1d4]]", vec![InlineRoll::Roll("1d8+1d4".into())])]
+    #[case("[[croll 45+]] and [[ 2d6 ]]", vec![InlineRoll::Croll("45+".into()), InlineRoll::Roll("2d6".into())])]
+    #[case("[[CROLL 60]]", vec![InlineRoll::Croll("60".into())])]
+    #[case("no rolls [1d6] [[]] [[ ]]", vec![])]
+    fn test_find_inline_rolls(#[case] text: &str, #[case] expected: Vec<InlineRoll>) {
+        assert_eq!(find_inline_rolls(text), expected);
+    }
+
+    #[rstest]
+    #[case("[[1d0]]")]
+    #[case("[[d0]]")]
+    fn test_zero_sided_inline_roll(#[case] text: &str) {
+        let rolls = find_inline_rolls(text);
+        assert!(matches!(&rolls[..], [InlineRoll::Roll(query)] if roll_query(&mut RealRng::new(), query).is_err()));
+    }
+
+    #[test]
+    fn test_inline_rolls_limit() {
+        assert_eq!(find_inline_rolls(&"[[1d6]]".repeat(20)).len(), MAX_INLINE_ROLLS);
+    }
+}
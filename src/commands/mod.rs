@@ -1,7 +1,40 @@
 pub mod autocomplete;
 pub mod basic;
+pub mod battle_tracker;
 #[cfg(feature = "character-sheet")]
 pub mod character;
 
 #[cfg(feature = "character-sheet")]
 pub mod gm;
+pub mod help;
+#[cfg(feature = "inline-rolls")]
+pub mod inline_roll;
+
+#[cfg(any(feature = "character-sheet", feature = "inline-rolls"))]
+use crate::types::{Context, Error};
+
+#[cfg(any(feature = "character-sheet", feature = "inline-rolls"))]
+pub async fn is_user_gm(ctx: Context<'_>) -> Result<bool, Error> {
+    if let Some(guild_id) = ctx.guild_id() {
+        let gm_role_id;
+        {
+            let data = ctx.data().data.read().await;
+            let gm_role_name = data.settings(Some(guild_id.get())).gm_role_name;
+            let guild = guild_id
+                .to_guild_cached(ctx.serenity_context())
+                .ok_or("Guild not in cache")?;
+
+            gm_role_id = guild
+                .role_by_name(&gm_role_name)
+                .ok_or(format!("No such role `{gm_role_name}`"))?
+                .id
+                .get();
+        }
+        if let Ok(member) = guild_id.member(ctx.serenity_context(), ctx.author().id).await {
+            let has_role = member.roles.iter().any(|r| r.get() == gm_role_id);
+            return Ok(has_role);
+        }
+    }
+
+    Ok(false)
+}
@@ -1,128 +1,35 @@
-mod bot_data;
-#[cfg(feature = "character-sheet")]
-mod character;
 mod command_list;
 mod commands;
-mod locale;
 mod message;
-mod roller;
+#[cfg(feature = "rest-api")]
+mod rest_api;
 mod types;
-mod utils;
 
-use crate::command_list::{CommandCategory, CommandMeta};
 #[cfg(feature = "character-sheet")]
-use crate::commands::gm::is_user_gm;
-use bot_data::{ContextData, Data};
-use command_list::command_list;
-use commands::autocomplete::autocomplete_help;
-use poise::serenity_prelude::{Http, HttpBuilder};
-use poise::{
-    CreateReply,
-    serenity_prelude::{ClientBuilder, GatewayIntents},
+use crate::commands::gm::dashboard::{post_command, refresh_dashboards};
+#[cfg(feature = "inline-rolls")]
+use crate::commands::inline_roll::handle_inline_rolls;
+use crate::{
+    command_list::command_list,
+    commands::battle_tracker::handle_tracker_button,
+    types::{ContextData, Error, FrameworkError},
 };
+use bot_data::Data;
+use cthulhu_roller_core::{bot_data, locale, roller};
+#[cfg(feature = "character-sheet")]
+use cthulhu_roller_core::{calendar, character, dashboard, ruleset, session, settings, utils};
+use poise::serenity_prelude::{self as serenity, ClientBuilder, GatewayIntents, Http, HttpBuilder};
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use types::{Context, Error, FrameworkError};
-
-#[poise::command(prefix_command, slash_command, aliases("pomoc"))]
-pub async fn help(
-    ctx: Context<'_>,
-    #[autocomplete = "autocomplete_help"] command: Option<String>,
-) -> Result<(), Error> {
-    let all_commands = &ctx.framework().options().commands;
-
-    if let Some(command) = command {
-        if let Some(cmd) = all_commands
-            .iter()
-            .find(|el| el.name.to_ascii_lowercase().eq(command.to_ascii_lowercase().as_str()))
-        {
-            let mut short_desc: Option<&'static str> = None;
-            let mut long_desc: Option<&'static str> = None;
-            if let Some(meta) = cmd.custom_data.downcast_ref::<CommandMeta>() {
-                short_desc = Some(meta.short_desc);
-                long_desc = Some(meta.long_desc);
-            }
-            ctx.send(
-                CreateReply::default()
-                    .content(format!(
-                        "`/{}`\n\n{}\n\n{}",
-                        cmd.name,
-                        short_desc.unwrap_or_default(),
-                        long_desc.unwrap_or_default()
-                    ))
-                    .ephemeral(true),
-            )
-            .await?;
-        } else {
-            return Err(format!("No such command: `{command}`").into());
-        }
-    } else {
-        let commands = vec![
-            format_help(all_commands, CommandCategory::Basic),
-            #[cfg(feature = "character-sheet")]
-            format_help(all_commands, CommandCategory::Character),
-        ];
-
-        #[cfg(feature = "character-sheet")]
-        let commands = if is_user_gm(ctx).await? {
-            commands
-                .into_iter()
-                .chain(vec![format_help(all_commands, CommandCategory::GM)])
-                .collect()
-        } else {
-            commands
-        };
-
-        for command in commands {
-            ctx.send(CreateReply::default().content(command).ephemeral(true))
-                .await?;
-        }
-    }
-
-    Ok(())
-}
-
-fn format_help(commands: &[poise::Command<ContextData, Error>], category: CommandCategory) -> String {
-    let mut out = format!("**{category:?} Commands**\n");
-
-    let mut table = tabled::builder::Builder::new();
-
-    for cmd in commands.iter().filter(|cmd| {
-        if let Some(meta) = &cmd.custom_data.downcast_ref::<CommandMeta>() {
-            meta.category == category
-        } else {
-            false
-        }
-    }) {
-        table.push_record([
-            ["/".to_owned(), cmd.name.clone()].join(""),
-            cmd.custom_data
-                .downcast_ref::<CommandMeta>()
-                .unwrap()
-                .short_desc
-                .to_string(),
-        ]);
-    }
-
-    out.push_str("```text\n");
-    out.push_str(
-        table
-            .build()
-            .with(tabled::settings::Style::empty())
-            .to_string()
-            .as_str(),
-    );
-    out.push_str("```");
-
-    out
-}
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
     dotenvy::dotenv()?;
     let discord_token = std::env::var("DISCORD_TOKEN")?;
 
-    let data = Data::load().await;
+    let data = Arc::new(RwLock::new(Data::load().await));
+    #[cfg(feature = "rest-api")]
+    let api_data = data.clone();
 
     let commands_vec = command_list();
 
@@ -130,14 +37,25 @@ async fn main() -> Result<(), Error> {
         .options(poise::FrameworkOptions {
             commands: commands_vec,
             on_error: |error| Box::pin(handle_error(error)),
+            event_handler: |ctx, event, _framework, data| Box::pin(event_handler(ctx, event, data)),
+            #[cfg(feature = "character-sheet")]
+            post_command: |ctx| Box::pin(post_command(ctx)),
             ..Default::default()
         })
         .setup(|ctx, _ready, framework| {
             Box::pin(async move {
                 poise::builtins::register_globally(ctx, &framework.options().commands).await?;
-                Ok(ContextData {
-                    data: Arc::new(RwLock::new(data)),
-                })
+                // Dashboards pick up changes made while the bot was offline, e.g. by editing db.json.
+                #[cfg(feature = "character-sheet")]
+                {
+                    data.write()
+                        .await
+                        .dashboards
+                        .values_mut()
+                        .for_each(|dashboard| dashboard.invalidate());
+                    refresh_dashboards(&ctx.http, &data).await?;
+                }
+                Ok(ContextData { data })
             })
         })
         .build();
@@ -149,13 +67,40 @@ async fn main() -> Result<(), Error> {
         Http::new(&discord_token)
     };
 
-    let mut client = ClientBuilder::new_with_http(http, GatewayIntents::non_privileged())
-        .framework(framework)
-        .await?;
+    let intents = GatewayIntents::non_privileged();
+    #[cfg(feature = "inline-rolls")]
+    let intents = intents | GatewayIntents::MESSAGE_CONTENT;
+    let mut client = ClientBuilder::new_with_http(http, intents).framework(framework).await?;
+
+    #[cfg(feature = "rest-api")]
+    {
+        let http = client.http.clone();
+        tokio::spawn(async move {
+            if let Err(e) = rest_api::serve(api_data, http).await {
+                eprintln!("REST API error: {e}");
+            }
+        });
+    }
 
     Ok(client.start().await?)
 }
 
+/// Errors are only logged, because the framework treats event handler errors as fatal.
+async fn event_handler(ctx: &serenity::Context, event: &serenity::FullEvent, data: &ContextData) -> Result<(), Error> {
+    let result = match event {
+        serenity::FullEvent::InteractionCreate {
+            interaction: serenity::Interaction::Component(interaction),
+        } => handle_tracker_button(ctx, interaction, &data.data).await,
+        #[cfg(feature = "inline-rolls")]
+        serenity::FullEvent::Message { new_message } => handle_inline_rolls(ctx, new_message, &data.data).await,
+        _ => Ok(()),
+    };
+    if let Err(e) = result {
+        eprintln!("{e}");
+    }
+    Ok(())
+}
+
 fn any_to_str<T>(_: &T) -> String {
     std::any::type_name::<T>().into()
 }
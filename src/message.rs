@@ -0,0 +1,34 @@
+pub use cthulhu_roller_core::message::*;
+
+use cthulhu_roller_core::roller::success_level::SuccessLevel;
+#[cfg(feature = "character-sheet")]
+use poise::serenity_prelude::Embed;
+use poise::serenity_prelude::{Colour, CreateEmbed, CreateEmbedFooter};
+
+pub trait ToEmbed {
+    fn to_embed(&self) -> CreateEmbed;
+}
+
+impl ToEmbed for MessageContent {
+    fn to_embed(&self) -> CreateEmbed {
+        let mut embed = CreateEmbed::default();
+        embed = embed.title(&self.title);
+        embed = embed.description(&self.description);
+        embed = embed.footer(CreateEmbedFooter::new(&self.footer));
+        embed = embed.colour(match self.colour {
+            Some(colour) => Colour::from(colour),
+            None => Colour::from(SuccessLevel::Success.hex()),
+        });
+        embed
+    }
+}
+
+#[cfg(feature = "character-sheet")]
+pub fn from_embed(embed: Embed) -> MessageContent {
+    MessageContent {
+        title: embed.title.unwrap_or_default(),
+        description: embed.description.unwrap_or_default(),
+        footer: embed.footer.map_or_else(|| "".into(), |f| f.text),
+        colour: embed.colour.map_or_else(|| None, |c| Some(c.0)),
+    }
+}
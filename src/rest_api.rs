@@ -0,0 +1,386 @@
+use crate::{
+    bot_data::Data,
+    character::{Character, hp_impl, luck_impl, san_impl},
+    commands::gm::dashboard::refresh_dashboards,
+    roller::{battle::Battle, roll_log::RollRecord},
+    types::Error,
+};
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use poise::serenity_prelude::Http;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+const DEFAULT_ADDR: &str = "127.0.0.1:8080";
+const DEFAULT_ROLLS_LIMIT: usize = 50;
+
+#[derive(Clone)]
+pub struct ApiState {
+    pub data: Arc<RwLock<Data>>,
+    /// Writes are rejected when no token is configured.
+    pub token: Option<String>,
+    /// Used to refresh party dashboards after a write; tests run without Discord.
+    pub http: Option<Arc<Http>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ApiError {
+    Unauthorized,
+    NotFound,
+    BadRequest(String),
+    Internal(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            ApiError::Unauthorized => (StatusCode::UNAUTHORIZED, "Missing or invalid token".to_string()),
+            ApiError::NotFound => (StatusCode::NOT_FOUND, "Character not found".to_string()),
+            ApiError::BadRequest(message) => (StatusCode::BAD_REQUEST, message),
+            ApiError::Internal(message) => (StatusCode::INTERNAL_SERVER_ERROR, message),
+        };
+        (status, Json(serde_json::json!({ "error": message }))).into_response()
+    }
+}
+
+#[derive(Serialize)]
+struct CharacterEntry<'a> {
+    user_id: u64,
+    active: bool,
+    #[serde(flatten)]
+    character: &'a Character,
+}
+
+#[derive(Deserialize)]
+struct RollsQuery {
+    limit: Option<usize>,
+    user_id: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Stat {
+    Hp,
+    Sanity,
+    Luck,
+}
+
+#[derive(Deserialize)]
+struct ChangeRequest {
+    /// Same syntax as the `/hp`, `/sanity` and `/luck` commands, e.g. `-3` or `-1d6`.
+    delta: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ChangeResponse {
+    message: String,
+    character: Character,
+}
+
+pub fn router(state: ApiState) -> Router {
+    Router::new()
+        .route("/api/characters", get(characters))
+        .route("/api/party", get(party))
+        .route("/api/battle", get(battle))
+        .route("/api/rolls", get(rolls))
+        .route("/api/users/{user_id}/characters/{name}", get(character))
+        .route("/api/users/{user_id}/characters/{name}/{stat}", post(change))
+        .with_state(state)
+}
+
+/// Listens on `REST_API_ADDR`; writes need `Authorization: Bearer <REST_API_TOKEN>`.
+pub async fn serve(data: Arc<RwLock<Data>>, http: Arc<Http>) -> Result<(), Error> {
+    let addr = std::env::var("REST_API_ADDR").unwrap_or(DEFAULT_ADDR.to_string());
+    let token = std::env::var("REST_API_TOKEN").ok().filter(|token| !token.is_empty());
+    if token.is_none() {
+        println!("REST_API_TOKEN not set, REST API is read-only.");
+    }
+    let state = ApiState {
+        data,
+        token,
+        http: Some(http),
+    };
+
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
+    println!("REST API listening on {addr}");
+    axum::serve(listener, router(state)).await?;
+    Ok(())
+}
+
+fn entries(data: &Data, active_only: bool) -> Vec<CharacterEntry<'_>> {
+    let mut entries: Vec<_> = data
+        .users
+        .iter()
+        .flat_map(|(user_id, user_data)| {
+            user_data.characters.values().map(|character| CharacterEntry {
+                user_id: *user_id,
+                active: user_data.active_character.as_ref() == Some(&character.name),
+                character,
+            })
+        })
+        .filter(|entry| entry.active || !active_only)
+        .collect();
+    entries.sort_by(|a, b| a.character.cmp(b.character).then(a.user_id.cmp(&b.user_id)));
+    entries
+}
+
+async fn characters(State(state): State<ApiState>) -> Response {
+    let data = state.data.read().await;
+    Json(entries(&data, false)).into_response()
+}
+
+/// Active characters only, as shown on the party dashboard.
+async fn party(State(state): State<ApiState>) -> Response {
+    let data = state.data.read().await;
+    Json(entries(&data, true)).into_response()
+}
+
+async fn battle(State(state): State<ApiState>) -> Json<Option<Battle>> {
+    Json(state.data.read().await.battle.clone())
+}
+
+/// Latest rolls, oldest first; hidden GM rolls are left out.
+async fn rolls(State(state): State<ApiState>, Query(query): Query<RollsQuery>) -> Json<Vec<RollRecord>> {
+    let data = state.data.read().await;
+    let mut rolls: Vec<_> = data
+        .rolls
+        .iter()
+        .rev()
+        .filter(|record| !record.hidden && query.user_id.is_none_or(|user_id| record.user_id == user_id))
+        .take(query.limit.unwrap_or(DEFAULT_ROLLS_LIMIT))
+        .cloned()
+        .collect();
+    rolls.reverse();
+    Json(rolls)
+}
+
+async fn character(
+    State(state): State<ApiState>,
+    Path((user_id, name)): Path<(u64, String)>,
+) -> Result<Json<Character>, ApiError> {
+    let data = state.data.read().await;
+    data.users
+        .get(&user_id)
+        .and_then(|user_data| user_data.characters.get(&name))
+        .cloned()
+        .map(Json)
+        .ok_or(ApiError::NotFound)
+}
+
+async fn change(
+    State(state): State<ApiState>,
+    headers: HeaderMap,
+    Path((user_id, name, stat)): Path<(u64, String, Stat)>,
+    Json(request): Json<ChangeRequest>,
+) -> Result<Json<ChangeResponse>, ApiError> {
+    authorize(&headers, state.token.as_deref())?;
+
+    let response;
+    {
+        let mut data = state.data.write().await;
+        response = change_stat(&mut data, user_id, &name, stat, &request.delta)?;
+        data.save().await.map_err(|e| ApiError::Internal(e.to_string()))?;
+    }
+
+    if let Some(http) = &state.http
+        && let Err(e) = refresh_dashboards(http, &state.data).await
+    {
+        eprintln!("{e}");
+    }
+
+    Ok(Json(response))
+}
+
+fn authorize(headers: &HeaderMap, token: Option<&str>) -> Result<(), ApiError> {
+    let bearer = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    match (token, bearer) {
+        (Some(token), Some(bearer)) if token == bearer => Ok(()),
+        _ => Err(ApiError::Unauthorized),
+    }
+}
+
+/// Applies the change like the matching slash command of the character's owner would.
+pub fn change_stat(
+    data: &mut Data,
+    user_id: u64,
+    name: &str,
+    stat: Stat,
+    delta: &str,
+) -> Result<ChangeResponse, ApiError> {
+    let bout_kind = data.bout_kind();
+    let user_data = data.users.get_mut(&user_id).ok_or(ApiError::NotFound)?;
+    let lang = user_data.lang;
+    let character = user_data.characters.get_mut(name).ok_or(ApiError::NotFound)?;
+
+    let mc = match stat {
+        Stat::Hp => hp_impl(character, delta, lang),
+        Stat::Sanity => san_impl(character, delta, lang, bout_kind).map(|(mc, _)| mc),
+        Stat::Luck => luck_impl(character, delta, lang),
+    }
+    .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    Ok(ChangeResponse {
+        message: mc.description,
+        character: character.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bot_data::UserData, character::Attributes, roller::croll::croll, ruleset::RulesetKind};
+    use axum::body::{Body, to_bytes};
+    use axum::http::Request;
+    use rstest::rstest;
+    use tower::ServiceExt;
+
+    fn data() -> Data {
+        let character = Character::new(
+            "Harvey",
+            &None,
+            Attributes::new(50, 50, 50, 50, 50, 50, 50, 50).unwrap(),
+            50,
+            None,
+            RulesetKind::Classic,
+        )
+        .unwrap();
+        let mut user_data = UserData {
+            active_character: Some(character.name.clone()),
+            ..Default::default()
+        };
+        user_data.characters.insert(character.name.clone(), character);
+        let mut data = Data::default();
+        data.users.insert(1, user_data);
+        data
+    }
+
+    fn state() -> ApiState {
+        ApiState {
+            data: Arc::new(RwLock::new(data())),
+            token: Some("secret".into()),
+            http: None,
+        }
+    }
+
+    async fn send(state: ApiState, request: Request<Body>) -> (StatusCode, serde_json::Value) {
+        let response = router(state).oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    fn get(uri: &str) -> Request<Body> {
+        Request::get(uri).body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn reads_characters_and_party() {
+        let state = state();
+        let (status, json) = send(state.clone(), get("/api/party")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json[0]["name"], "Harvey");
+        assert_eq!(json[0]["user_id"], 1);
+        assert_eq!(json[0]["active"], true);
+
+        let (status, json) = send(state.clone(), get("/api/users/1/characters/Harvey")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["hp"]["current"], 10);
+
+        let (status, _) = send(state.clone(), get("/api/users/1/characters/Nobody")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, json) = send(state, get("/api/battle")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(json.is_null());
+    }
+
+    #[tokio::test]
+    async fn hidden_rolls_are_left_out() {
+        let state = state();
+        {
+            let mut data = state.data.write().await;
+            let croll_result = croll("50", 50, 0, 0, RulesetKind::Classic).unwrap();
+            data.record_roll(RollRecord::new(1, &croll_result).with_skill("Spot Hidden"));
+            data.record_roll(RollRecord::new(2, &croll_result).hidden());
+            data.record_roll(RollRecord::new(2, &croll_result).with_skill("Dodge"));
+        }
+
+        let (_, json) = send(state.clone(), get("/api/rolls")).await;
+        assert_eq!(json.as_array().unwrap().len(), 2);
+        assert_eq!(json[1]["skill"], "Dodge");
+
+        let (_, json) = send(state.clone(), get("/api/rolls?limit=1")).await;
+        assert_eq!(json.as_array().unwrap().len(), 1);
+        assert_eq!(json[0]["skill"], "Dodge");
+
+        let (_, json) = send(state, get("/api/rolls?user_id=1")).await;
+        assert_eq!(json[0]["skill"], "Spot Hidden");
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some("Bearer wrong"))]
+    #[case(Some("secret"))]
+    #[tokio::test]
+    async fn writes_need_token(#[case] authorization: Option<&str>) {
+        let state = state();
+        let mut request =
+            Request::post("/api/users/1/characters/Harvey/hp").header(header::CONTENT_TYPE, "application/json");
+        if let Some(authorization) = authorization {
+            request = request.header(header::AUTHORIZATION, authorization);
+        }
+        let request = request.body(Body::from(r#"{"delta":"-3"}"#)).unwrap();
+
+        let (status, json) = send(state.clone(), request).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(json["error"].is_string());
+        assert_eq!(state.data.read().await.users[&1].characters["Harvey"].hp.current, 10);
+    }
+
+    #[test]
+    fn authorize_bearer_token() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::AUTHORIZATION, "Bearer secret".parse().unwrap());
+        assert_eq!(authorize(&headers, Some("secret")), Ok(()));
+        assert_eq!(authorize(&headers, None), Err(ApiError::Unauthorized));
+    }
+
+    #[rstest]
+    #[case(Stat::Hp, "-3", 7)]
+    #[case(Stat::Sanity, "-2", 48)]
+    #[case(Stat::Luck, "+5", 55)]
+    fn change_stats(#[case] stat: Stat, #[case] delta: &str, #[case] expected: i32) {
+        let mut data = data();
+        let response = change_stat(&mut data, 1, "Harvey", stat, delta).unwrap();
+        let character = &data.users[&1].characters["Harvey"];
+        let current = match stat {
+            Stat::Hp => character.hp.current,
+            Stat::Sanity => character.sanity.current,
+            Stat::Luck => character.luck.current,
+        };
+        assert_eq!(current, expected);
+        assert!(!response.message.is_empty());
+    }
+
+    #[test]
+    fn change_errors() {
+        let mut data = data();
+        assert_eq!(
+            change_stat(&mut data, 2, "Harvey", Stat::Hp, "-1").err(),
+            Some(ApiError::NotFound)
+        );
+        assert!(matches!(
+            change_stat(&mut data, 1, "Harvey", Stat::Luck, "-100"),
+            Err(ApiError::BadRequest(_))
+        ));
+    }
+}